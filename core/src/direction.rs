@@ -0,0 +1,49 @@
+use crate::alignment;
+
+/// The horizontal direction in which content flows.
+///
+/// When content flows [`RightToLeft`], horizontal layouts are mirrored: the
+/// children of a row are placed from right to left, horizontal paddings are
+/// swapped, and start alignments resolve to the right edge.
+///
+/// [`RightToLeft`]: Direction::RightToLeft
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Direction {
+    /// Content flows from left to right.
+    #[default]
+    LeftToRight,
+
+    /// Content flows from right to left.
+    RightToLeft,
+}
+
+impl Direction {
+    /// Returns true if the [`Direction`] is [`Direction::RightToLeft`].
+    pub fn is_right_to_left(self) -> bool {
+        self == Direction::RightToLeft
+    }
+
+    /// Resolves the given horizontal alignment, which is expressed for
+    /// left-to-right content, in the current [`Direction`].
+    ///
+    /// In other words, it swaps [`Left`] and [`Right`] when the
+    /// [`Direction`] is [`RightToLeft`].
+    ///
+    /// [`Left`]: alignment::Horizontal::Left
+    /// [`Right`]: alignment::Horizontal::Right
+    /// [`RightToLeft`]: Direction::RightToLeft
+    pub fn align_x(
+        self,
+        alignment: alignment::Horizontal,
+    ) -> alignment::Horizontal {
+        match (self, alignment) {
+            (Direction::RightToLeft, alignment::Horizontal::Left) => {
+                alignment::Horizontal::Right
+            }
+            (Direction::RightToLeft, alignment::Horizontal::Right) => {
+                alignment::Horizontal::Left
+            }
+            (_, alignment) => alignment,
+        }
+    }
+}
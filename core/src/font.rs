@@ -1,5 +1,9 @@
+//! Describe and load fonts.
+use std::borrow::Cow;
+use std::fmt;
+
 /// A font.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Font {
     /// The default font.
     ///
@@ -15,6 +19,43 @@ pub enum Font {
         /// The bytes of the external font
         bytes: &'static [u8],
     },
+
+    /// A font described by its family name and properties.
+    ///
+    /// The font is resolved by the renderer, first among the fonts loaded at
+    /// runtime and then among the fonts installed in the system. If no font
+    /// matches, the [`Default`] font is used.
+    ///
+    /// The family name can be owned, so families discovered at runtime can
+    /// be used too; for instance, the family of a [`Descriptor`] returned
+    /// after loading a font.
+    ///
+    /// [`Default`]: Font::Default
+    Family {
+        /// The family name of the font, like `"Inter"`
+        name: Cow<'static, str>,
+
+        /// The weight of the font
+        weight: Weight,
+
+        /// The stretch of the font
+        stretch: Stretch,
+
+        /// The style of the font
+        style: Style,
+    },
+}
+
+impl Font {
+    /// Creates a [`Font::Family`] with the given name and normal properties.
+    pub const fn with_name(name: &'static str) -> Font {
+        Font::Family {
+            name: Cow::Borrowed(name),
+            weight: Weight::Normal,
+            stretch: Stretch::Normal,
+            style: Style::Normal,
+        }
+    }
 }
 
 impl Default for Font {
@@ -22,3 +63,190 @@ impl Default for Font {
         Font::Default
     }
 }
+
+/// The weight of a [`Font`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Weight {
+    /// Thin (100).
+    Thin,
+    /// Extra light (200).
+    ExtraLight,
+    /// Light (300).
+    Light,
+    /// Normal (400).
+    #[default]
+    Normal,
+    /// Medium (500).
+    Medium,
+    /// Semibold (600).
+    Semibold,
+    /// Bold (700).
+    Bold,
+    /// Extra bold (800).
+    ExtraBold,
+    /// Black (900).
+    Black,
+}
+
+impl Weight {
+    /// Returns the numeric value of the [`Weight`], as used by CSS and
+    /// OpenType.
+    pub fn to_number(self) -> u16 {
+        match self {
+            Weight::Thin => 100,
+            Weight::ExtraLight => 200,
+            Weight::Light => 300,
+            Weight::Normal => 400,
+            Weight::Medium => 500,
+            Weight::Semibold => 600,
+            Weight::Bold => 700,
+            Weight::ExtraBold => 800,
+            Weight::Black => 900,
+        }
+    }
+
+    /// Returns the [`Weight`] closest to the given numeric value.
+    pub fn from_number(number: u16) -> Weight {
+        match number.saturating_add(50) / 100 {
+            0 | 1 => Weight::Thin,
+            2 => Weight::ExtraLight,
+            3 => Weight::Light,
+            4 => Weight::Normal,
+            5 => Weight::Medium,
+            6 => Weight::Semibold,
+            7 => Weight::Bold,
+            8 => Weight::ExtraBold,
+            _ => Weight::Black,
+        }
+    }
+}
+
+/// The width of a [`Font`], relative to its normal width.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
+pub enum Stretch {
+    /// Ultra condensed (50%).
+    UltraCondensed,
+    /// Extra condensed (62.5%).
+    ExtraCondensed,
+    /// Condensed (75%).
+    Condensed,
+    /// Semi condensed (87.5%).
+    SemiCondensed,
+    /// Normal (100%).
+    #[default]
+    Normal,
+    /// Semi expanded (112.5%).
+    SemiExpanded,
+    /// Expanded (125%).
+    Expanded,
+    /// Extra expanded (150%).
+    ExtraExpanded,
+    /// Ultra expanded (200%).
+    UltraExpanded,
+}
+
+impl Stretch {
+    /// Returns the width of the [`Stretch`], relative to the normal width.
+    pub fn to_ratio(self) -> f32 {
+        match self {
+            Stretch::UltraCondensed => 0.5,
+            Stretch::ExtraCondensed => 0.625,
+            Stretch::Condensed => 0.75,
+            Stretch::SemiCondensed => 0.875,
+            Stretch::Normal => 1.0,
+            Stretch::SemiExpanded => 1.125,
+            Stretch::Expanded => 1.25,
+            Stretch::ExtraExpanded => 1.5,
+            Stretch::UltraExpanded => 2.0,
+        }
+    }
+}
+
+/// The style of a [`Font`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Style {
+    /// Upright glyphs.
+    #[default]
+    Normal,
+    /// Cursive glyphs, designed to be slanted.
+    Italic,
+    /// Upright glyphs, slanted artificially.
+    Oblique,
+}
+
+/// The family and properties of a font, as described by its data.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Descriptor {
+    /// The family name of the font.
+    pub family: String,
+
+    /// The weight of the font.
+    pub weight: Weight,
+
+    /// The stretch of the font.
+    pub stretch: Stretch,
+
+    /// The style of the font.
+    pub style: Style,
+}
+
+impl Descriptor {
+    /// Returns how far the [`Descriptor`] is from the given properties.
+    ///
+    /// A mismatching style weighs more than a mismatching stretch, which
+    /// weighs more than a mismatching weight.
+    pub fn distance(
+        &self,
+        weight: Weight,
+        stretch: Stretch,
+        style: Style,
+    ) -> u32 {
+        let style = match (self.style, style) {
+            (a, b) if a == b => 0,
+            (Style::Normal, _) | (_, Style::Normal) => 2,
+            _ => 1,
+        };
+
+        let stretch = (self.stretch.to_ratio() - stretch.to_ratio()).abs();
+
+        let weight = (i32::from(self.weight.to_number())
+            - i32::from(weight.to_number()))
+        .unsigned_abs();
+
+        style * 100_000 + (stretch * 8.0).round() as u32 * 1_000 + weight
+    }
+}
+
+impl From<Descriptor> for Font {
+    fn from(descriptor: Descriptor) -> Font {
+        Font::Family {
+            name: Cow::Owned(descriptor.family),
+            weight: descriptor.weight,
+            stretch: descriptor.stretch,
+            style: descriptor.style,
+        }
+    }
+}
+
+/// An error that occurred while loading a font.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The bytes provided do not contain a valid font.
+    Invalid,
+
+    /// The renderer does not support loading fonts at runtime.
+    Unsupported,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Invalid => write!(f, "the font data is invalid"),
+            Error::Unsupported => {
+                write!(f, "the renderer does not support loading fonts")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
@@ -1,8 +1,10 @@
 //! Listen to keyboard events.
 mod event;
+mod ime;
 mod key_code;
 mod modifiers;
 
 pub use event::Event;
+pub use ime::Ime;
 pub use key_code::KeyCode;
 pub use modifiers::Modifiers;
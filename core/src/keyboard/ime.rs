@@ -0,0 +1,28 @@
+/// An event of an input method editor (IME).
+///
+/// An IME lets users compose text that cannot be typed directly, like
+/// Chinese, Japanese, or Korean characters.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Ime {
+    /// The IME was enabled.
+    Enabled,
+
+    /// A new composing text should be shown in place of the previous one.
+    ///
+    /// An empty content means the composition was cleared.
+    Preedit {
+        /// The composing text
+        content: String,
+
+        /// The byte range of the cursor in the composing text
+        ///
+        /// If `None`, the cursor should be hidden.
+        cursor: Option<(usize, usize)>,
+    },
+
+    /// The composition was finished and the given text should be inserted.
+    Commit(String),
+
+    /// The IME was disabled.
+    Disabled,
+}
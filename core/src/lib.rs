@@ -15,12 +15,14 @@
 #![forbid(unsafe_code)]
 #![forbid(rust_2018_idioms)]
 pub mod alignment;
+pub mod font;
 pub mod keyboard;
 pub mod mouse;
 
 mod background;
 mod color;
-mod font;
+mod content_fit;
+mod direction;
 mod length;
 mod padding;
 mod point;
@@ -31,6 +33,8 @@ mod vector;
 pub use alignment::Alignment;
 pub use background::Background;
 pub use color::Color;
+pub use content_fit::ContentFit;
+pub use direction::Direction;
 pub use font::Font;
 pub use length::Length;
 pub use padding::Padding;
@@ -5,9 +5,9 @@ use crate::{Settings, Transformation, Viewport};
 
 use iced_graphics::backend;
 use iced_graphics::font;
-use iced_graphics::{Layer, Primitive};
-use iced_native::alignment;
-use iced_native::{Font, Size};
+use iced_graphics::layer::{self, Layer};
+use iced_graphics::Primitive;
+use iced_native::{Direction, Font, Size};
 
 /// A [`glow`] graphics backend for [`iced`].
 ///
@@ -19,6 +19,7 @@ pub struct Backend {
     text_pipeline: text::Pipeline,
     triangle_pipeline: triangle::Pipeline,
     default_text_size: u16,
+    direction: Direction,
 }
 
 impl Backend {
@@ -38,6 +39,7 @@ impl Backend {
             text_pipeline,
             triangle_pipeline,
             default_text_size: settings.default_text_size,
+            direction: settings.direction,
         }
     }
 
@@ -111,9 +113,11 @@ impl Backend {
         }
 
         if !layer.text.is_empty() {
+            let mut decorations = Vec::new();
+
             for text in layer.text.iter() {
                 // Target physical coordinates directly to avoid blurry text
-                let text = glow_glyph::Section {
+                let section = glow_glyph::Section {
                     // TODO: We `round` here to avoid rerasterizing text when
                     // its position changes slightly. This can make text feel a
                     // bit "jumpy". We may be able to do better once we improve
@@ -141,39 +145,69 @@ impl Backend {
                             x: text.size * scale_factor,
                             y: text.size * scale_factor,
                         },
-                        font_id: self.text_pipeline.find_font(text.font),
+                        font_id: self.text_pipeline.find_font(&text.font),
                         extra: glow_glyph::Extra {
                             color: text.color,
                             z: 0.0,
                         },
                     }],
-                    layout: glow_glyph::Layout::default()
-                        .h_align(match text.horizontal_alignment {
-                            alignment::Horizontal::Left => {
-                                glow_glyph::HorizontalAlign::Left
-                            }
-                            alignment::Horizontal::Center => {
-                                glow_glyph::HorizontalAlign::Center
-                            }
-                            alignment::Horizontal::Right => {
-                                glow_glyph::HorizontalAlign::Right
-                            }
-                        })
-                        .v_align(match text.vertical_alignment {
-                            alignment::Vertical::Top => {
-                                glow_glyph::VerticalAlign::Top
-                            }
-                            alignment::Vertical::Center => {
-                                glow_glyph::VerticalAlign::Center
-                            }
-                            alignment::Vertical::Bottom => {
-                                glow_glyph::VerticalAlign::Bottom
-                            }
-                        }),
                     ..Default::default()
                 };
 
-                self.text_pipeline.queue(text);
+                let format = text.format.scale(scale_factor);
+
+                for decoration in text.decorations {
+                    let color = decoration
+                        .color
+                        .map(|color| color.into_linear())
+                        .unwrap_or(text.color);
+
+                    let quads = self
+                        .text_pipeline
+                        .decorate(
+                            &section,
+                            text.horizontal_alignment,
+                            text.vertical_alignment,
+                            format,
+                            decoration,
+                        )
+                        .into_iter()
+                        .map(|bounds| layer::Quad {
+                            position: [
+                                bounds.x / scale_factor,
+                                bounds.y / scale_factor,
+                            ],
+                            size: [
+                                bounds.width / scale_factor,
+                                bounds.height / scale_factor,
+                            ],
+                            color,
+                            border_color: [0.0; 4],
+                            border_radius: 0.0,
+                            border_width: 0.0,
+                        });
+
+                    decorations.extend(quads);
+                }
+
+                self.text_pipeline.queue(
+                    section,
+                    text.horizontal_alignment,
+                    text.vertical_alignment,
+                    format,
+                );
+            }
+
+            // Decorations are drawn right below the text
+            if !decorations.is_empty() {
+                self.quad_pipeline.draw(
+                    gl,
+                    target_height,
+                    &decorations,
+                    transformation,
+                    scale_factor,
+                    bounds,
+                );
             }
 
             self.text_pipeline.draw_queued(
@@ -194,12 +228,24 @@ impl iced_graphics::Backend for Backend {
     fn trim_measurements(&mut self) {
         self.text_pipeline.trim_measurement_cache()
     }
+
+    fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    fn load_font(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<iced_native::font::Descriptor, iced_native::font::Error> {
+        self.text_pipeline.load_font(bytes)
+    }
 }
 
 impl backend::Text for Backend {
     const ICON_FONT: Font = font::ICONS;
     const CHECKMARK_ICON: char = font::CHECKMARK_ICON;
     const ARROW_DOWN_ICON: char = font::ARROW_DOWN_ICON;
+    const ARROW_RIGHT_ICON: char = font::ARROW_RIGHT_ICON;
 
     fn default_size(&self) -> u16 {
         self.default_text_size
@@ -211,8 +257,10 @@ impl backend::Text for Backend {
         size: f32,
         font: Font,
         bounds: Size,
+        format: iced_native::text::Format,
     ) -> (f32, f32) {
-        self.text_pipeline.measure(contents, size, font, bounds)
+        self.text_pipeline
+            .measure(contents, size, font, bounds, format)
     }
 
     fn hit_test(
@@ -221,6 +269,7 @@ impl backend::Text for Backend {
         size: f32,
         font: Font,
         bounds: Size,
+        format: iced_native::text::Format,
         point: iced_native::Point,
         nearest_only: bool,
     ) -> Option<text::Hit> {
@@ -229,6 +278,7 @@ impl backend::Text for Backend {
             size,
             font,
             bounds,
+            format,
             point,
             nearest_only,
         )
@@ -1,17 +1,34 @@
 use crate::Transformation;
 
 use iced_graphics::font;
+use iced_native::alignment;
+use iced_native::text;
 
 use glow_glyph::ab_glyph;
-use std::{cell::RefCell, collections::HashMap};
+use std::{borrow::Cow, cell::RefCell, collections::HashMap};
 
 pub use iced_native::text::Hit;
 
+type Properties = (
+    iced_native::font::Weight,
+    iced_native::font::Stretch,
+    iced_native::font::Style,
+);
+
+type FamilyMap<T> = HashMap<Cow<'static, str>, HashMap<Properties, T>>;
+
 #[derive(Debug)]
 pub struct Pipeline {
     draw_brush: RefCell<glow_glyph::GlyphBrush>,
     draw_font_map: RefCell<HashMap<String, glow_glyph::FontId>>,
+    family_font_map: RefCell<FamilyMap<glow_glyph::FontId>>,
+    #[cfg(feature = "default_system_font")]
+    system_font_map: RefCell<FamilyMap<Option<glow_glyph::FontId>>>,
+    loaded_fonts: Vec<(font::Descriptor, glow_glyph::FontId)>,
     measure_brush: RefCell<glyph_brush::GlyphBrush<()>>,
+    fallback_fonts: RefCell<Vec<glow_glyph::FontId>>,
+    #[cfg(feature = "default_system_font")]
+    system_fallback: RefCell<font::Fallback>,
 }
 
 impl Pipeline {
@@ -46,24 +63,96 @@ impl Pipeline {
                     .expect("Load fallback font")
             });
 
+        // The default font is tried first, then the system fonts, which are
+        // only loaded once a character is missing
+        let fonts = vec![font];
+
+        let fallback_fonts = vec![glow_glyph::FontId(0)];
+
         let draw_brush =
-            glow_glyph::GlyphBrushBuilder::using_font(font.clone())
+            glow_glyph::GlyphBrushBuilder::using_fonts(fonts.clone())
                 .initial_cache_size((2048, 2048))
                 .draw_cache_multithread(multithreading)
                 .build(&gl);
 
         let measure_brush =
-            glyph_brush::GlyphBrushBuilder::using_font(font).build();
+            glyph_brush::GlyphBrushBuilder::using_fonts(fonts).build();
 
         Pipeline {
             draw_brush: RefCell::new(draw_brush),
             draw_font_map: RefCell::new(HashMap::new()),
+            family_font_map: RefCell::new(HashMap::new()),
+            #[cfg(feature = "default_system_font")]
+            system_font_map: RefCell::new(HashMap::new()),
+            loaded_fonts: Vec::new(),
             measure_brush: RefCell::new(measure_brush),
+            fallback_fonts: RefCell::new(fallback_fonts),
+            #[cfg(feature = "default_system_font")]
+            system_fallback: RefCell::new(font::Fallback::new()),
         }
     }
 
-    pub fn queue(&mut self, section: glow_glyph::Section<'_>) {
-        self.draw_brush.borrow_mut().queue(section);
+    pub fn queue(
+        &mut self,
+        section: glow_glyph::Section<'_>,
+        horizontal_alignment: alignment::Horizontal,
+        vertical_alignment: alignment::Vertical,
+        format: text::Format,
+    ) {
+        for text in &section.text {
+            self.load_fallback(text.text, text.font_id);
+        }
+
+        let fallback_fonts = self.fallback_fonts.borrow();
+
+        let layout = iced_graphics::text::Layout {
+            horizontal_alignment,
+            vertical_alignment,
+            format,
+            fallback: &fallback_fonts,
+        };
+
+        self.draw_brush
+            .borrow_mut()
+            .queue_custom_layout(section, &layout);
+    }
+
+    pub fn decorate(
+        &self,
+        section: &glow_glyph::Section<'_>,
+        horizontal_alignment: alignment::Horizontal,
+        vertical_alignment: alignment::Vertical,
+        format: text::Format,
+        decoration: &text::Decoration,
+    ) -> Vec<iced_native::Rectangle> {
+        use glow_glyph::GlyphCruncher;
+
+        let fallback_fonts = self.fallback_fonts.borrow();
+
+        let layout = iced_graphics::text::Layout {
+            horizontal_alignment,
+            vertical_alignment,
+            format,
+            fallback: &fallback_fonts,
+        };
+
+        let content = section
+            .text
+            .first()
+            .map(|text| text.text)
+            .unwrap_or_default();
+
+        let mut mb = self.measure_brush.borrow_mut();
+
+        // The underlying type is FontArc, so clones are cheap.
+        let fonts = mb.fonts().to_vec();
+
+        iced_graphics::text::decorate(
+            &fonts,
+            mb.glyphs_custom_layout(section, &layout),
+            content,
+            decoration,
+        )
     }
 
     pub fn draw_queued(
@@ -88,10 +177,13 @@ impl Pipeline {
         size: f32,
         font: iced_native::Font,
         bounds: iced_native::Size,
+        format: text::Format,
     ) -> (f32, f32) {
         use glow_glyph::GlyphCruncher;
 
-        let glow_glyph::FontId(font_id) = self.find_font(font);
+        let glow_glyph::FontId(font_id) = self.find_font(&font);
+
+        self.load_fallback(content, glow_glyph::FontId(font_id));
 
         let section = glow_glyph::Section {
             bounds: (bounds.width, bounds.height),
@@ -104,8 +196,17 @@ impl Pipeline {
             ..Default::default()
         };
 
-        if let Some(bounds) =
-            self.measure_brush.borrow_mut().glyph_bounds(section)
+        let fallback_fonts = self.fallback_fonts.borrow();
+
+        let layout = iced_graphics::text::Layout {
+            format,
+            ..iced_graphics::text::Layout::new(&fallback_fonts)
+        };
+
+        if let Some(bounds) = self
+            .measure_brush
+            .borrow_mut()
+            .glyph_bounds_custom_layout(section, &layout)
         {
             (bounds.width().ceil(), bounds.height().ceil())
         } else {
@@ -113,18 +214,22 @@ impl Pipeline {
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn hit_test(
         &self,
         content: &str,
         size: f32,
         font: iced_native::Font,
         bounds: iced_native::Size,
+        format: text::Format,
         point: iced_native::Point,
         nearest_only: bool,
     ) -> Option<Hit> {
         use glow_glyph::GlyphCruncher;
 
-        let glow_glyph::FontId(font_id) = self.find_font(font);
+        let glow_glyph::FontId(font_id) = self.find_font(&font);
+
+        self.load_fallback(content, glow_glyph::FontId(font_id));
 
         let section = glow_glyph::Section {
             bounds: (bounds.width, bounds.height),
@@ -137,17 +242,28 @@ impl Pipeline {
             ..Default::default()
         };
 
+        let fallback_fonts = self.fallback_fonts.borrow();
+        let layout = iced_graphics::text::Layout {
+            format,
+            ..iced_graphics::text::Layout::new(&fallback_fonts)
+        };
         let mut mb = self.measure_brush.borrow_mut();
 
         // The underlying type is FontArc, so clones are cheap.
         use ab_glyph::{Font, ScaleFont};
-        let font = mb.fonts()[font_id].clone().into_scaled(size);
+        let fonts = mb.fonts().to_vec();
 
         // Implements an iterator over the glyph bounding boxes.
-        let bounds = mb.glyphs(section).map(
+        let bounds = mb.glyphs_custom_layout(section, &layout).map(
             |glow_glyph::SectionGlyph {
-                 byte_index, glyph, ..
+                 byte_index,
+                 glyph,
+                 font_id,
+                 ..
              }| {
+                // Glyphs may come from a fallback font
+                let font = fonts[font_id.0].clone().into_scaled(size);
+
                 (
                     *byte_index,
                     iced_native::Rectangle::new(
@@ -197,7 +313,15 @@ impl Pipeline {
             });
 
         nearest.map(|(idx, center)| {
-            Hit::NearestCharOffset(char_index(idx), point - center)
+            let mut delta = point - center;
+
+            // The glyphs of right-to-left runs advance to the left, so the
+            // side of the glyph that comes after it is mirrored.
+            if iced_graphics::text::is_right_to_left(content, idx) {
+                delta.x = -delta.x;
+            }
+
+            Hit::NearestCharOffset(char_index(idx), delta)
         })
     }
 
@@ -225,11 +349,55 @@ impl Pipeline {
         }
     }
 
-    pub fn find_font(&self, font: iced_native::Font) -> glow_glyph::FontId {
+    /// Loads the system fallback fonts needed to display the given content,
+    /// if the given font and the fallback fonts loaded so far are missing
+    /// some of its characters.
+    #[cfg(feature = "default_system_font")]
+    fn load_fallback(&self, content: &str, font_id: glow_glyph::FontId) {
+        use ab_glyph::Font;
+        use glow_glyph::GlyphCruncher;
+
+        for c in content.chars() {
+            if c.is_whitespace() || c.is_control() {
+                continue;
+            }
+
+            let is_missing = {
+                let measure_brush = self.measure_brush.borrow();
+                let fonts = measure_brush.fonts();
+
+                std::iter::once(&font_id)
+                    .chain(self.fallback_fonts.borrow().iter())
+                    .filter_map(|font_id| fonts.get(font_id.0))
+                    .all(|font| font.glyph_id(c).0 == 0)
+            };
+
+            if !is_missing {
+                continue;
+            }
+
+            let font =
+                self.system_fallback.borrow_mut().load(c).and_then(|bytes| {
+                    ab_glyph::FontArc::try_from_vec(bytes).ok()
+                });
+
+            if let Some(font) = font {
+                let _ = self.measure_brush.borrow_mut().add_font(font.clone());
+                let font_id = self.draw_brush.borrow_mut().add_font(font);
+
+                self.fallback_fonts.borrow_mut().push(font_id);
+            }
+        }
+    }
+
+    #[cfg(not(feature = "default_system_font"))]
+    fn load_fallback(&self, _content: &str, _font_id: glow_glyph::FontId) {}
+
+    pub fn find_font(&self, font: &iced_native::Font) -> glow_glyph::FontId {
         match font {
             iced_native::Font::Default => glow_glyph::FontId(0),
             iced_native::Font::External { name, bytes } => {
-                if let Some(font_id) = self.draw_font_map.borrow().get(name) {
+                if let Some(font_id) = self.draw_font_map.borrow().get(*name) {
                     return *font_id;
                 }
 
@@ -243,10 +411,120 @@ impl Pipeline {
                 let _ = self
                     .draw_font_map
                     .borrow_mut()
-                    .insert(String::from(name), font_id);
+                    .insert(String::from(*name), font_id);
+
+                font_id
+            }
+            iced_native::Font::Family {
+                name,
+                weight,
+                stretch,
+                style,
+            } => {
+                let properties = (*weight, *stretch, *style);
+
+                if let Some(font_id) = self
+                    .family_font_map
+                    .borrow()
+                    .get(name.as_ref())
+                    .and_then(|fonts| fonts.get(&properties))
+                {
+                    return *font_id;
+                }
+
+                let font_id = self
+                    .find_family(name.clone(), properties)
+                    .unwrap_or(glow_glyph::FontId(0));
+
+                let _ = self
+                    .family_font_map
+                    .borrow_mut()
+                    .entry(name.clone())
+                    .or_default()
+                    .insert(properties, font_id);
 
                 font_id
             }
         }
     }
+
+    pub fn load_font(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<font::Descriptor, iced_native::font::Error> {
+        let descriptor =
+            font::parse(&bytes).ok_or(iced_native::font::Error::Invalid)?;
+
+        let font = ab_glyph::FontArc::try_from_vec(bytes)
+            .map_err(|_| iced_native::font::Error::Invalid)?;
+
+        let _ = self.measure_brush.borrow_mut().add_font(font.clone());
+        let font_id = self.draw_brush.borrow_mut().add_font(font);
+
+        // Only the family of the new font may now resolve to it
+        self.family_font_map
+            .borrow_mut()
+            .retain(|name, _| !name.eq_ignore_ascii_case(&descriptor.family));
+
+        self.loaded_fonts.push((descriptor.clone(), font_id));
+
+        Ok(descriptor)
+    }
+
+    fn find_family(
+        &self,
+        name: Cow<'static, str>,
+        properties: Properties,
+    ) -> Option<glow_glyph::FontId> {
+        let (weight, stretch, style) = properties;
+
+        // Fonts loaded at runtime take precedence over system fonts
+        let loaded = font::find_best_match(
+            self.loaded_fonts.iter().map(|(descriptor, _)| descriptor),
+            &name,
+            weight,
+            stretch,
+            style,
+        );
+
+        if let Some(index) = loaded {
+            return Some(self.loaded_fonts[index].1);
+        }
+
+        #[cfg(feature = "default_system_font")]
+        {
+            // System fonts are only added to the brushes once
+            if let Some(font_id) = self
+                .system_font_map
+                .borrow()
+                .get(name.as_ref())
+                .and_then(|fonts| fonts.get(&properties))
+            {
+                return *font_id;
+            }
+
+            let font_id = font::Source::new()
+                .load_family(&name, weight, stretch, style)
+                .ok()
+                .and_then(|bytes| ab_glyph::FontArc::try_from_vec(bytes).ok())
+                .map(|font| {
+                    let _ =
+                        self.measure_brush.borrow_mut().add_font(font.clone());
+
+                    self.draw_brush.borrow_mut().add_font(font)
+                });
+
+            let _ = self
+                .system_font_map
+                .borrow_mut()
+                .entry(name)
+                .or_default()
+                .insert(properties, font_id);
+
+            font_id
+        }
+
+        #[cfg(not(feature = "default_system_font"))]
+        None
+    }
 }
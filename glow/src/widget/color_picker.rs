@@ -0,0 +1,15 @@
+//! Let your users pick a color.
+//!
+//! A [`ColorPicker`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::color_picker::{Style, StyleSheet};
+pub use iced_native::widget::color_picker::{Notation, State};
+
+/// A color picker with a saturation and value area, hue and alpha strips, a
+/// text entry, and a history of picked colors.
+///
+/// This is an alias of an `iced_native` color picker with an
+/// `iced_glow::Renderer`.
+pub type ColorPicker<'a, Message> =
+    iced_native::widget::ColorPicker<'a, Message, Renderer>;
@@ -0,0 +1,16 @@
+//! Display rich text written in Markdown.
+//!
+//! A [`Markdown`] displays a parsed [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::markdown::{State, Style, StyleSheet};
+
+/// A document written in CommonMark Markdown.
+///
+/// This is an alias of an `iced_native` markdown with an
+/// `iced_glow::Renderer`.
+///
+/// `iced_glow` does not draw images yet, so the images of a document are
+/// only laid out as blank space.
+pub type Markdown<'a, Message> =
+    iced_native::widget::Markdown<'a, Message, Renderer>;
@@ -0,0 +1,14 @@
+//! Let your users pick a time of the day.
+//!
+//! A [`TimePicker`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::time_picker::{Style, StyleSheet};
+pub use iced_native::widget::time_picker::{State, Time};
+
+/// A field that picks a time with an hour spinner and a minute spinner.
+///
+/// This is an alias of an `iced_native` time picker with an
+/// `iced_glow::Renderer`.
+pub type TimePicker<'a, Message> =
+    iced_native::widget::TimePicker<'a, Message, Renderer>;
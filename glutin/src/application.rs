@@ -5,6 +5,7 @@ use crate::{Error, Executor, Runtime};
 pub use iced_winit::Application;
 
 use iced_graphics::window;
+use iced_native::window::RedrawRequest;
 use iced_winit::application;
 use iced_winit::conversion;
 use iced_winit::futures;
@@ -14,6 +15,7 @@ use iced_winit::{Clipboard, Debug, Proxy, Settings};
 
 use glutin::window::Window;
 use std::mem::ManuallyDrop;
+use std::time::Instant;
 
 /// Runs an [`Application`] with an executor, compositor, and the provided
 /// settings.
@@ -83,7 +85,7 @@ where
     };
 
     #[allow(unsafe_code)]
-    let (compositor, renderer) = unsafe {
+    let (compositor, mut renderer) = unsafe {
         C::new(compositor_settings, |address| {
             context.get_proc_address(address)
         })?
@@ -95,12 +97,14 @@ where
         init_command,
         &mut runtime,
         &mut clipboard,
+        &mut renderer,
         &mut proxy,
         context.window(),
     );
     runtime.track(subscription);
 
     let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -111,11 +115,13 @@ where
         proxy,
         debug,
         receiver,
+        control_sender,
         context,
         settings.exit_on_close_request,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
+    let mut next_control_flow = glutin::event_loop::ControlFlow::Wait;
 
     event_loop.run_return(move |event, _, control_flow| {
         use glutin::event_loop::ControlFlow;
@@ -145,7 +151,13 @@ where
             let poll = instance.as_mut().poll(&mut context);
 
             *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
+                task::Poll::Pending => {
+                    while let Ok(Some(flow)) = control_receiver.try_next() {
+                        next_control_flow = flow;
+                    }
+
+                    next_control_flow
+                }
                 task::Poll::Ready(_) => ControlFlow::Exit,
             };
         }
@@ -163,6 +175,7 @@ async fn run_instance<A, E, C>(
     mut proxy: glutin::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<glutin::event::Event<'_, A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<glutin::event_loop::ControlFlow>,
     mut context: glutin::ContextWrapper<glutin::PossiblyCurrent, Window>,
     exit_on_close_request: bool,
 ) where
@@ -187,12 +200,31 @@ async fn run_instance<A, E, C>(
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
+    // Widgets of the initial view may need to be redrawn continuously (e.g.
+    // progress indicators and animations), so a redraw is always requested
+    let mut redraw_request = Some(RedrawRequest::NextFrame);
 
     debug.startup_finished();
 
     while let Some(event) = receiver.next().await {
         match event {
             event::Event::MainEventsCleared => {
+                let now = Instant::now();
+
+                let is_redraw_due = match redraw_request {
+                    Some(RedrawRequest::NextFrame) => true,
+                    Some(RedrawRequest::At(at)) => at <= now,
+                    None => false,
+                };
+
+                if is_redraw_due {
+                    events.push(iced_native::Event::Window(
+                        iced_native::window::Event::RedrawRequested(now),
+                    ));
+
+                    redraw_request = None;
+                }
+
                 if events.is_empty() && messages.is_empty() {
                     continue;
                 }
@@ -209,6 +241,20 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_finished();
 
+                redraw_request = redraw_request
+                    .into_iter()
+                    .chain(user_interface.redraw_request())
+                    .min();
+
+                if let Some(position) = user_interface.ime_position() {
+                    context.window().set_ime_position(
+                        conversion::ime_position(
+                            position,
+                            state.scale_factor(),
+                        ),
+                    );
+                }
+
                 for event in events.drain(..).zip(statuses.into_iter()) {
                     runtime.broadcast(event);
                 }
@@ -227,6 +273,7 @@ async fn run_instance<A, E, C>(
                         &mut application,
                         &mut runtime,
                         &mut clipboard,
+                        &mut renderer,
                         &mut proxy,
                         &mut debug,
                         &mut messages,
@@ -266,6 +313,10 @@ async fn run_instance<A, E, C>(
                 }
 
                 context.window().request_redraw();
+
+                control_sender
+                    .start_send(application::control_flow(redraw_request, now))
+                    .expect("Send control flow");
             }
             event::Event::PlatformSpecific(event::PlatformSpecific::MacOS(
                 event::MacOS::ReceivedUrl(url),
@@ -340,9 +391,6 @@ async fn run_instance<A, E, C>(
                 context.swap_buffers().expect("Swap buffers");
 
                 debug.render_finished();
-
-                // TODO: Handle animations!
-                // Maybe we can use `ControlFlow::WaitUntil` for this.
             }
             event::Event::WindowEvent {
                 event: window_event,
@@ -363,6 +411,12 @@ async fn run_instance<A, E, C>(
                 ) {
                     events.push(event);
                 }
+
+                if let Some(ime) = conversion::ime_event(&window_event) {
+                    events.push(iced_native::Event::Keyboard(
+                        iced_native::keyboard::Event::Ime(ime),
+                    ));
+                }
             }
             _ => {}
         }
@@ -1,8 +1,11 @@
 //! Write a graphics backend.
+use iced_native::font;
 use iced_native::image;
 use iced_native::svg;
 use iced_native::text;
-use iced_native::{Font, Point, Size};
+use iced_native::{Direction, Font, Point, Size};
+
+use std::time::Duration;
 
 /// The graphics backend of a [`Renderer`].
 ///
@@ -14,6 +17,24 @@ pub trait Backend {
     /// `iced_wgpu` and `iced_glow` because of limitations in the text rendering
     /// pipeline. It will be removed in the future.
     fn trim_measurements(&mut self) {}
+
+    /// Returns the [`Direction`] used to lay out the root of a user interface.
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    fn direction(&self) -> Direction {
+        Direction::LeftToRight
+    }
+
+    /// Loads the font contained in the given bytes.
+    ///
+    /// Returns the [`font::Descriptor`] of the loaded font. By default, it
+    /// returns [`font::Error::Unsupported`].
+    fn load_font(
+        &mut self,
+        _bytes: Vec<u8>,
+    ) -> Result<font::Descriptor, font::Error> {
+        Err(font::Error::Unsupported)
+    }
 }
 
 /// A graphics backend that supports text rendering.
@@ -31,18 +52,27 @@ pub trait Text {
     /// [`ICON_FONT`]: Self::ICON_FONT
     const ARROW_DOWN_ICON: char;
 
+    /// The `char` representing a ▶ icon in the built-in [`ICON_FONT`].
+    ///
+    /// [`ICON_FONT`]: Self::ICON_FONT
+    const ARROW_RIGHT_ICON: char;
+
     /// Returns the default size of text.
     fn default_size(&self) -> u16;
 
-    /// Measures the text contents with the given size and font,
+    /// Measures the text contents with the given size, font, and format,
     /// returning the size of a laid out paragraph that fits in the provided
     /// bounds.
+    ///
+    /// The contents should be shaped before being measured. See
+    /// [`text::Layout`](crate::text::Layout).
     fn measure(
         &self,
         contents: &str,
         size: f32,
         font: Font,
         bounds: Size,
+        format: text::Format,
     ) -> (f32, f32);
 
     /// Tests whether the provided point is within the boundaries of [`Text`]
@@ -52,12 +82,17 @@ pub trait Text {
     /// If nearest_only is true, the hit test does not consider whether the
     /// the point is interior to any glyph bounds, returning only the character
     /// with the nearest centeroid.
+    ///
+    /// When multiple characters are shaped into a single glyph, the index of
+    /// the first character of the cluster is returned.
+    #[allow(clippy::too_many_arguments)]
     fn hit_test(
         &self,
         contents: &str,
         size: f32,
         font: Font,
         bounds: Size,
+        format: text::Format,
         point: Point,
         nearest_only: bool,
     ) -> Option<text::Hit>;
@@ -67,6 +102,13 @@ pub trait Text {
 pub trait Image {
     /// Returns the dimensions of the provided image.
     fn dimensions(&self, handle: &image::Handle) -> (u32, u32);
+
+    /// Returns the delay of each frame of the provided image.
+    ///
+    /// The returned list is empty if the image is not animated.
+    fn frame_delays(&self, _handle: &image::Handle) -> Vec<Duration> {
+        Vec::new()
+    }
 }
 
 /// A graphics backend that supports SVG rendering.
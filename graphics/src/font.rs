@@ -1,10 +1,15 @@
 //! Find system fonts or use the built-in ones.
+mod descriptor;
+
 #[cfg(feature = "font-source")]
 mod source;
 
+pub use descriptor::{find_best_match, parse};
+pub use iced_native::font::Descriptor;
+
 #[cfg(feature = "font-source")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-source")))]
-pub use source::Source;
+pub use source::{Fallback, Source};
 
 #[cfg(feature = "font-source")]
 #[cfg_attr(docsrs, doc(cfg(feature = "font-source")))]
@@ -33,3 +38,7 @@ pub const CHECKMARK_ICON: char = '\u{F00C}';
 /// The `char` representing a ▼ icon in the built-in [`ICONS`] font.
 #[cfg(feature = "font-icons")]
 pub const ARROW_DOWN_ICON: char = '\u{E800}';
+
+/// The `char` representing a ▶ icon in the built-in [`ICONS`] font.
+#[cfg(feature = "font-icons")]
+pub const ARROW_RIGHT_ICON: char = '\u{E801}';
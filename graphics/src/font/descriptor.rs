@@ -0,0 +1,124 @@
+use iced_native::font::{Descriptor, Stretch, Style, Weight};
+
+use rustybuzz::ttf_parser;
+
+/// Reads the [`Descriptor`] of the font contained in the given bytes.
+///
+/// Returns `None` if the bytes do not contain a valid font or the font has
+/// no family name.
+pub fn parse(bytes: &[u8]) -> Option<Descriptor> {
+    let face = ttf_parser::Face::parse(bytes, 0).ok()?;
+
+    let name = |id| {
+        face.names()
+            .into_iter()
+            .filter(|name| name.name_id == id)
+            .find_map(|name| name.to_string())
+    };
+
+    let family = name(ttf_parser::name_id::TYPOGRAPHIC_FAMILY)
+        .or_else(|| name(ttf_parser::name_id::FAMILY))?;
+
+    let stretch = match face.width() {
+        ttf_parser::Width::UltraCondensed => Stretch::UltraCondensed,
+        ttf_parser::Width::ExtraCondensed => Stretch::ExtraCondensed,
+        ttf_parser::Width::Condensed => Stretch::Condensed,
+        ttf_parser::Width::SemiCondensed => Stretch::SemiCondensed,
+        ttf_parser::Width::Normal => Stretch::Normal,
+        ttf_parser::Width::SemiExpanded => Stretch::SemiExpanded,
+        ttf_parser::Width::Expanded => Stretch::Expanded,
+        ttf_parser::Width::ExtraExpanded => Stretch::ExtraExpanded,
+        ttf_parser::Width::UltraExpanded => Stretch::UltraExpanded,
+    };
+
+    let style = match face.style() {
+        ttf_parser::Style::Normal => Style::Normal,
+        ttf_parser::Style::Italic => Style::Italic,
+        ttf_parser::Style::Oblique => Style::Oblique,
+    };
+
+    Some(Descriptor {
+        family,
+        weight: Weight::from_number(face.weight().to_number()),
+        stretch,
+        style,
+    })
+}
+
+/// Finds the [`Descriptor`] of the given family that best matches the given
+/// properties, returning its index.
+///
+/// Family names are compared ignoring case.
+pub fn find_best_match<'a>(
+    descriptors: impl IntoIterator<Item = &'a Descriptor>,
+    family: &str,
+    weight: Weight,
+    stretch: Stretch,
+    style: Style,
+) -> Option<usize> {
+    descriptors
+        .into_iter()
+        .enumerate()
+        .filter(|(_, descriptor)| {
+            descriptor.family.eq_ignore_ascii_case(family)
+        })
+        .min_by_key(|(_, descriptor)| {
+            descriptor.distance(weight, stretch, style)
+        })
+        .map(|(index, _)| index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LATO: &[u8] = include_bytes!("../../fonts/Lato-Regular.ttf");
+
+    #[test]
+    fn parses_family_and_properties() {
+        let descriptor = parse(LATO).expect("Parse font");
+
+        assert_eq!(descriptor.family, "Lato");
+        assert_eq!(descriptor.weight, Weight::Normal);
+        assert_eq!(descriptor.stretch, Stretch::Normal);
+        assert_eq!(descriptor.style, Style::Normal);
+    }
+
+    #[test]
+    fn prefers_style_over_weight() {
+        let descriptor = |weight, style| Descriptor {
+            family: String::from("Lato"),
+            weight,
+            stretch: Stretch::Normal,
+            style,
+        };
+
+        let descriptors = [
+            descriptor(Weight::Normal, Style::Normal),
+            descriptor(Weight::Black, Style::Italic),
+            descriptor(Weight::Normal, Style::Italic),
+        ];
+
+        assert_eq!(
+            find_best_match(
+                &descriptors,
+                "lato",
+                Weight::Bold,
+                Stretch::Normal,
+                Style::Italic
+            ),
+            Some(1)
+        );
+
+        assert_eq!(
+            find_best_match(
+                &descriptors,
+                "Inter",
+                Weight::Normal,
+                Stretch::Normal,
+                Style::Normal
+            ),
+            None
+        );
+    }
+}
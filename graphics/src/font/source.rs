@@ -1,5 +1,51 @@
 use crate::font::{Family, LoadError};
 
+use iced_native::font::{Stretch, Style, Weight};
+
+use rustybuzz::ttf_parser;
+use std::collections::HashMap;
+
+/// The families of the system fonts that are used as a fallback, in order of
+/// preference.
+///
+/// Only fonts with outlines are listed, as color bitmap fonts cannot be
+/// rasterized.
+const FALLBACK_FAMILIES: &[&str] = &[
+    // Linux
+    "Noto Sans",
+    "Noto Sans Arabic",
+    "Noto Sans Hebrew",
+    "Noto Sans Devanagari",
+    "Noto Sans Thai",
+    "Noto Sans CJK SC",
+    "Noto Emoji",
+    "DejaVu Sans",
+    // Windows
+    "Segoe UI",
+    "Nirmala UI",
+    "Leelawadee UI",
+    "Microsoft YaHei",
+    "Segoe UI Symbol",
+    // macOS
+    "Helvetica Neue",
+    "Geeza Pro",
+    "Kohinoor Devanagari",
+    "Thonburi",
+    "PingFang SC",
+];
+
+/// Returns the generic [`Family`] with the given CSS name, if any.
+///
+/// Names are compared ignoring case.
+fn generic_family(name: &str) -> Option<Family> {
+    match name.to_ascii_lowercase().as_str() {
+        "serif" => Some(Family::Serif),
+        "sans-serif" => Some(Family::SansSerif),
+        "monospace" => Some(Family::Monospace),
+        _ => None,
+    }
+}
+
 /// A font source that can find and load system fonts.
 #[allow(missing_debug_implementations)]
 pub struct Source {
@@ -16,10 +62,56 @@ impl Source {
 
     /// Finds and loads a font matching the set of provided family priorities.
     pub fn load(&self, families: &[Family]) -> Result<Vec<u8>, LoadError> {
-        let font = self.raw.select_best_match(
+        self.load_with_properties(
             families,
             &font_kit::properties::Properties::default(),
-        )?;
+        )
+    }
+
+    /// Finds and loads the font of the given family that best matches the
+    /// provided properties.
+    ///
+    /// Unlike [`load`], it fails if the family is not installed. The generic
+    /// `serif`, `sans-serif`, and `monospace` families are resolved to the
+    /// preferred system fonts, as they are not installed under these names
+    /// on every platform.
+    ///
+    /// [`load`]: Self::load
+    pub fn load_family(
+        &self,
+        name: &str,
+        weight: Weight,
+        stretch: Stretch,
+        style: Style,
+    ) -> Result<Vec<u8>, LoadError> {
+        let family = match generic_family(name) {
+            Some(family) => family,
+            None => {
+                let _ = self.raw.select_family_by_name(name)?;
+
+                Family::Title(String::from(name))
+            }
+        };
+
+        let properties = font_kit::properties::Properties {
+            weight: font_kit::properties::Weight(f32::from(weight.to_number())),
+            stretch: font_kit::properties::Stretch(stretch.to_ratio()),
+            style: match style {
+                Style::Normal => font_kit::properties::Style::Normal,
+                Style::Italic => font_kit::properties::Style::Italic,
+                Style::Oblique => font_kit::properties::Style::Oblique,
+            },
+        };
+
+        self.load_with_properties(&[family], &properties)
+    }
+
+    fn load_with_properties(
+        &self,
+        families: &[Family],
+        properties: &font_kit::properties::Properties,
+    ) -> Result<Vec<u8>, LoadError> {
+        let font = self.raw.select_best_match(families, properties)?;
 
         match font {
             font_kit::handle::Handle::Path { path, .. } => {
@@ -37,3 +129,111 @@ impl Source {
         }
     }
 }
+
+/// The system fonts that can be used as a fallback when a font is missing
+/// some characters.
+///
+/// The fonts are only loaded once a character is missing, in order of
+/// preference.
+pub struct Fallback {
+    source: Source,
+
+    /// The families that have not been used yet, with their font data once
+    /// it has been loaded.
+    families: Vec<(&'static str, Option<Vec<u8>>)>,
+
+    /// The family containing every character looked up so far, if any.
+    characters: HashMap<char, Option<&'static str>>,
+}
+
+impl Fallback {
+    /// Creates a new [`Fallback`], with no fonts loaded yet.
+    pub fn new() -> Self {
+        Fallback {
+            source: Source::new(),
+            families: FALLBACK_FAMILIES
+                .iter()
+                .map(|family| (*family, None))
+                .collect(),
+            characters: HashMap::new(),
+        }
+    }
+
+    /// Loads the next fallback font that contains the given character.
+    ///
+    /// Every font is read at most once, and every character is looked up at
+    /// most once. Returns `None` if the character was already looked up or
+    /// none of the fallback fonts that are left contains it.
+    pub fn load(&mut self, c: char) -> Option<Vec<u8>> {
+        if self.characters.contains_key(&c) {
+            return None;
+        }
+
+        let mut index = 0;
+
+        while index < self.families.len() {
+            let (family, font) = &mut self.families[index];
+
+            if font.is_none() {
+                *font = if self.source.raw.select_family_by_name(family).is_ok()
+                {
+                    self.source
+                        .load(&[Family::Title(String::from(*family))])
+                        .ok()
+                } else {
+                    None
+                };
+            }
+
+            match font {
+                Some(bytes) if contains(bytes, c) => {
+                    let (family, bytes) = self.families.remove(index);
+                    let _ = self.characters.insert(c, Some(family));
+
+                    return bytes;
+                }
+                // The family may contain other missing characters
+                Some(_) => index += 1,
+                None => {
+                    let _ = self.families.remove(index);
+                }
+            }
+        }
+
+        let _ = self.characters.insert(c, None);
+
+        None
+    }
+}
+
+impl std::fmt::Debug for Fallback {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let families: Vec<_> =
+            self.families.iter().map(|(family, _)| family).collect();
+
+        f.debug_struct("Fallback")
+            .field("families", &families)
+            .field("characters", &self.characters)
+            .finish()
+    }
+}
+
+fn contains(bytes: &[u8], c: char) -> bool {
+    ttf_parser::Face::parse(bytes, 0)
+        .ok()
+        .and_then(|face| face.glyph_index(c))
+        .is_some()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolves_generic_families() {
+        assert_eq!(generic_family("sans-serif"), Some(Family::SansSerif));
+        assert_eq!(generic_family("Monospace"), Some(Family::Monospace));
+        assert_eq!(generic_family("serif"), Some(Family::Serif));
+        assert_eq!(generic_family("Noto Sans"), None);
+    }
+}
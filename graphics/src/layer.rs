@@ -7,6 +7,8 @@ use crate::{
     Background, Font, Point, Primitive, Rectangle, Size, Vector, Viewport,
 };
 
+use iced_native::text;
+
 /// A group of primitives that should be clipped together.
 #[derive(Debug, Clone)]
 pub struct Layer<'a> {
@@ -57,9 +59,11 @@ impl<'a> Layer<'a> {
                 font: Font::Default,
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Top,
+                format: text::Format::default(),
+                decorations: &[],
             };
 
-            overlay.text.push(text);
+            overlay.text.push(text.clone());
 
             overlay.text.push(Text {
                 bounds: text.bounds + Vector::new(-1.0, -1.0),
@@ -121,6 +125,8 @@ impl<'a> Layer<'a> {
                 font,
                 horizontal_alignment,
                 vertical_alignment,
+                format,
+                decorations,
             } => {
                 let layer = &mut layers[current_layer];
 
@@ -129,9 +135,11 @@ impl<'a> Layer<'a> {
                     bounds: *bounds + translation,
                     size: *size,
                     color: color.into_linear(),
-                    font: *font,
+                    font: font.clone(),
                     horizontal_alignment: *horizontal_alignment,
                     vertical_alignment: *vertical_alignment,
+                    format: *format,
+                    decorations,
                 });
             }
             Primitive::Quad {
@@ -213,11 +221,18 @@ impl<'a> Layer<'a> {
                     current_layer,
                 );
             }
-            Primitive::Image { handle, bounds } => {
+            Primitive::Image {
+                handle,
+                frame,
+                filter_method,
+                bounds,
+            } => {
                 let layer = &mut layers[current_layer];
 
                 layer.images.push(Image::Raster {
                     handle: handle.clone(),
+                    frame: *frame,
+                    filter_method: *filter_method,
                     bounds: *bounds + translation,
                 });
             }
@@ -272,7 +287,7 @@ pub struct Mesh<'a> {
 }
 
 /// A paragraph of text.
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone)]
 pub struct Text<'a> {
     /// The content of the [`Text`].
     pub content: &'a str,
@@ -294,6 +309,12 @@ pub struct Text<'a> {
 
     /// The vertical alignment of the [`Text`].
     pub vertical_alignment: alignment::Vertical,
+
+    /// The format of the [`Text`].
+    pub format: text::Format,
+
+    /// The decorations of the [`Text`].
+    pub decorations: &'a [text::Decoration],
 }
 
 /// A raster or vector image.
@@ -304,6 +325,12 @@ pub enum Image {
         /// The handle of a raster image.
         handle: image::Handle,
 
+        /// The frame of the image to draw, if it is animated.
+        frame: usize,
+
+        /// The filter method used to scale the image.
+        filter_method: image::FilterMethod,
+
         /// The bounds of the image.
         bounds: Rectangle,
     },
@@ -24,6 +24,7 @@ pub mod font;
 pub mod layer;
 pub mod overlay;
 pub mod renderer;
+pub mod text;
 pub mod triangle;
 pub mod widget;
 pub mod window;
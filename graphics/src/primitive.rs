@@ -1,5 +1,6 @@
 use iced_native::image;
 use iced_native::svg;
+use iced_native::text;
 use iced_native::{Background, Color, Font, Rectangle, Size, Vector};
 
 use crate::alignment;
@@ -33,6 +34,10 @@ pub enum Primitive {
         horizontal_alignment: alignment::Horizontal,
         /// The vertical alignment of the text
         vertical_alignment: alignment::Vertical,
+        /// The format of the text
+        format: text::Format,
+        /// The decorations of the text
+        decorations: Vec<text::Decoration>,
     },
     /// A quad primitive
     Quad {
@@ -51,6 +56,10 @@ pub enum Primitive {
     Image {
         /// The handle of the image
         handle: image::Handle,
+        /// The frame of the image to draw, if it is animated
+        frame: usize,
+        /// The filter method used to scale the image
+        filter_method: image::FilterMethod,
         /// The bounds of the image
         bounds: Rectangle,
     },
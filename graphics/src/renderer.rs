@@ -1,10 +1,15 @@
 //! Create a renderer from a [`Backend`].
 use crate::backend::{self, Backend};
 use crate::{Primitive, Vector};
+use iced_native::font;
 use iced_native::layout;
 use iced_native::renderer;
 use iced_native::text::{self, Text};
-use iced_native::{Background, Element, Font, Point, Rectangle, Size};
+use iced_native::{
+    Background, Direction, Element, Font, Point, Rectangle, Size,
+};
+
+use std::borrow::Cow;
 
 pub use iced_native::renderer::Style;
 
@@ -57,6 +62,17 @@ where
         layout
     }
 
+    fn direction(&self) -> Direction {
+        self.backend.direction()
+    }
+
+    fn load_font(
+        &mut self,
+        bytes: Vec<u8>,
+    ) -> Result<font::Descriptor, font::Error> {
+        self.backend.load_font(bytes)
+    }
+
     fn with_layer(&mut self, bounds: Rectangle, f: impl FnOnce(&mut Self)) {
         let current_primitives = std::mem::take(&mut self.primitives);
 
@@ -121,20 +137,49 @@ where
     const ICON_FONT: Font = B::ICON_FONT;
     const CHECKMARK_ICON: char = B::CHECKMARK_ICON;
     const ARROW_DOWN_ICON: char = B::ARROW_DOWN_ICON;
+    const ARROW_RIGHT_ICON: char = B::ARROW_RIGHT_ICON;
 
     fn default_size(&self) -> u16 {
         self.backend().default_size()
     }
 
+    fn bold_font() -> Font {
+        Font::Family {
+            name: Cow::Borrowed("sans-serif"),
+            weight: font::Weight::Bold,
+            stretch: font::Stretch::Normal,
+            style: font::Style::Normal,
+        }
+    }
+
+    fn italic_font() -> Font {
+        Font::Family {
+            name: Cow::Borrowed("sans-serif"),
+            weight: font::Weight::Normal,
+            stretch: font::Stretch::Normal,
+            style: font::Style::Italic,
+        }
+    }
+
+    fn monospace_font() -> Font {
+        Font::Family {
+            name: Cow::Borrowed("monospace"),
+            weight: font::Weight::Normal,
+            stretch: font::Stretch::Normal,
+            style: font::Style::Normal,
+        }
+    }
+
     fn measure(
         &self,
         content: &str,
         size: u16,
         font: Font,
         bounds: Size,
+        format: text::Format,
     ) -> (f32, f32) {
         self.backend()
-            .measure(content, f32::from(size), font, bounds)
+            .measure(content, f32::from(size), font, bounds, format)
     }
 
     fn hit_test(
@@ -143,6 +188,7 @@ where
         size: f32,
         font: Font,
         bounds: Size,
+        format: text::Format,
         point: Point,
         nearest_only: bool,
     ) -> Option<text::Hit> {
@@ -151,6 +197,7 @@ where
             size,
             font,
             bounds,
+            format,
             point,
             nearest_only,
         )
@@ -165,6 +212,8 @@ where
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            format: text.format,
+            decorations: text.decorations.to_vec(),
         });
     }
 }
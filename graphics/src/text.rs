@@ -0,0 +1,202 @@
+//! Shape and lay out text.
+mod layout;
+
+pub use layout::Layout;
+
+use iced_native::text::{Decoration, Kind};
+use iced_native::Rectangle;
+
+use ab_glyph::{Font, PxScale, ScaleFont};
+use glyph_brush::{FontId, SectionGlyph};
+use rustybuzz::ttf_parser;
+use unicode_bidi::BidiInfo;
+
+/// Returns true if the character at the given byte index of the content is
+/// part of a right-to-left run, following the rules of the
+/// [Unicode Bidirectional Algorithm].
+///
+/// [Unicode Bidirectional Algorithm]: https://www.unicode.org/reports/tr9/
+pub fn is_right_to_left(content: &str, byte_index: usize) -> bool {
+    let info = BidiInfo::new(content, None);
+
+    info.levels
+        .get(byte_index)
+        .map(|level| level.is_rtl())
+        .unwrap_or(false)
+}
+
+/// Computes the bounds of a [`Decoration`] of some content, given its laid
+/// out glyphs.
+///
+/// A rectangle is produced for every contiguous span of decorated glyphs in
+/// a row. Underlines and strikethroughs are placed using the metrics of the
+/// font, when available.
+pub fn decorate<'a, F: Font>(
+    fonts: &[F],
+    glyphs: impl IntoIterator<Item = &'a SectionGlyph>,
+    content: &str,
+    decoration: &Decoration,
+) -> Vec<Rectangle> {
+    let byte_index = |char_index: usize| {
+        content
+            .char_indices()
+            .nth(char_index)
+            .map(|(i, _)| i)
+            .unwrap_or(content.len())
+    };
+
+    let range =
+        byte_index(decoration.range.start)..byte_index(decoration.range.end);
+
+    let mut spans: Vec<Span> = Vec::new();
+    let mut is_open = false;
+
+    for SectionGlyph {
+        byte_index,
+        glyph,
+        font_id,
+        ..
+    } in glyphs
+    {
+        if !range.contains(byte_index) {
+            is_open = false;
+            continue;
+        }
+
+        let font = fonts[font_id.0].as_scaled(glyph.scale);
+        let left = glyph.position.x;
+        let right = left + font.h_advance(glyph.id);
+        let baseline = glyph.position.y;
+
+        match spans.last_mut() {
+            Some(span)
+                if is_open
+                    && (span.baseline - baseline).abs()
+                        < font.height() / 2.0 =>
+            {
+                span.left = span.left.min(left);
+                span.right = span.right.max(right);
+            }
+            _ => spans.push(Span {
+                left,
+                right,
+                baseline,
+                font_id: *font_id,
+                scale: glyph.scale,
+            }),
+        }
+
+        is_open = true;
+    }
+
+    spans
+        .into_iter()
+        .map(|span| {
+            let font = &fonts[span.font_id.0];
+            let scaled = font.as_scaled(span.scale);
+            let face = ttf_parser::Face::parse(font.font_data(), 0).ok();
+
+            // Lines are positioned by the top of their stroke, relative to
+            // the baseline
+            let line = |metrics: Option<ttf_parser::LineMetrics>,
+                        fallback: f32| {
+                let v_scale = scaled.v_scale_factor();
+
+                match metrics {
+                    Some(metrics) => (
+                        span.baseline - f32::from(metrics.position) * v_scale,
+                        (f32::from(metrics.thickness) * v_scale).max(1.0),
+                    ),
+                    None => (
+                        span.baseline - fallback,
+                        (span.scale.y / 14.0).max(1.0),
+                    ),
+                }
+            };
+
+            let (y, height) = match decoration.kind {
+                Kind::Underline => line(
+                    face.and_then(|face| face.underline_metrics()),
+                    scaled.descent() / 2.0,
+                ),
+                Kind::Strikethrough => line(
+                    face.and_then(|face| face.strikeout_metrics()),
+                    scaled.ascent() / 3.0,
+                ),
+                Kind::Highlight => (
+                    span.baseline - scaled.ascent(),
+                    scaled.ascent() - scaled.descent(),
+                ),
+            };
+
+            Rectangle {
+                x: span.left,
+                y,
+                width: span.right - span.left,
+                height,
+            }
+        })
+        .collect()
+}
+
+/// A contiguous span of decorated glyphs in a row.
+#[derive(Debug)]
+struct Span {
+    left: f32,
+    right: f32,
+    baseline: f32,
+    font_id: FontId,
+    scale: PxScale,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ab_glyph::FontRef;
+    use glyph_brush::{GlyphPositioner, SectionGeometry, SectionText};
+
+    #[test]
+    fn decorations_follow_the_glyphs() {
+        let fonts = [FontRef::try_from_slice(include_bytes!(
+            "../fonts/Lato-Regular.ttf"
+        ))
+        .expect("Load Lato")];
+
+        let content = "Hello world";
+
+        let glyphs = Layout::new(&[]).calculate_glyphs(
+            &fonts,
+            &SectionGeometry {
+                screen_position: (0.0, 0.0),
+                bounds: (f32::INFINITY, f32::INFINITY),
+            },
+            &[SectionText {
+                text: content,
+                scale: PxScale::from(20.0),
+                font_id: FontId(0),
+            }],
+        );
+
+        let baseline = glyphs[0].glyph.position.y;
+        let world = glyphs[6].glyph.position.x;
+
+        let bounds = |decoration| {
+            let bounds = decorate(&fonts, &glyphs, content, &decoration);
+
+            assert_eq!(bounds.len(), 1);
+            bounds[0]
+        };
+
+        let underline = bounds(Decoration::underline(6..11));
+        let strikethrough = bounds(Decoration::strikethrough(6..11));
+        let highlight = bounds(Decoration::highlight(0..5, Default::default()));
+
+        assert_eq!(underline.x, world);
+        assert!(underline.y > baseline);
+        assert!(strikethrough.y < baseline);
+        assert!(highlight.x + highlight.width <= world);
+        assert!(highlight.y < strikethrough.y);
+        assert!(highlight.y + highlight.height > underline.y);
+    }
+}
@@ -0,0 +1,909 @@
+use crate::alignment;
+use iced_native::text;
+
+use ab_glyph::{point, Font, GlyphId, PxScale, Rect, ScaleFont};
+use glyph_brush::{
+    FontId, GlyphPositioner, SectionGeometry, SectionGlyph, SectionText,
+    ToSectionText,
+};
+use std::ops::Range;
+use unicode_bidi::{BidiClass, BidiInfo, Level};
+
+/// A [`GlyphPositioner`] that shapes text.
+///
+/// Every line of a section is split in runs of the same direction following
+/// the [Unicode Bidirectional Algorithm]. Then, the runs are split again
+/// whenever the font of the section is missing a character, in which case the
+/// first [`fallback`] font containing it is used instead. Finally, every run
+/// is shaped with [`rustybuzz`], which takes care of ligatures, kerning, and
+/// complex scripts.
+///
+/// Lines are wrapped in logical order, following the [`format`] of the text,
+/// and then every wrapped line is reordered for display. If the text has more
+/// lines than allowed, the last one is truncated with an ellipsis.
+///
+/// [Unicode Bidirectional Algorithm]: https://www.unicode.org/reports/tr9/
+/// [`fallback`]: Self::fallback
+/// [`format`]: Self::format
+#[derive(Debug, Clone, Copy, PartialEq, Hash)]
+pub struct Layout<'a> {
+    /// The horizontal alignment of the text, relative to the screen position
+    /// of the section.
+    pub horizontal_alignment: alignment::Horizontal,
+
+    /// The vertical alignment of the text, relative to the screen position
+    /// of the section.
+    pub vertical_alignment: alignment::Vertical,
+
+    /// The wrapping, truncation, and spacing of the text.
+    pub format: text::Format,
+
+    /// The fonts to try, in order, when a character is missing.
+    pub fallback: &'a [FontId],
+}
+
+impl<'a> Layout<'a> {
+    /// Creates a new [`Layout`] with the given fallback fonts.
+    ///
+    /// The text is aligned to the top left corner and uses the default
+    /// [`text::Format`].
+    pub fn new(fallback: &'a [FontId]) -> Self {
+        Layout {
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Top,
+            format: text::Format::default(),
+            fallback,
+        }
+    }
+
+    fn select_font<F: Font>(
+        &self,
+        fonts: &[F],
+        requested: FontId,
+        current: Option<FontId>,
+        c: char,
+    ) -> FontId {
+        let contains = |font_id: FontId| {
+            fonts
+                .get(font_id.0)
+                .map(|font| font.glyph_id(c).0 != 0)
+                .unwrap_or(false)
+        };
+
+        // Marks and joiners stay with their base character, so the whole
+        // cluster is shaped together
+        if let Some(current) = current.filter(|_| is_extending(c)) {
+            return current;
+        }
+
+        if contains(requested) {
+            return requested;
+        }
+
+        if let Some(current) = current.filter(|current| contains(*current)) {
+            return current;
+        }
+
+        self.fallback
+            .iter()
+            .copied()
+            .find(|font_id| contains(*font_id))
+            .unwrap_or(requested)
+    }
+
+    fn font_runs<F: Font>(
+        &self,
+        fonts: &[F],
+        requested: FontId,
+        text: &str,
+        range: Range<usize>,
+    ) -> Vec<(FontId, Range<usize>)> {
+        let mut runs: Vec<(FontId, Range<usize>)> = Vec::new();
+
+        for (i, c) in text[range.clone()].char_indices() {
+            let start = range.start + i;
+            let end = start + c.len_utf8();
+
+            let current = runs.last().map(|(font_id, _)| *font_id);
+            let font_id = self.select_font(fonts, requested, current, c);
+
+            match runs.last_mut() {
+                Some((current, run)) if *current == font_id => {
+                    run.end = end;
+                }
+                _ => {
+                    runs.push((font_id, start..end));
+                }
+            }
+        }
+
+        runs
+    }
+
+    fn ellipsis<F: Font>(&self, fonts: &[F], row: &Row) -> Glyph {
+        let font_id = self.select_font(fonts, row.font_id, None, ELLIPSIS);
+        let font = fonts[font_id.0].as_scaled(row.scale);
+        let id = font.glyph_id(ELLIPSIS);
+
+        Glyph {
+            section_index: 0,
+            byte_index: 0,
+            font_id,
+            id,
+            scale: row.scale,
+            offset: (0.0, 0.0),
+            advance: font.h_advance(id),
+            is_whitespace: false,
+            level: row.level,
+        }
+    }
+}
+
+impl<'a> GlyphPositioner for Layout<'a> {
+    fn calculate_glyphs<F, S>(
+        &self,
+        fonts: &[F],
+        geometry: &SectionGeometry,
+        sections: &[S],
+    ) -> Vec<SectionGlyph>
+    where
+        F: Font,
+        S: ToSectionText,
+    {
+        let SectionGeometry {
+            screen_position: (screen_x, screen_y),
+            bounds: (bound_width, bound_height),
+        } = *geometry;
+
+        let mut lines: Vec<Line> = Vec::new();
+        let mut faces = Faces::new(fonts);
+
+        for (section_index, section) in sections.iter().enumerate() {
+            let SectionText {
+                text,
+                scale,
+                font_id,
+            } = section.to_section_text();
+
+            let info = BidiInfo::new(text, None);
+
+            for paragraph in &info.paragraphs {
+                let content = &text[paragraph.range.clone()];
+                let trimmed = content.trim_end_matches(|c| {
+                    unicode_bidi::bidi_class(c) == BidiClass::B
+                });
+
+                let range = paragraph.range.start
+                    ..paragraph.range.start + trimmed.len();
+
+                let mut glyphs = Vec::new();
+
+                for (level, run) in level_runs(&info.levels, text, range) {
+                    for (font_id, range) in
+                        self.font_runs(fonts, font_id, text, run)
+                    {
+                        let mut shaped = shape(
+                            &fonts[font_id.0],
+                            faces.get(font_id),
+                            font_id,
+                            scale,
+                            section_index,
+                            &text[range.clone()],
+                            range.start,
+                            level,
+                        );
+
+                        // Right-to-left runs are shaped in visual order, but
+                        // lines are wrapped in logical order
+                        if level.is_rtl() {
+                            shaped.reverse();
+                        }
+
+                        glyphs.extend(shaped);
+                    }
+                }
+
+                for glyph in &mut glyphs {
+                    // Marks have no advance and stay attached to their base
+                    if glyph.advance > 0.0 {
+                        glyph.advance += self.format.letter_spacing;
+                    }
+                }
+
+                let is_terminated = trimmed.len() < content.len();
+
+                match lines.last_mut() {
+                    Some(line) if !line.is_terminated => {
+                        line.glyphs.extend(glyphs);
+                        line.is_terminated = is_terminated;
+                    }
+                    _ => lines.push(Line {
+                        glyphs,
+                        font_id,
+                        scale,
+                        level: paragraph.level,
+                        is_terminated,
+                    }),
+                }
+            }
+        }
+
+        let mut glyphs = Vec::new();
+        let mut caret_y = screen_y;
+
+        let mut rows: Vec<Row> = lines
+            .into_iter()
+            .flat_map(|line| {
+                line.wrap(fonts, bound_width, self.format.wrapping)
+            })
+            .collect();
+
+        if let Some(max_lines) = self.format.max_lines {
+            let is_truncated = rows.len() > max_lines;
+            rows.truncate(max_lines);
+
+            if let Some(row) = rows.last_mut() {
+                if is_truncated || row.width > bound_width {
+                    let ellipsis = self.ellipsis(fonts, row);
+
+                    row.ellipsize(fonts, ellipsis, bound_width);
+                }
+            }
+        }
+
+        for row in rows {
+            if self.vertical_alignment == alignment::Vertical::Top
+                && caret_y >= screen_y + bound_height
+            {
+                break;
+            }
+
+            let x = match self.horizontal_alignment {
+                alignment::Horizontal::Left => screen_x,
+                alignment::Horizontal::Center => screen_x - row.width / 2.0,
+                alignment::Horizontal::Right => screen_x - row.width,
+            };
+
+            let baseline = caret_y + row.ascent;
+            let line_height = match self.format.line_height {
+                text::LineHeight::Relative(factor) => {
+                    (row.ascent - row.descent + row.line_gap) * factor
+                }
+                text::LineHeight::Absolute(height) => height,
+            };
+
+            glyphs.extend(row.reorder().into_iter().map(|(glyph, glyph_x)| {
+                SectionGlyph {
+                    section_index: glyph.section_index,
+                    byte_index: glyph.byte_index,
+                    glyph: glyph.id.with_scale_and_position(
+                        glyph.scale,
+                        point(
+                            x + glyph_x + glyph.offset.0,
+                            baseline - glyph.offset.1,
+                        ),
+                    ),
+                    font_id: glyph.font_id,
+                }
+            }));
+
+            caret_y += line_height;
+        }
+
+        let shift_up = match self.vertical_alignment {
+            alignment::Vertical::Top => 0.0,
+            alignment::Vertical::Center => (caret_y - screen_y) / 2.0,
+            alignment::Vertical::Bottom => caret_y - screen_y,
+        };
+
+        if shift_up != 0.0 {
+            for glyph in &mut glyphs {
+                glyph.glyph.position.y -= shift_up;
+            }
+        }
+
+        glyphs
+    }
+
+    fn bounds_rect(&self, geometry: &SectionGeometry) -> Rect {
+        let SectionGeometry {
+            screen_position: (x, y),
+            bounds: (width, height),
+        } = *geometry;
+
+        let (min_x, max_x) = match self.horizontal_alignment {
+            alignment::Horizontal::Left => (x, x + width),
+            alignment::Horizontal::Center => (x - width / 2.0, x + width / 2.0),
+            alignment::Horizontal::Right => (x - width, x),
+        };
+
+        let (min_y, max_y) = match self.vertical_alignment {
+            alignment::Vertical::Top => (y, y + height),
+            alignment::Vertical::Center => (y - height / 2.0, y + height / 2.0),
+            alignment::Vertical::Bottom => (y - height, y),
+        };
+
+        Rect {
+            min: point(min_x, min_y),
+            max: point(max_x, max_y),
+        }
+    }
+}
+
+/// A shaped glyph, positioned relative to the pen.
+#[derive(Debug, Clone, Copy)]
+struct Glyph {
+    section_index: usize,
+    byte_index: usize,
+    font_id: FontId,
+    id: GlyphId,
+    scale: PxScale,
+    offset: (f32, f32),
+    advance: f32,
+    is_whitespace: bool,
+    level: Level,
+}
+
+/// A line of glyphs in logical order, before wrapping.
+#[derive(Debug)]
+struct Line {
+    glyphs: Vec<Glyph>,
+    font_id: FontId,
+    scale: PxScale,
+    level: Level,
+    is_terminated: bool,
+}
+
+/// A line of glyphs in logical order that fits in the bounds, if possible.
+#[derive(Debug)]
+struct Row {
+    glyphs: Vec<Glyph>,
+    font_id: FontId,
+    scale: PxScale,
+    level: Level,
+    width: f32,
+    ascent: f32,
+    descent: f32,
+    line_gap: f32,
+}
+
+impl Line {
+    fn wrap<F: Font>(
+        self,
+        fonts: &[F],
+        bound_width: f32,
+        wrapping: text::Wrapping,
+    ) -> Vec<Row> {
+        let Line {
+            glyphs,
+            font_id,
+            scale,
+            level,
+            ..
+        } = self;
+
+        let new_row = || Row {
+            glyphs: Vec::new(),
+            font_id,
+            scale,
+            level,
+            width: 0.0,
+            ascent: 0.0,
+            descent: 0.0,
+            line_gap: 0.0,
+        };
+
+        let mut rows = Vec::new();
+        let mut row = new_row();
+        let mut caret = 0.0;
+
+        let mut glyphs = glyphs.into_iter().peekable();
+
+        while glyphs.peek().is_some() {
+            let mut word = Vec::new();
+            let mut width = 0.0;
+            let mut trimmed_width = 0.0;
+
+            while let Some(glyph) = glyphs.next() {
+                width += glyph.advance;
+
+                if !glyph.is_whitespace {
+                    trimmed_width = width;
+                }
+
+                let is_boundary = match wrapping {
+                    text::Wrapping::None => false,
+                    text::Wrapping::Word => {
+                        glyph.is_whitespace
+                            && matches!(
+                                glyphs.peek(),
+                                Some(next) if !next.is_whitespace
+                            )
+                    }
+                    text::Wrapping::Glyph => matches!(
+                        glyphs.peek(),
+                        Some(next) if next.byte_index != glyph.byte_index
+                    ),
+                };
+
+                word.push(glyph);
+
+                if is_boundary {
+                    break;
+                }
+            }
+
+            if !row.glyphs.is_empty() && caret + trimmed_width > bound_width {
+                row.measure(fonts);
+                rows.push(row);
+
+                row = new_row();
+                caret = 0.0;
+            }
+
+            for glyph in word {
+                row.glyphs.push(glyph);
+                caret += glyph.advance;
+            }
+
+            row.width = caret - (width - trimmed_width);
+        }
+
+        row.measure(fonts);
+        rows.push(row);
+        rows
+    }
+}
+
+impl Row {
+    /// Truncates the end of the [`Row`] so it fits in the given width after
+    /// appending the ellipsis glyph.
+    fn ellipsize<F: Font>(
+        &mut self,
+        fonts: &[F],
+        mut ellipsis: Glyph,
+        bound_width: f32,
+    ) {
+        let available = bound_width - ellipsis.advance;
+        let mut width = 0.0;
+
+        let kept = self
+            .glyphs
+            .iter()
+            .take_while(|glyph| {
+                width += glyph.advance;
+                width <= available
+            })
+            .count();
+
+        if let Some(glyph) =
+            self.glyphs.get(kept).or_else(|| self.glyphs.last())
+        {
+            ellipsis.section_index = glyph.section_index;
+            ellipsis.byte_index = glyph.byte_index;
+        }
+
+        self.glyphs.truncate(kept);
+
+        while matches!(self.glyphs.last(), Some(glyph) if glyph.is_whitespace) {
+            let _ = self.glyphs.pop();
+        }
+
+        self.glyphs.push(ellipsis);
+
+        self.width = self.glyphs.iter().map(|glyph| glyph.advance).sum();
+        self.measure(fonts);
+    }
+
+    /// Reorders the glyphs of the [`Row`] for display, following the rules
+    /// L1 and L2 of the [Unicode Bidirectional Algorithm], and positions them
+    /// relative to the start of the row.
+    ///
+    /// [Unicode Bidirectional Algorithm]: https://www.unicode.org/reports/tr9/#Reordering_Resolved_Levels
+    fn reorder(self) -> Vec<(Glyph, f32)> {
+        let mut glyphs = self.glyphs;
+        let mut trailing_width = 0.0;
+
+        // Trailing whitespace takes the direction of the paragraph
+        for glyph in glyphs.iter_mut().rev() {
+            if !glyph.is_whitespace {
+                break;
+            }
+
+            glyph.level = self.level;
+            trailing_width += glyph.advance;
+        }
+
+        let highest = glyphs.iter().map(|glyph| glyph.level.number()).max();
+
+        let lowest_odd = glyphs
+            .iter()
+            .map(|glyph| glyph.level.number())
+            .filter(|level| level % 2 == 1)
+            .min();
+
+        // From the highest level to the lowest odd level, every sequence of
+        // glyphs at that level or higher is reversed
+        if let (Some(highest), Some(lowest_odd)) = (highest, lowest_odd) {
+            for level in (lowest_odd..=highest).rev() {
+                let mut start = 0;
+
+                while start < glyphs.len() {
+                    if glyphs[start].level.number() < level {
+                        start += 1;
+                        continue;
+                    }
+
+                    let end = glyphs[start..]
+                        .iter()
+                        .position(|glyph| glyph.level.number() < level)
+                        .map_or(glyphs.len(), |length| start + length);
+
+                    glyphs[start..end].reverse();
+                    start = end;
+                }
+            }
+        }
+
+        // The trailing whitespace of a right-to-left row hangs on its left
+        let mut caret = if self.level.is_rtl() {
+            -trailing_width
+        } else {
+            0.0
+        };
+
+        glyphs
+            .into_iter()
+            .map(|glyph| {
+                let x = caret;
+                caret += glyph.advance;
+
+                (glyph, x)
+            })
+            .collect()
+    }
+
+    fn measure<F: Font>(&mut self, fonts: &[F]) {
+        let metrics = |font_id: FontId, scale: PxScale| {
+            let font = fonts[font_id.0].as_scaled(scale);
+
+            (font.ascent(), font.descent(), font.line_gap())
+        };
+
+        let (ascent, descent, line_gap) = self
+            .glyphs
+            .iter()
+            .map(|glyph| metrics(glyph.font_id, glyph.scale))
+            .fold(None, |max: Option<(f32, f32, f32)>, metrics| {
+                Some(max.map_or(metrics, |max| {
+                    (
+                        max.0.max(metrics.0),
+                        max.1.min(metrics.1),
+                        max.2.max(metrics.2),
+                    )
+                }))
+            })
+            .unwrap_or_else(|| metrics(self.font_id, self.scale));
+
+        self.ascent = ascent;
+        self.descent = descent;
+        self.line_gap = line_gap;
+    }
+}
+
+/// The faces of the fonts used by a layout, parsed for shaping the first time
+/// they are needed.
+struct Faces<'a, F> {
+    fonts: &'a [F],
+    faces: Vec<Option<Option<rustybuzz::Face<'a>>>>,
+}
+
+impl<'a, F: Font> Faces<'a, F> {
+    fn new(fonts: &'a [F]) -> Self {
+        Faces {
+            fonts,
+            faces: fonts.iter().map(|_| None).collect(),
+        }
+    }
+
+    fn get(&mut self, font_id: FontId) -> Option<&rustybuzz::Face<'a>> {
+        let font = &self.fonts[font_id.0];
+
+        self.faces[font_id.0]
+            .get_or_insert_with(|| {
+                rustybuzz::Face::from_slice(font.font_data(), 0)
+            })
+            .as_ref()
+    }
+}
+
+/// Splits the given range of some text in runs of the same embedding level,
+/// in logical order.
+fn level_runs(
+    levels: &[Level],
+    text: &str,
+    range: Range<usize>,
+) -> Vec<(Level, Range<usize>)> {
+    let mut runs: Vec<(Level, Range<usize>)> = Vec::new();
+
+    for (i, c) in text[range.clone()].char_indices() {
+        let start = range.start + i;
+        let end = start + c.len_utf8();
+        let level = levels[start];
+
+        match runs.last_mut() {
+            Some((current, run)) if *current == level => {
+                run.end = end;
+            }
+            _ => {
+                runs.push((level, start..end));
+            }
+        }
+    }
+
+    runs
+}
+
+/// Shapes some content, starting at the given byte index of its section, with
+/// a single font and embedding level.
+///
+/// The glyphs are returned in visual order.
+#[allow(clippy::too_many_arguments)]
+fn shape<F: Font>(
+    font: &F,
+    face: Option<&rustybuzz::Face<'_>>,
+    font_id: FontId,
+    scale: PxScale,
+    section_index: usize,
+    content: &str,
+    start: usize,
+    level: Level,
+) -> Vec<Glyph> {
+    let is_right_to_left = level.is_rtl();
+    let scaled = font.as_scaled(scale);
+    let (h_scale, v_scale) = (scaled.h_scale_factor(), scaled.v_scale_factor());
+
+    let mut glyphs = Vec::new();
+
+    let is_whitespace =
+        |cluster: usize| content[cluster..].starts_with(char::is_whitespace);
+
+    if let Some(face) = face {
+        let mut buffer = rustybuzz::UnicodeBuffer::new();
+        buffer.push_str(content);
+        buffer.set_direction(if is_right_to_left {
+            rustybuzz::Direction::RightToLeft
+        } else {
+            rustybuzz::Direction::LeftToRight
+        });
+        buffer.guess_segment_properties();
+
+        let output = rustybuzz::shape(face, &[], buffer);
+
+        for (info, position) in
+            output.glyph_infos().iter().zip(output.glyph_positions())
+        {
+            let cluster = info.cluster as usize;
+
+            glyphs.push(Glyph {
+                section_index,
+                byte_index: start + cluster,
+                font_id,
+                id: GlyphId(info.glyph_id as u16),
+                scale,
+                offset: (
+                    position.x_offset as f32 * h_scale,
+                    position.y_offset as f32 * v_scale,
+                ),
+                advance: position.x_advance as f32 * h_scale,
+                is_whitespace: is_whitespace(cluster),
+                level,
+            });
+        }
+    } else {
+        // The font could not be parsed for shaping, so we lay out its
+        // characters one by one
+        let characters = content.char_indices().map(|(i, c)| (start + i, c));
+
+        let mut push = |(byte_index, c): (usize, char)| {
+            let id = scaled.glyph_id(c);
+
+            glyphs.push(Glyph {
+                section_index,
+                byte_index,
+                font_id,
+                id,
+                scale,
+                offset: (0.0, 0.0),
+                advance: scaled.h_advance(id),
+                is_whitespace: c.is_whitespace(),
+                level,
+            });
+        };
+
+        if is_right_to_left {
+            characters.rev().for_each(&mut push);
+        } else {
+            characters.for_each(&mut push);
+        }
+    }
+
+    glyphs
+}
+
+/// The character appended to truncated lines.
+const ELLIPSIS: char = '…';
+
+/// Returns true if the character extends the cluster of the previous one.
+fn is_extending(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200C}'..='\u{200D}'
+            | '\u{FE00}'..='\u{FE0F}'
+            | '\u{1F3FB}'..='\u{1F3FF}'
+            | '\u{E0020}'..='\u{E007F}'
+            | '\u{E0100}'..='\u{E01EF}'
+    ) || unicode_bidi::bidi_class(c) == BidiClass::NSM
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use ab_glyph::FontRef;
+
+    fn lato() -> FontRef<'static> {
+        FontRef::try_from_slice(include_bytes!("../../fonts/Lato-Regular.ttf"))
+            .expect("Load Lato")
+    }
+
+    fn layout(content: &str, width: f32) -> Vec<SectionGlyph> {
+        layout_with_format(text::Format::default(), content, width)
+    }
+
+    fn layout_with_format(
+        format: text::Format,
+        content: &str,
+        width: f32,
+    ) -> Vec<SectionGlyph> {
+        let layout = Layout {
+            format,
+            ..Layout::new(&[])
+        };
+
+        layout.calculate_glyphs(
+            &[lato()],
+            &SectionGeometry {
+                screen_position: (0.0, 0.0),
+                bounds: (width, f32::INFINITY),
+            },
+            &[SectionText {
+                text: content,
+                scale: PxScale::from(20.0),
+                font_id: FontId(0),
+            }],
+        )
+    }
+
+    fn rows(glyphs: &[SectionGlyph]) -> usize {
+        let mut baselines: Vec<f32> =
+            glyphs.iter().map(|glyph| glyph.glyph.position.y).collect();
+
+        baselines.dedup();
+        baselines.len()
+    }
+
+    #[test]
+    fn wraps_at_whitespace() {
+        let glyphs = layout("Hello world", 60.0);
+
+        let first = glyphs.first().expect("First glyph");
+        let last = glyphs.last().expect("Last glyph");
+
+        assert_eq!(last.byte_index, 10);
+        assert!(last.glyph.position.y > first.glyph.position.y);
+        assert!(last.glyph.position.x < 60.0);
+    }
+
+    #[test]
+    fn right_to_left_runs_are_reversed() {
+        let glyphs = layout("ab אב", f32::INFINITY);
+
+        let byte_indices: Vec<usize> =
+            glyphs.iter().map(|glyph| glyph.byte_index).collect();
+
+        assert_eq!(byte_indices, vec![0, 1, 2, 5, 3]);
+    }
+
+    #[test]
+    fn wraps_right_to_left_lines_in_logical_order() {
+        // Every word takes a whole row
+        let glyphs = layout("אב גד", 1.0);
+
+        let mut rows: Vec<(f32, Vec<(f32, usize)>)> = Vec::new();
+
+        for glyph in &glyphs {
+            let position = glyph.glyph.position;
+
+            match rows.last_mut() {
+                Some((y, row)) if *y == position.y => {
+                    row.push((position.x, glyph.byte_index))
+                }
+                _ => rows
+                    .push((position.y, vec![(position.x, glyph.byte_index)])),
+            }
+        }
+
+        let byte_indices: Vec<Vec<usize>> = rows
+            .into_iter()
+            .map(|(_, row)| row.into_iter().map(|(_, index)| index).collect())
+            .collect();
+
+        // The first word is on the first row, laid out from right to left,
+        // and its trailing whitespace hangs on the left
+        assert_eq!(byte_indices, vec![vec![4, 2, 0], vec![7, 5]]);
+    }
+
+    #[test]
+    fn wraps_at_any_glyph() {
+        let format = text::Format {
+            wrapping: text::Wrapping::Glyph,
+            ..text::Format::default()
+        };
+
+        let glyphs = layout_with_format(format, "Helloworld", 30.0);
+
+        assert!(rows(&glyphs) > 1);
+        assert!(glyphs.iter().all(|glyph| glyph.glyph.position.x < 30.0));
+    }
+
+    #[test]
+    fn does_not_wrap_without_wrapping() {
+        let format = text::Format {
+            wrapping: text::Wrapping::None,
+            ..text::Format::default()
+        };
+
+        let glyphs = layout_with_format(format, "Hello world", 30.0);
+
+        assert_eq!(rows(&glyphs), 1);
+    }
+
+    #[test]
+    fn truncates_with_ellipsis() {
+        let format = text::Format {
+            max_lines: Some(1),
+            ..text::Format::default()
+        };
+
+        let glyphs = layout_with_format(format, "Hello world, hello", 80.0);
+        let ellipsis = lato().glyph_id(ELLIPSIS);
+        let last = glyphs.last().expect("Last glyph");
+
+        assert_eq!(rows(&glyphs), 1);
+        assert_eq!(last.glyph.id, ellipsis);
+        assert!(last.glyph.position.x < 80.0);
+    }
+
+    #[test]
+    fn spaces_lines_with_line_height() {
+        let baselines = |line_height| {
+            let format = text::Format {
+                line_height,
+                ..text::Format::default()
+            };
+
+            let glyphs = layout_with_format(format, "A\nB", f32::INFINITY);
+
+            glyphs[1].glyph.position.y - glyphs[0].glyph.position.y
+        };
+
+        let natural = baselines(text::LineHeight::Relative(1.0));
+
+        let absolute = baselines(text::LineHeight::Absolute(50.0));
+        let relative = baselines(text::LineHeight::Relative(2.0));
+
+        assert!((absolute - 50.0).abs() < 0.01);
+        assert!((relative - natural * 2.0).abs() < 0.01);
+    }
+}
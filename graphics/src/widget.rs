@@ -8,18 +8,30 @@
 //! use iced_graphics::{button, Button};
 //! ```
 pub mod button;
+pub mod carousel;
 pub mod checkbox;
+pub mod collapsible;
+pub mod color_picker;
 pub mod container;
+pub mod date_picker;
+pub mod drag_and_drop;
 pub mod image;
+pub mod mouse_area;
+pub mod number_input;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
+pub mod progress_ring;
 pub mod radio;
+pub mod radio_group;
+pub mod range_slider;
+pub mod reorderable_list;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod svg;
 pub mod text_input;
+pub mod time_picker;
 pub mod toggler;
 pub mod tooltip;
 
@@ -31,18 +43,40 @@ mod text;
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use carousel::Carousel;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
 #[doc(no_inline)]
+pub use collapsible::{Accordion, Collapsible};
+#[doc(no_inline)]
+pub use color_picker::ColorPicker;
+#[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use drag_and_drop::{DragSource, DropTarget};
+#[doc(no_inline)]
+pub use mouse_area::MouseArea;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
+#[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
 pub use pick_list::PickList;
 #[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
+pub use progress_ring::ProgressRing;
+#[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use radio_group::RadioGroup;
+#[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
+pub use reorderable_list::ReorderableList;
+#[doc(no_inline)]
 pub use rule::Rule;
 #[doc(no_inline)]
 pub use scrollable::Scrollable;
@@ -51,6 +85,8 @@ pub use slider::Slider;
 #[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
@@ -70,6 +106,30 @@ pub mod canvas;
 #[doc(no_inline)]
 pub use canvas::Canvas;
 
+#[cfg(feature = "canvas")]
+#[cfg_attr(docsrs, doc(cfg(feature = "canvas")))]
+pub mod chart;
+
+#[cfg(feature = "canvas")]
+#[doc(no_inline)]
+pub use chart::Chart;
+
+#[cfg(feature = "code_editor")]
+#[cfg_attr(docsrs, doc(cfg(feature = "code_editor")))]
+pub mod code_editor;
+
+#[cfg(feature = "code_editor")]
+#[doc(no_inline)]
+pub use code_editor::CodeEditor;
+
+#[cfg(feature = "markdown")]
+#[cfg_attr(docsrs, doc(cfg(feature = "markdown")))]
+pub mod markdown;
+
+#[cfg(feature = "markdown")]
+#[doc(no_inline)]
+pub use markdown::Markdown;
+
 #[cfg(feature = "qr_code")]
 #[cfg_attr(docsrs, doc(cfg(feature = "qr_code")))]
 pub mod qr_code;
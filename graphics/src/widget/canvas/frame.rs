@@ -227,6 +227,8 @@ impl Frame {
             font: text.font,
             horizontal_alignment: text.horizontal_alignment,
             vertical_alignment: text.vertical_alignment,
+            format: Default::default(),
+            decorations: Vec::new(),
         });
     }
 
@@ -0,0 +1,1019 @@
+//! Plot series of data points with axes and a legend.
+//!
+//! A [`Chart`] can be zoomed with the mouse wheel and panned by dragging it.
+//! A right click fits it back to its data.
+use crate::canvas::{self, Frame, Path, Stroke};
+use crate::renderer::{self, Renderer};
+use crate::{backend, Backend};
+
+use iced_native::alignment;
+use iced_native::event::{self, Event};
+use iced_native::layout;
+use iced_native::mouse;
+use iced_native::text;
+use iced_native::{
+    Clipboard, Color, Element, Font, Hasher, Layout, Length, Point, Rectangle,
+    Shell, Size, Vector, Widget,
+};
+use std::cell::RefCell;
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+
+mod axis;
+mod series;
+
+pub use iced_style::chart::{Style, StyleSheet};
+pub use series::{Kind, Series};
+
+use axis::Range;
+
+const DEFAULT_TEXT_SIZE: u16 = 14;
+const MARGIN: f32 = 8.0;
+const TICK_LENGTH: f32 = 4.0;
+const LABEL_SPACING: f32 = 4.0;
+const LEGEND_SPACING: f32 = 16.0;
+
+/// The approximate distance between ticks, in pixels.
+const X_TICK_SPACING: f32 = 100.0;
+const Y_TICK_SPACING: f32 = 50.0;
+
+const LINE_WIDTH: f32 = 2.0;
+const DOT_RADIUS: f32 = 3.0;
+const AREA_ALPHA: f32 = 0.3;
+
+/// The fraction of the space between data points covered by bars.
+const BAR_FILL: f32 = 0.8;
+
+/// The zoom factor applied by every line scrolled with the mouse wheel.
+const ZOOM_STEP: f32 = 1.2;
+const PIXELS_PER_LINE: f32 = 60.0;
+
+const TOOLTIP_OFFSET: f32 = 12.0;
+const TOOLTIP_PADDING: f32 = 6.0;
+
+/// A widget that plots series of data points along automatically computed
+/// axes.
+///
+/// The axes and the legend are cached, so streaming data only redraws the
+/// series as long as the ticks of the axes stay the same.
+///
+/// # Example
+/// ```
+/// # use iced_graphics::chart::{self, Chart, Series};
+/// #
+/// let mut state = chart::State::new();
+/// let temperature = vec![(0.0, 21.5), (1.0, 22.1), (2.0, 21.8)];
+/// let humidity = vec![(0.0, 40.0), (1.0, 42.5), (2.0, 41.0)];
+///
+/// let chart = Chart::new(&mut state)
+///     .push(Series::line("Temperature", &temperature))
+///     .push(Series::bar("Humidity", &humidity));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Chart<'a> {
+    state: &'a mut State,
+    series: Vec<Series<'a>>,
+    width: Length,
+    height: Length,
+    x_range: Option<RangeInclusive<f32>>,
+    y_range: Option<RangeInclusive<f32>>,
+    text_size: u16,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a> Chart<'a> {
+    /// Creates a new empty [`Chart`] with the given [`State`].
+    pub fn new(state: &'a mut State) -> Self {
+        Chart {
+            state,
+            series: Vec::new(),
+            width: Length::Fill,
+            height: Length::Units(300),
+            x_range: None,
+            y_range: None,
+            text_size: DEFAULT_TEXT_SIZE,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Adds a [`Series`] to the [`Chart`].
+    pub fn push(mut self, series: Series<'a>) -> Self {
+        self.series.push(series);
+        self
+    }
+
+    /// Sets the width of the [`Chart`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Chart`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the range of the horizontal axis of the [`Chart`].
+    ///
+    /// By default, the range is computed from the data.
+    pub fn x_range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.x_range = Some(range);
+        self
+    }
+
+    /// Sets the range of the vertical axis of the [`Chart`].
+    ///
+    /// By default, the range is computed from the data.
+    pub fn y_range(mut self, range: RangeInclusive<f32>) -> Self {
+        self.y_range = Some(range);
+        self
+    }
+
+    /// Sets the text size of the labels of the [`Chart`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = size;
+        self
+    }
+
+    /// Sets the style of the [`Chart`].
+    pub fn style(mut self, style: impl Into<Box<dyn StyleSheet + 'a>>) -> Self {
+        self.style_sheet = style.into();
+        self
+    }
+
+    /// Returns the ranges of the axes, either chosen by the user or fitted to
+    /// the data.
+    fn ranges(&self, size: Size) -> (Range, Range) {
+        if let Some(view) = self.state.view {
+            return view;
+        }
+
+        let fixed = |range: &Option<RangeInclusive<f32>>| {
+            range
+                .as_ref()
+                .map(|range| Range::new(*range.start(), *range.end()))
+        };
+
+        let points = || self.series.iter().flat_map(|series| series.data);
+
+        let x = fixed(&self.x_range).unwrap_or_else(|| {
+            let range = Range::fit(points().map(|(x, _)| *x))
+                .unwrap_or_else(|| Range::new(0.0, 1.0));
+
+            // Leave room for the bars at both ends
+            let range = match self.bar_gap() {
+                Some(gap) => {
+                    Range::new(range.min - gap / 2.0, range.max + gap / 2.0)
+                }
+                None => range,
+            };
+
+            range.nice(tick_count(size.width, X_TICK_SPACING))
+        });
+
+        let y = fixed(&self.y_range).unwrap_or_else(|| {
+            let range = Range::fit(points().map(|(_, y)| *y))
+                .unwrap_or_else(|| Range::new(0.0, 1.0));
+
+            let has_baseline = self
+                .series
+                .iter()
+                .any(|series| matches!(series.kind, Kind::Area | Kind::Bar));
+
+            let range = if has_baseline {
+                range.include(0.0)
+            } else {
+                range
+            };
+
+            range.nice(tick_count(size.height, Y_TICK_SPACING))
+        });
+
+        (x, y)
+    }
+
+    /// Returns the smallest space between the bars of any bar series, in data
+    /// units.
+    fn bar_gap(&self) -> Option<f32> {
+        let bars = self.series.iter().filter(|series| series.kind == Kind::Bar);
+
+        let mut has_bars = false;
+        let mut gap: Option<f32> = None;
+
+        for series in bars {
+            has_bars = true;
+
+            if let Some(series_gap) = series.min_gap() {
+                gap = Some(
+                    gap.map(|gap| gap.min(series_gap)).unwrap_or(series_gap),
+                );
+            }
+        }
+
+        if has_bars {
+            Some(gap.unwrap_or(1.0))
+        } else {
+            None
+        }
+    }
+
+    fn colors(&self) -> Vec<Color> {
+        self.series
+            .iter()
+            .enumerate()
+            .map(|(i, series)| {
+                series
+                    .color
+                    .unwrap_or_else(|| self.style_sheet.series_color(i))
+            })
+            .collect()
+    }
+
+    fn has_legend(&self) -> bool {
+        self.series.iter().any(|series| !series.label.is_empty())
+    }
+
+    /// Computes the [`Plot`] of the [`Chart`] for the given size.
+    fn plot<B>(&self, renderer: &Renderer<B>, size: Size) -> Plot
+    where
+        B: Backend + backend::Text,
+    {
+        use text::Renderer as _;
+
+        let (x, y) = self.ranges(size);
+        let x_ticks = x.ticks(tick_count(size.width, X_TICK_SPACING));
+        let y_ticks = y.ticks(tick_count(size.height, Y_TICK_SPACING));
+
+        let label_width = |(ticks, step): &(Vec<f32>, f32)| {
+            ticks
+                .iter()
+                .map(|value| {
+                    renderer.measure_width(
+                        &axis::format(*value, *step),
+                        self.text_size,
+                        Font::Default,
+                    )
+                })
+                .fold(0.0, f32::max)
+        };
+
+        let text_size = f32::from(self.text_size);
+
+        let left = MARGIN + label_width(&y_ticks) + LABEL_SPACING + TICK_LENGTH;
+        let right = MARGIN + label_width(&x_ticks) / 2.0;
+        let bottom = TICK_LENGTH + LABEL_SPACING + text_size + MARGIN;
+        let top = if self.has_legend() {
+            MARGIN + text_size + MARGIN
+        } else {
+            MARGIN
+        };
+
+        Plot {
+            bounds: Rectangle {
+                x: left,
+                y: top,
+                width: (size.width - left - right).max(1.0),
+                height: (size.height - top - bottom).max(1.0),
+            },
+            x,
+            y,
+            x_ticks,
+            y_ticks,
+        }
+    }
+
+    /// Draws the background, the axes, their ticks and the legend.
+    fn draw_axes(
+        &self,
+        frame: &mut Frame,
+        plot: &Plot,
+        legend: &[LegendItem],
+        style: &Style,
+    ) {
+        let text_size = f32::from(self.text_size);
+        let bounds = plot.bounds;
+
+        if let Some(background) = style.background {
+            frame.fill_rectangle(Point::ORIGIN, frame.size(), background);
+        }
+
+        let (x_ticks, x_step) = &plot.x_ticks;
+        let (y_ticks, y_step) = &plot.y_ticks;
+
+        let grid = Path::new(|builder| {
+            for x in x_ticks {
+                let x = plot.project((*x, plot.y.min)).x;
+
+                builder.move_to(Point::new(x, bounds.y));
+                builder.line_to(Point::new(x, bounds.y + bounds.height));
+            }
+
+            for y in y_ticks {
+                let y = plot.project((plot.x.min, *y)).y;
+
+                builder.move_to(Point::new(bounds.x, y));
+                builder.line_to(Point::new(bounds.x + bounds.width, y));
+            }
+        });
+
+        frame.stroke(
+            &grid,
+            Stroke::default()
+                .with_color(style.grid_color)
+                .with_width(1.0),
+        );
+
+        let axes = Path::new(|builder| {
+            let bottom = bounds.y + bounds.height;
+
+            builder.move_to(Point::new(bounds.x, bounds.y));
+            builder.line_to(Point::new(bounds.x, bottom));
+            builder.line_to(Point::new(bounds.x + bounds.width, bottom));
+
+            for x in x_ticks {
+                let x = plot.project((*x, plot.y.min)).x;
+
+                builder.move_to(Point::new(x, bottom));
+                builder.line_to(Point::new(x, bottom + TICK_LENGTH));
+            }
+
+            for y in y_ticks {
+                let y = plot.project((plot.x.min, *y)).y;
+
+                builder.move_to(Point::new(bounds.x - TICK_LENGTH, y));
+                builder.line_to(Point::new(bounds.x, y));
+            }
+        });
+
+        frame.stroke(
+            &axes,
+            Stroke::default()
+                .with_color(style.axis_color)
+                .with_width(1.0),
+        );
+
+        let label = |content: String, position: Point| canvas::Text {
+            content,
+            position,
+            color: style.label_color,
+            size: text_size,
+            ..canvas::Text::default()
+        };
+
+        for x in x_ticks {
+            let position = Point::new(
+                plot.project((*x, plot.y.min)).x,
+                bounds.y + bounds.height + TICK_LENGTH + LABEL_SPACING,
+            );
+
+            frame.fill_text(canvas::Text {
+                horizontal_alignment: alignment::Horizontal::Center,
+                ..label(axis::format(*x, *x_step), position)
+            });
+        }
+
+        for y in y_ticks {
+            let position = Point::new(
+                bounds.x - TICK_LENGTH - LABEL_SPACING,
+                plot.project((plot.x.min, *y)).y,
+            );
+
+            frame.fill_text(canvas::Text {
+                horizontal_alignment: alignment::Horizontal::Right,
+                vertical_alignment: alignment::Vertical::Center,
+                ..label(axis::format(*y, *y_step), position)
+            });
+        }
+
+        let swatch_size = (text_size * 0.8).round();
+        let center = MARGIN + text_size / 2.0;
+
+        for item in legend {
+            frame.fill_rectangle(
+                Point::new(item.x, center - swatch_size / 2.0),
+                Size::new(swatch_size, swatch_size),
+                item.color,
+            );
+
+            frame.fill_text(canvas::Text {
+                vertical_alignment: alignment::Vertical::Center,
+                ..label(
+                    item.label.clone(),
+                    Point::new(item.x + swatch_size + LABEL_SPACING, center),
+                )
+            });
+        }
+    }
+
+    /// Draws every series, clipped later to the bounds of the [`Plot`].
+    fn draw_series(&self, frame: &mut Frame, plot: &Plot, colors: &[Color]) {
+        let baseline = plot
+            .project((plot.x.min, 0.0_f32.clamp(plot.y.min, plot.y.max)))
+            .y;
+
+        let bar_count = self
+            .series
+            .iter()
+            .filter(|series| series.kind == Kind::Bar)
+            .count();
+
+        let bar_width = self
+            .bar_gap()
+            .map(|gap| {
+                gap / plot.x.span() * plot.bounds.width * BAR_FILL
+                    / bar_count as f32
+            })
+            .unwrap_or(0.0);
+
+        let mut bar_index = 0;
+
+        for (series, color) in self.series.iter().zip(colors) {
+            let points: Vec<Point> = series
+                .data
+                .iter()
+                .filter(|(x, y)| x.is_finite() && y.is_finite())
+                .map(|point| plot.project(*point))
+                .collect();
+
+            match series.kind {
+                Kind::Line => {
+                    frame.stroke(&polyline(&points), line_stroke(*color));
+                }
+                Kind::Area => {
+                    if let (Some(first), Some(last)) =
+                        (points.first(), points.last())
+                    {
+                        let area = Path::new(|builder| {
+                            builder.move_to(Point::new(first.x, baseline));
+
+                            for point in &points {
+                                builder.line_to(*point);
+                            }
+
+                            builder.line_to(Point::new(last.x, baseline));
+                            builder.close();
+                        });
+
+                        frame.fill(
+                            &area,
+                            Color {
+                                a: color.a * AREA_ALPHA,
+                                ..*color
+                            },
+                        );
+                    }
+
+                    frame.stroke(&polyline(&points), line_stroke(*color));
+                }
+                Kind::Bar => {
+                    let offset = (bar_index as f32
+                        - (bar_count - 1) as f32 / 2.0)
+                        * bar_width;
+
+                    for point in &points {
+                        frame.fill_rectangle(
+                            Point::new(
+                                point.x + offset - bar_width / 2.0,
+                                point.y.min(baseline),
+                            ),
+                            Size::new(bar_width, (point.y - baseline).abs()),
+                            *color,
+                        );
+                    }
+
+                    bar_index += 1;
+                }
+                Kind::Scatter => {
+                    let dots = Path::new(|builder| {
+                        for point in &points {
+                            builder.circle(*point, DOT_RADIUS);
+                        }
+                    });
+
+                    frame.fill(&dots, *color);
+                }
+            }
+        }
+    }
+
+    /// Draws the crosshair at the given position and a tooltip with the
+    /// closest value of every series.
+    fn draw_hover(
+        &self,
+        frame: &mut Frame,
+        plot: &Plot,
+        position: Point,
+        tooltip: &Tooltip,
+        style: &Style,
+    ) {
+        let bounds = plot.bounds;
+        let text_size = f32::from(self.text_size);
+
+        let crosshair = Path::new(|builder| {
+            builder.move_to(Point::new(position.x, bounds.y));
+            builder.line_to(Point::new(position.x, bounds.y + bounds.height));
+            builder.move_to(Point::new(bounds.x, position.y));
+            builder.line_to(Point::new(bounds.x + bounds.width, position.y));
+        });
+
+        frame.stroke(
+            &crosshair,
+            Stroke::default()
+                .with_color(style.crosshair_color)
+                .with_width(1.0),
+        );
+
+        for entry in &tooltip.entries {
+            let point = plot.project(entry.point);
+
+            if bounds.contains(point) {
+                frame.fill(&Path::circle(point, DOT_RADIUS + 1.0), entry.color);
+            }
+        }
+
+        let line_height = text_size + LABEL_SPACING;
+        let size = Size::new(
+            tooltip.width + 2.0 * TOOLTIP_PADDING,
+            line_height * (tooltip.entries.len() + 1) as f32 - LABEL_SPACING
+                + 2.0 * TOOLTIP_PADDING,
+        );
+
+        // Keep the tooltip inside the plot, flipping it around the cursor
+        let x = if position.x + TOOLTIP_OFFSET + size.width
+            > bounds.x + bounds.width
+        {
+            position.x - TOOLTIP_OFFSET - size.width
+        } else {
+            position.x + TOOLTIP_OFFSET
+        };
+
+        let y = if position.y + TOOLTIP_OFFSET + size.height
+            > bounds.y + bounds.height
+        {
+            position.y - TOOLTIP_OFFSET - size.height
+        } else {
+            position.y + TOOLTIP_OFFSET
+        };
+
+        let background = Path::rectangle(Point::new(x, y), size);
+
+        frame.fill(&background, style.tooltip_background);
+        frame.stroke(
+            &background,
+            Stroke::default()
+                .with_color(style.tooltip_border_color)
+                .with_width(1.0),
+        );
+
+        let text = |content: String, position: Point| canvas::Text {
+            content,
+            position,
+            color: style.tooltip_text_color,
+            size: text_size,
+            ..canvas::Text::default()
+        };
+
+        let left = x + TOOLTIP_PADDING;
+        let top = y + TOOLTIP_PADDING;
+
+        frame.fill_text(text(tooltip.header.clone(), Point::new(left, top)));
+
+        let swatch_size = (text_size * 0.6).round();
+
+        for (i, entry) in tooltip.entries.iter().enumerate() {
+            let top = top + line_height * (i + 1) as f32;
+
+            frame.fill_rectangle(
+                Point::new(left, top + (text_size - swatch_size) / 2.0),
+                Size::new(swatch_size, swatch_size),
+                entry.color,
+            );
+
+            frame.fill_text(text(
+                entry.text.clone(),
+                Point::new(left + swatch_size + LABEL_SPACING, top),
+            ));
+        }
+    }
+}
+
+impl<'a, Message, B> Widget<Message, Renderer<B>> for Chart<'a>
+where
+    B: Backend + backend::Text,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer<B>,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer<B>,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let plot = self.plot(renderer, bounds.size());
+        let position = cursor_position - Vector::new(bounds.x, bounds.y);
+        let is_over =
+            bounds.contains(cursor_position) && plot.bounds.contains(position);
+
+        match event {
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) if is_over => {
+                let lines = match delta {
+                    mouse::ScrollDelta::Lines { y, .. } => y,
+                    mouse::ScrollDelta::Pixels { y, .. } => y / PIXELS_PER_LINE,
+                };
+
+                let factor = ZOOM_STEP.powf(-lines);
+                let (x, y) = plot.unproject(position);
+
+                self.state.view =
+                    Some((plot.x.zoom(x, factor), plot.y.zoom(y, factor)));
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+                if is_over =>
+            {
+                self.state.drag = Some(cursor_position);
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                match self.state.drag {
+                    Some(origin) => {
+                        // The origin is a translated cursor position, so the
+                        // delta must be computed from one too
+                        let delta = cursor_position - origin;
+
+                        let x = delta.x / plot.bounds.width * plot.x.span();
+                        let y = delta.y / plot.bounds.height * plot.y.span();
+
+                        self.state.view = Some((plot.x.pan(-x), plot.y.pan(y)));
+                        self.state.drag = Some(cursor_position);
+
+                        event::Status::Captured
+                    }
+                    None => event::Status::Ignored,
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+                if self.state.drag.is_some() =>
+            {
+                self.state.drag = None;
+
+                event::Status::Captured
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right))
+                if is_over && self.state.view.is_some() =>
+            {
+                self.state.view = None;
+
+                event::Status::Captured
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer<B>,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        if self.state.drag.is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if !bounds.contains(cursor_position) {
+            return mouse::Interaction::default();
+        }
+
+        let plot = self.plot(renderer, bounds.size());
+        let position = cursor_position - Vector::new(bounds.x, bounds.y);
+
+        if plot.bounds.contains(position) {
+            mouse::Interaction::Crosshair
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer<B>,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        use iced_native::Renderer as _;
+        use text::Renderer as _;
+
+        let bounds = layout.bounds();
+        let size = bounds.size();
+
+        let plot = self.plot(renderer, size);
+        let style = self.style_sheet.style();
+        let colors = self.colors();
+
+        let text_size = f32::from(self.text_size);
+        let swatch_size = (text_size * 0.8).round();
+
+        let mut legend = Vec::new();
+        let mut x = plot.bounds.x;
+
+        for (series, color) in self.series.iter().zip(&colors) {
+            if series.label.is_empty() {
+                continue;
+            }
+
+            legend.push(LegendItem {
+                label: series.label.clone(),
+                color: *color,
+                x,
+            });
+
+            x += swatch_size
+                + LABEL_SPACING
+                + renderer.measure_width(
+                    &series.label,
+                    self.text_size,
+                    Font::Default,
+                )
+                + LEGEND_SPACING;
+        }
+
+        let axes = {
+            let key = Key {
+                x: plot.x,
+                y: plot.y,
+                legend: legend
+                    .iter()
+                    .map(|item| (item.label.clone(), item.color))
+                    .collect(),
+                text_size: self.text_size,
+                style,
+            };
+
+            let mut layer = self.state.axes.borrow_mut();
+
+            if layer.key.as_ref() != Some(&key) {
+                layer.cache.clear();
+                layer.key = Some(key);
+            }
+
+            layer.cache.draw(size, |frame| {
+                self.draw_axes(frame, &plot, &legend, &style)
+            })
+        };
+
+        let series = {
+            let mut frame = Frame::new(size);
+            self.draw_series(&mut frame, &plot, &colors);
+
+            frame.into_geometry()
+        };
+
+        let position = cursor_position - Vector::new(bounds.x, bounds.y);
+
+        let hover = if self.state.drag.is_none()
+            && bounds.contains(cursor_position)
+            && plot.bounds.contains(position)
+        {
+            let (x, _) = plot.unproject(position);
+            let precision = plot.y_ticks.1 / 10.0;
+
+            let entries: Vec<TooltipEntry> = self
+                .series
+                .iter()
+                .zip(&colors)
+                .filter_map(|(series, color)| {
+                    let point = series.closest(x)?;
+                    let value = axis::format(point.1, precision);
+
+                    Some(TooltipEntry {
+                        text: if series.label.is_empty() {
+                            value
+                        } else {
+                            format!("{}: {}", series.label, value)
+                        },
+                        color: *color,
+                        point,
+                    })
+                })
+                .collect();
+
+            let header = axis::format(x, plot.x_ticks.1 / 10.0);
+            let swatch_size = (text_size * 0.6).round();
+
+            let width = entries
+                .iter()
+                .map(|entry| {
+                    swatch_size
+                        + LABEL_SPACING
+                        + renderer.measure_width(
+                            &entry.text,
+                            self.text_size,
+                            Font::Default,
+                        )
+                })
+                .fold(
+                    renderer.measure_width(
+                        &header,
+                        self.text_size,
+                        Font::Default,
+                    ),
+                    f32::max,
+                );
+
+            let tooltip = Tooltip {
+                header,
+                entries,
+                width,
+            };
+
+            let mut frame = Frame::new(size);
+            self.draw_hover(&mut frame, &plot, position, &tooltip, &style);
+
+            Some(frame.into_geometry())
+        } else {
+            None
+        };
+
+        renderer.with_translation(
+            Vector::new(bounds.x, bounds.y),
+            |renderer| {
+                renderer.draw_primitive(axes.into_primitive());
+
+                renderer.with_layer(plot.bounds, |renderer| {
+                    renderer.draw_primitive(series.into_primitive());
+                });
+
+                // The crosshair and the tooltip need their own layer to be drawn
+                // on top of the clipped series
+                if let Some(hover) = hover {
+                    renderer.with_layer(
+                        Rectangle::with_size(size),
+                        |renderer| {
+                            renderer.draw_primitive(hover.into_primitive());
+                        },
+                    );
+                }
+            },
+        );
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+    }
+}
+
+impl<'a, Message, B> From<Chart<'a>> for Element<'a, Message, Renderer<B>>
+where
+    B: Backend + backend::Text,
+{
+    fn from(chart: Chart<'a>) -> Element<'a, Message, Renderer<B>> {
+        Element::new(chart)
+    }
+}
+
+/// The local state of a [`Chart`].
+#[derive(Debug, Default)]
+pub struct State {
+    view: Option<(Range, Range)>,
+    drag: Option<Point>,
+    axes: RefCell<Layer>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`Chart`] has been zoomed or panned away from its
+    /// data.
+    pub fn is_zoomed(&self) -> bool {
+        self.view.is_some()
+    }
+
+    /// Fits the [`Chart`] back to its data, undoing any zooming or panning.
+    pub fn reset(&mut self) {
+        self.view = None;
+    }
+}
+
+/// A cached layer of a [`Chart`], which is redrawn only when its [`Key`]
+/// changes.
+#[derive(Debug, Default)]
+struct Layer {
+    key: Option<Key>,
+    cache: canvas::Cache,
+}
+
+/// Everything the axes and the legend of a [`Chart`] depend on, besides its
+/// size.
+#[derive(Debug, PartialEq)]
+struct Key {
+    x: Range,
+    y: Range,
+    legend: Vec<(String, Color)>,
+    text_size: u16,
+    style: Style,
+}
+
+/// The area of a [`Chart`] where its series are plotted, in local
+/// coordinates, and the ranges of its axes.
+struct Plot {
+    bounds: Rectangle,
+    x: Range,
+    y: Range,
+    x_ticks: (Vec<f32>, f32),
+    y_ticks: (Vec<f32>, f32),
+}
+
+impl Plot {
+    /// Returns the position of a data point.
+    fn project(&self, (x, y): (f32, f32)) -> Point {
+        Point::new(
+            self.bounds.x + self.x.normalize(x) * self.bounds.width,
+            self.bounds.y + (1.0 - self.y.normalize(y)) * self.bounds.height,
+        )
+    }
+
+    /// Returns the data point at a position.
+    fn unproject(&self, position: Point) -> (f32, f32) {
+        (
+            self.x
+                .interpolate((position.x - self.bounds.x) / self.bounds.width),
+            self.y.interpolate(
+                1.0 - (position.y - self.bounds.y) / self.bounds.height,
+            ),
+        )
+    }
+}
+
+struct LegendItem {
+    label: String,
+    color: Color,
+    x: f32,
+}
+
+struct Tooltip {
+    header: String,
+    entries: Vec<TooltipEntry>,
+    width: f32,
+}
+
+struct TooltipEntry {
+    text: String,
+    color: Color,
+    point: (f32, f32),
+}
+
+fn tick_count(length: f32, spacing: f32) -> usize {
+    ((length / spacing) as usize).max(2)
+}
+
+fn line_stroke(color: Color) -> Stroke {
+    Stroke::default().with_color(color).with_width(LINE_WIDTH)
+}
+
+fn polyline(points: &[Point]) -> Path {
+    Path::new(|builder| {
+        if let Some((first, rest)) = points.split_first() {
+            builder.move_to(*first);
+
+            for point in rest {
+                builder.line_to(*point);
+            }
+        }
+    })
+}
@@ -0,0 +1,198 @@
+/// A closed interval of values along an axis of a [`Chart`].
+///
+/// [`Chart`]: super::Chart
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct Range {
+    pub min: f32,
+    pub max: f32,
+}
+
+impl Range {
+    /// The smallest span a [`Range`] can be zoomed into, relative to the
+    /// magnitude of its bounds.
+    const MIN_RELATIVE_SPAN: f32 = 1e-5;
+
+    pub fn new(min: f32, max: f32) -> Range {
+        Range { min, max }
+    }
+
+    /// Returns the smallest [`Range`] containing all the finite values, if
+    /// any.
+    pub fn fit(values: impl Iterator<Item = f32>) -> Option<Range> {
+        values
+            .filter(|value| value.is_finite())
+            .fold(None, |range, value| {
+                Some(match range {
+                    Some(range) => range.include(value),
+                    None => Range::new(value, value),
+                })
+            })
+    }
+
+    pub fn span(self) -> f32 {
+        self.max - self.min
+    }
+
+    /// Extends the [`Range`] to contain the given value.
+    pub fn include(self, value: f32) -> Range {
+        Range::new(self.min.min(value), self.max.max(value))
+    }
+
+    /// Extends the [`Range`] to the closest multiples of the step of its
+    /// ticks, widening it first if it is empty.
+    pub fn nice(self, count: usize) -> Range {
+        let range = if self.span() > 0.0 {
+            self
+        } else {
+            let margin = (self.min.abs() / 10.0).max(1.0);
+
+            Range::new(self.min - margin, self.max + margin)
+        };
+
+        let step = step(range.span(), count);
+
+        Range::new(
+            (range.min / step).floor() * step,
+            (range.max / step).ceil() * step,
+        )
+    }
+
+    /// Returns the values of roughly `count` evenly spaced ticks inside the
+    /// [`Range`], together with the step between them.
+    pub fn ticks(self, count: usize) -> (Vec<f32>, f32) {
+        let step = step(self.span(), count);
+        let first = (self.min / step).ceil();
+        let tolerance = step * 1e-3;
+
+        let ticks = (0..)
+            .map(|i| (first + i as f32) * step)
+            .take_while(|value| *value <= self.max + tolerance)
+            .collect();
+
+        (ticks, step)
+    }
+
+    /// Scales the [`Range`] by the given factor, keeping the anchor value at
+    /// the same relative position.
+    pub fn zoom(self, anchor: f32, factor: f32) -> Range {
+        let span = self.span() * factor;
+        let magnitude = self.min.abs().max(self.max.abs()).max(1.0);
+
+        if span < magnitude * Self::MIN_RELATIVE_SPAN || !span.is_finite() {
+            return self;
+        }
+
+        let min = anchor - (anchor - self.min) * factor;
+
+        Range::new(min, min + span)
+    }
+
+    /// Moves the [`Range`] by the given amount.
+    pub fn pan(self, delta: f32) -> Range {
+        Range::new(self.min + delta, self.max + delta)
+    }
+
+    /// Returns the relative position of the value in the [`Range`], from 0 at
+    /// its minimum to 1 at its maximum.
+    pub fn normalize(self, value: f32) -> f32 {
+        (value - self.min) / self.span()
+    }
+
+    /// Returns the value at the given relative position in the [`Range`].
+    pub fn interpolate(self, t: f32) -> f32 {
+        self.min + t * self.span()
+    }
+}
+
+/// Returns a step of 1, 2 or 5 times a power of ten that splits the span in
+/// roughly `count` parts.
+fn step(span: f32, count: usize) -> f32 {
+    let raw = span / count.max(1) as f32;
+    let magnitude = 10f32.powf(raw.log10().floor());
+
+    let normalized = raw / magnitude;
+
+    let nice = if normalized <= 1.0 {
+        1.0
+    } else if normalized <= 2.0 {
+        2.0
+    } else if normalized <= 5.0 {
+        5.0
+    } else {
+        10.0
+    };
+
+    nice * magnitude
+}
+
+/// Formats a value with as many decimals as the given step needs.
+pub(super) fn format(value: f32, step: f32) -> String {
+    // Nudge the logarithm so steps like `0.1` do not get an extra decimal
+    // due to rounding errors
+    let decimals = (-(step.log10() + 1e-4).floor()).max(0.0) as usize;
+
+    // Avoid printing tiny rounding errors as `-0`
+    let value = if value.abs() < step / 2.0 { 0.0 } else { value };
+
+    format!("{:.*}", decimals, value)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn steps_are_nice() {
+        assert_eq!(step(10.0, 5), 2.0);
+        assert_eq!(step(100.0, 4), 50.0);
+        assert_eq!(step(7.0, 10), 1.0);
+    }
+
+    #[test]
+    fn nice_range_snaps_to_steps() {
+        assert_eq!(Range::new(0.3, 9.2).nice(5), Range::new(0.0, 10.0));
+        assert_eq!(Range::new(-13.0, 42.0).nice(5), Range::new(-20.0, 60.0));
+        assert_eq!(Range::new(5.0, 5.0).nice(4), Range::new(4.0, 6.0));
+    }
+
+    #[test]
+    fn ticks_cover_range() {
+        let (ticks, step) = Range::new(0.0, 10.0).ticks(5);
+
+        assert_eq!(step, 2.0);
+        assert_eq!(ticks, vec![0.0, 2.0, 4.0, 6.0, 8.0, 10.0]);
+
+        let (ticks, _) = Range::new(0.5, 3.7).ticks(4);
+
+        assert_eq!(ticks, vec![1.0, 2.0, 3.0]);
+    }
+
+    #[test]
+    fn fit_ignores_non_finite_values() {
+        assert_eq!(
+            Range::fit(vec![3.0, f32::NAN, -1.0, f32::INFINITY].into_iter()),
+            Some(Range::new(-1.0, 3.0))
+        );
+        assert_eq!(Range::fit(std::iter::empty()), None);
+    }
+
+    #[test]
+    fn zoom_keeps_anchor() {
+        let range = Range::new(0.0, 10.0).zoom(2.0, 0.5);
+
+        assert_eq!(range, Range::new(1.0, 6.0));
+        assert_eq!(range.normalize(2.0), Range::new(0.0, 10.0).normalize(2.0));
+
+        let tiny = Range::new(0.0, 1e-5);
+
+        assert_eq!(tiny.zoom(0.0, 0.1), tiny);
+    }
+
+    #[test]
+    fn format_uses_step_precision() {
+        assert_eq!(format(2.0, 1.0), "2");
+        assert_eq!(format(0.25, 0.05), "0.25");
+        assert_eq!(format(-1e-7, 0.5), "0.0");
+        assert_eq!(format(1500.0, 500.0), "1500");
+    }
+}
@@ -0,0 +1,111 @@
+use iced_native::Color;
+
+/// A set of data points drawn in a [`Chart`].
+///
+/// The data is borrowed, so streaming data can be plotted without copying it
+/// every time the view is rebuilt.
+///
+/// [`Chart`]: super::Chart
+#[derive(Debug, Clone)]
+pub struct Series<'a> {
+    pub(super) kind: Kind,
+    pub(super) label: String,
+    pub(super) data: &'a [(f32, f32)],
+    pub(super) color: Option<Color>,
+}
+
+impl<'a> Series<'a> {
+    /// Creates a new [`Series`] of the given [`Kind`].
+    ///
+    /// It expects:
+    ///   * the [`Kind`] of the [`Series`]
+    ///   * the label of the [`Series`] shown in the legend and tooltips. An
+    ///     empty label hides the [`Series`] from the legend.
+    ///   * the `(x, y)` data points of the [`Series`], sorted by `x` for
+    ///     [`Kind::Line`] and [`Kind::Area`] series
+    pub fn new(
+        kind: Kind,
+        label: impl Into<String>,
+        data: &'a [(f32, f32)],
+    ) -> Self {
+        Series {
+            kind,
+            label: label.into(),
+            data,
+            color: None,
+        }
+    }
+
+    /// Creates a new [`Kind::Line`] [`Series`].
+    pub fn line(label: impl Into<String>, data: &'a [(f32, f32)]) -> Self {
+        Self::new(Kind::Line, label, data)
+    }
+
+    /// Creates a new [`Kind::Area`] [`Series`].
+    pub fn area(label: impl Into<String>, data: &'a [(f32, f32)]) -> Self {
+        Self::new(Kind::Area, label, data)
+    }
+
+    /// Creates a new [`Kind::Bar`] [`Series`].
+    pub fn bar(label: impl Into<String>, data: &'a [(f32, f32)]) -> Self {
+        Self::new(Kind::Bar, label, data)
+    }
+
+    /// Creates a new [`Kind::Scatter`] [`Series`].
+    pub fn scatter(label: impl Into<String>, data: &'a [(f32, f32)]) -> Self {
+        Self::new(Kind::Scatter, label, data)
+    }
+
+    /// Sets the [`Color`] of the [`Series`].
+    ///
+    /// By default, the color is chosen by the style sheet of the [`Chart`].
+    ///
+    /// [`Chart`]: super::Chart
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Returns the data point closest to the given `x` value, if any.
+    pub(super) fn closest(&self, x: f32) -> Option<(f32, f32)> {
+        self.data
+            .iter()
+            .filter(|point| point.0.is_finite() && point.1.is_finite())
+            .min_by(|a, b| {
+                (a.0 - x).abs().partial_cmp(&(b.0 - x).abs()).unwrap()
+            })
+            .copied()
+    }
+
+    /// Returns the smallest distance between the `x` values of consecutive
+    /// data points, if any.
+    pub(super) fn min_gap(&self) -> Option<f32> {
+        self.data
+            .windows(2)
+            .map(|pair| (pair[1].0 - pair[0].0).abs())
+            .filter(|gap| *gap > 0.0)
+            .fold(None, |min: Option<f32>, gap| {
+                Some(min.map(|min| min.min(gap)).unwrap_or(gap))
+            })
+    }
+}
+
+/// The way a [`Series`] is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// The data points are joined by straight lines.
+    Line,
+
+    /// The data points are joined by straight lines and the area down to
+    /// zero is filled.
+    Area,
+
+    /// Every data point is a bar that goes from zero to its value.
+    ///
+    /// Bars of different series sharing the same `x` values are drawn side
+    /// by side.
+    Bar,
+
+    /// Every data point is a dot.
+    Scatter,
+}
@@ -0,0 +1,16 @@
+//! Display and edit source code with syntax highlighting.
+//!
+//! A [`CodeEditor`] has some local [`State`], which owns the code.
+use crate::Renderer;
+
+pub use iced_native::widget::code_editor::{
+    Position, State, Style, StyleSheet, Theme,
+};
+
+/// A view of source code with line numbers and syntax highlighting, which
+/// can optionally be edited.
+///
+/// This is an alias of an `iced_native` code editor with an
+/// `iced_graphics::Renderer`.
+pub type CodeEditor<'a, Message, Backend> =
+    iced_native::widget::CodeEditor<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,22 @@
+//! Show sections of content that can be expanded and collapsed.
+//!
+//! A [`Collapsible`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::widget::collapsible::State;
+pub use iced_style::collapsible::{Style, StyleSheet};
+
+/// A section with a clickable header that shows or hides its content.
+///
+/// This is an alias of an `iced_native` collapsible with an
+/// `iced_graphics::Renderer`.
+pub type Collapsible<'a, Message, Backend> =
+    iced_native::widget::Collapsible<'a, Message, Renderer<Backend>>;
+
+/// A group of [`Collapsible`] sections where only one section can be open at
+/// a time.
+///
+/// This is an alias of an `iced_native` accordion with an
+/// `iced_graphics::Renderer`.
+pub type Accordion<'a, Message, Backend> =
+    iced_native::widget::Accordion<'a, Message, Renderer<Backend>>;
@@ -0,0 +1,15 @@
+//! Let your users pick dates from a calendar.
+//!
+//! A [`DatePicker`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_native::widget::date_picker::{Date, State, Weekday};
+pub use iced_style::date_picker::{Calendar, Style, StyleSheet};
+
+/// A compact field that opens a calendar to pick a date, or a range of
+/// dates.
+///
+/// This is an alias of an `iced_native` date picker with an
+/// `iced_graphics::Renderer`.
+pub type DatePicker<'a, Message, Backend> =
+    iced_native::widget::DatePicker<'a, Message, Renderer<Backend>>;
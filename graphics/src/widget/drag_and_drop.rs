@@ -0,0 +1,20 @@
+//! Let your users drag content around and drop it somewhere else.
+use crate::Renderer;
+
+pub use iced_native::widget::drag_and_drop::{
+    Event, Grab, State, Style, StyleSheet,
+};
+
+/// Some content that can be dragged around, carrying a payload.
+///
+/// This is an alias of an `iced_native` drag source with an
+/// `iced_graphics::Renderer`.
+pub type DragSource<'a, T, Message, Backend> =
+    iced_native::widget::DragSource<'a, T, Message, Renderer<Backend>>;
+
+/// Some content where the payload of a [`DragSource`] can be dropped.
+///
+/// This is an alias of an `iced_native` drop target with an
+/// `iced_graphics::Renderer`.
+pub type DropTarget<'a, T, Message, Backend> =
+    iced_native::widget::DropTarget<'a, T, Message, Renderer<Backend>>;
@@ -6,8 +6,10 @@ use crate::{Primitive, Rectangle, Renderer};
 
 use iced_native::image;
 
-pub use iced_native::widget::image::{Image, Viewer};
-pub use image::Handle;
+use std::time::Duration;
+
+pub use iced_native::widget::image::{Image, Playback, State, Viewer};
+pub use image::{FilterMethod, Handle};
 
 impl<B> image::Renderer for Renderer<B>
 where
@@ -19,7 +21,31 @@ where
         self.backend().dimensions(handle)
     }
 
-    fn draw(&mut self, handle: image::Handle, bounds: Rectangle) {
-        self.draw_primitive(Primitive::Image { handle, bounds })
+    fn frame_delays(&self, handle: &image::Handle) -> Vec<Duration> {
+        self.backend().frame_delays(handle)
+    }
+
+    fn draw(
+        &mut self,
+        handle: image::Handle,
+        filter_method: image::FilterMethod,
+        bounds: Rectangle,
+    ) {
+        self.draw_frame(handle, 0, filter_method, bounds)
+    }
+
+    fn draw_frame(
+        &mut self,
+        handle: image::Handle,
+        frame: usize,
+        filter_method: image::FilterMethod,
+        bounds: Rectangle,
+    ) {
+        self.draw_primitive(Primitive::Image {
+            handle,
+            frame,
+            filter_method,
+            bounds,
+        })
     }
 }
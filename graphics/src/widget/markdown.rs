@@ -0,0 +1,13 @@
+//! Display rich text written in Markdown.
+//!
+//! A [`Markdown`] displays a parsed [`State`].
+use crate::Renderer;
+
+pub use iced_native::widget::markdown::{State, Style, StyleSheet};
+
+/// A document written in CommonMark Markdown.
+///
+/// This is an alias of an `iced_native` markdown with an
+/// `iced_graphics::Renderer`.
+pub type Markdown<'a, Message, Backend> =
+    iced_native::widget::Markdown<'a, Message, Renderer<Backend>>;
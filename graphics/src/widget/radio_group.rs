@@ -0,0 +1,12 @@
+//! Choose a single option from a group of radio buttons.
+use crate::Renderer;
+
+pub use iced_native::widget::radio_group::{Orientation, State};
+pub use iced_style::radio::{Style, StyleSheet};
+
+/// A list of radio buttons where only one option can be selected.
+///
+/// This is an alias of an `iced_native` radio group with an
+/// `iced_graphics::Renderer`.
+pub type RadioGroup<'a, T, Message, Backend> =
+    iced_native::widget::RadioGroup<'a, T, Message, Renderer<Backend>>;
@@ -1,5 +1,7 @@
 //! Display an interactive selector of a single value from a range of values.
 //!
 //! A [`Slider`] has some local [`State`].
-pub use iced_native::widget::slider::{Slider, State};
-pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
+pub use iced_native::widget::slider::{Orientation, Slider, State, Tick};
+pub use iced_style::slider::{
+    Handle, HandleShape, Style, StyleSheet, TickMarks,
+};
@@ -3,7 +3,7 @@
 //! A [`TextInput`] has some local [`State`].
 use crate::Renderer;
 
-pub use iced_native::widget::text_input::State;
+pub use iced_native::widget::text_input::{filter, Filter, State};
 pub use iced_style::text_input::{Style, StyleSheet};
 
 /// A field that can be filled with text.
@@ -1,4 +1,5 @@
 use crate::clipboard;
+use crate::font;
 use crate::window;
 
 use std::fmt;
@@ -15,6 +16,9 @@ pub enum Action<T> {
 
     /// Run a window action.
     Window(window::Action),
+
+    /// Run a font action.
+    Font(font::Action<T>),
 }
 
 impl<T> Action<T> {
@@ -29,6 +33,7 @@ impl<T> Action<T> {
             Self::Future(future) => Action::Future(Box::pin(future.map(f))),
             Self::Clipboard(action) => Action::Clipboard(action.map(f)),
             Self::Window(window) => Action::Window(window),
+            Self::Font(action) => Action::Font(action.map(f)),
         }
     }
 }
@@ -41,6 +46,7 @@ impl<T> fmt::Debug for Action<T> {
                 write!(f, "Action::Clipboard({:?})", action)
             }
             Self::Window(action) => write!(f, "Action::Window({:?})", action),
+            Self::Font(action) => write!(f, "Action::Font({:?})", action),
         }
     }
 }
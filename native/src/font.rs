@@ -0,0 +1,43 @@
+//! Describe and load fonts.
+pub use iced_core::font::{Descriptor, Error, Font, Stretch, Style, Weight};
+
+use std::fmt;
+
+/// A font action to be performed by some [`Command`].
+///
+/// [`Command`]: crate::Command
+pub enum Action<T> {
+    /// Load the given font bytes and produce `T` with the result.
+    Load {
+        /// The bytes of the font to load
+        bytes: Vec<u8>,
+
+        /// The closure producing `T` with the result of the load
+        tagger: Box<dyn Fn(Result<Descriptor, Error>) -> T>,
+    },
+}
+
+impl<T> Action<T> {
+    /// Maps the output of a font [`Action`] using the provided closure.
+    pub fn map<A>(self, f: impl Fn(T) -> A + 'static + Send + Sync) -> Action<A>
+    where
+        T: 'static,
+    {
+        match self {
+            Self::Load { bytes, tagger } => Action::Load {
+                bytes,
+                tagger: Box::new(move |result| f(tagger(result))),
+            },
+        }
+    }
+}
+
+impl<T> fmt::Debug for Action<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Load { bytes, .. } => {
+                write!(f, "Action::Load({} bytes)", bytes.len())
+            }
+        }
+    }
+}
@@ -4,6 +4,7 @@ use crate::{Hasher, Rectangle};
 use std::hash::{Hash, Hasher as _};
 use std::path::PathBuf;
 use std::sync::Arc;
+use std::time::Duration;
 
 /// An [`Image`] handle.
 #[derive(Debug, Clone)]
@@ -16,6 +17,8 @@ impl Handle {
     /// Creates an image [`Handle`] pointing to the image of the given path.
     ///
     /// Makes an educated guess about the image format by examining the data in the file.
+    ///
+    /// Animated GIF and APNG files are decoded into all of their frames.
     pub fn from_path<T: Into<PathBuf>>(path: T) -> Handle {
         Self::from_data(Data::Path(path.into()))
     }
@@ -111,6 +114,24 @@ impl std::fmt::Debug for Data {
     }
 }
 
+/// The filtering method used when an image is scaled to its bounds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FilterMethod {
+    /// Bilinear interpolation, which smooths the pixels of the image.
+    Linear,
+
+    /// Nearest neighbor, which keeps the pixels of the image sharp.
+    ///
+    /// It is useful to display pixel art or screenshots.
+    Nearest,
+}
+
+impl Default for FilterMethod {
+    fn default() -> Self {
+        FilterMethod::Linear
+    }
+}
+
 /// A [`Renderer`] that can render raster graphics.
 ///
 /// [renderer]: crate::renderer
@@ -123,7 +144,35 @@ pub trait Renderer: crate::Renderer {
     /// Returns the dimensions of an image for the given [`Handle`].
     fn dimensions(&self, handle: &Self::Handle) -> (u32, u32);
 
+    /// Returns the delay of each frame of an image for the given [`Handle`].
+    ///
+    /// The returned list is empty if the image is not animated.
+    fn frame_delays(&self, _handle: &Self::Handle) -> Vec<Duration> {
+        Vec::new()
+    }
+
     /// Draws an image with the given [`Handle`] and inside the provided
-    /// `bounds`.
-    fn draw(&mut self, handle: Self::Handle, bounds: Rectangle);
+    /// `bounds`, using the given [`FilterMethod`] to scale it.
+    fn draw(
+        &mut self,
+        handle: Self::Handle,
+        filter_method: FilterMethod,
+        bounds: Rectangle,
+    );
+
+    /// Draws the given `frame` of an animated image with the given
+    /// [`Handle`], like [`draw`] does.
+    ///
+    /// By default, only the first frame of the image is drawn.
+    ///
+    /// [`draw`]: Self::draw
+    fn draw_frame(
+        &mut self,
+        handle: Self::Handle,
+        _frame: usize,
+        filter_method: FilterMethod,
+        bounds: Rectangle,
+    ) {
+        self.draw(handle, filter_method, bounds)
+    }
 }
@@ -7,7 +7,7 @@ pub mod flex;
 pub use limits::Limits;
 pub use node::Node;
 
-use crate::{Point, Rectangle, Vector};
+use crate::{Direction, Point, Rectangle, Vector};
 
 /// The bounds of a [`Node`] and its children, using absolute coordinates.
 #[derive(Debug, Clone, Copy)]
@@ -53,6 +53,14 @@ impl<'a> Layout<'a> {
         }
     }
 
+    /// Returns the [`Direction`] of the [`Layout`].
+    ///
+    /// Widgets that need to mirror their contents when drawing or handling
+    /// events should rely on this.
+    pub fn direction(&self) -> Direction {
+        self.node.direction()
+    }
+
     /// Returns an iterator over the [`Layout`] of the children of a [`Node`].
     pub fn children(self) -> impl Iterator<Item = Layout<'a>> {
         self.node.children().iter().map(move |node| {
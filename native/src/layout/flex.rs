@@ -55,7 +55,12 @@ impl Axis {
 /// Computes the flex layout with the given axis and limits, applying spacing,
 /// padding and alignment to the items as needed.
 ///
+/// If the [`Direction`] of the [`Limits`] is right-to-left, the resulting
+/// layout is mirrored horizontally.
+///
 /// It returns a new layout [`Node`].
+///
+/// [`Direction`]: crate::Direction
 pub fn resolve<Message, Renderer>(
     axis: Axis,
     renderer: &Renderer,
@@ -69,6 +74,7 @@ where
     Renderer: crate::Renderer,
 {
     let limits = limits.pad(padding);
+    let direction = limits.direction();
     let total_spacing = spacing * items.len().saturating_sub(1) as f32;
     let max_cross = axis.cross(limits.max());
 
@@ -93,7 +99,8 @@ where
                 let (max_width, max_height) = axis.pack(available, max_cross);
 
                 let child_limits =
-                    Limits::new(Size::ZERO, Size::new(max_width, max_height));
+                    Limits::new(Size::ZERO, Size::new(max_width, max_height))
+                        .with_direction(direction);
 
                 let layout = child.layout(renderer, &child_limits);
                 let size = layout.size();
@@ -128,7 +135,8 @@ where
             let child_limits = Limits::new(
                 Size::new(min_width, min_height),
                 Size::new(max_width, max_height),
-            );
+            )
+            .with_direction(direction);
 
             let layout = child.layout(renderer, &child_limits);
             let size = layout.size();
@@ -177,7 +185,8 @@ where
             let child_limits = Limits::new(
                 Size::new(min_width, min_height),
                 Size::new(max_width, max_height),
-            );
+            )
+            .with_direction(direction);
 
             let layout = child.layout(renderer, &child_limits);
 
@@ -224,7 +233,18 @@ where
     }
 
     let (width, height) = axis.pack(main - pad.0, cross);
-    let size = limits.resolve(Size::new(width, height));
+    let size = limits.resolve(Size::new(width, height)).pad(padding);
+
+    if direction.is_right_to_left() {
+        for node in nodes.iter_mut() {
+            let bounds = node.bounds();
+
+            node.move_to(Point::new(
+                size.width - bounds.x - bounds.width,
+                bounds.y,
+            ));
+        }
+    }
 
-    Node::with_children(size.pad(padding), nodes)
+    Node::with_children(size, nodes).with_direction(direction)
 }
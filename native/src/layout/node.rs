@@ -1,10 +1,11 @@
-use crate::{Alignment, Point, Rectangle, Size, Vector};
+use crate::{Alignment, Direction, Point, Rectangle, Size, Vector};
 
 /// The bounds of an element and its children.
 #[derive(Debug, Clone, Default)]
 pub struct Node {
     bounds: Rectangle,
     children: Vec<Node>,
+    direction: Direction,
 }
 
 impl Node {
@@ -23,9 +24,16 @@ impl Node {
                 height: size.height,
             },
             children,
+            direction: Direction::LeftToRight,
         }
     }
 
+    /// Sets the [`Direction`] the [`Node`] was laid out with.
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
     /// Returns the [`Size`] of the [`Node`].
     pub fn size(&self) -> Size {
         Size::new(self.bounds.width, self.bounds.height)
@@ -36,6 +44,11 @@ impl Node {
         self.bounds
     }
 
+    /// Returns the [`Direction`] the [`Node`] was laid out with.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
     /// Returns the children of the [`Node`].
     pub fn children(&self) -> &[Node] {
         &self.children
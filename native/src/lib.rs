@@ -39,6 +39,7 @@
 pub mod clipboard;
 pub mod command;
 pub mod event;
+pub mod font;
 pub mod image;
 pub mod keyboard;
 pub mod layout;
@@ -70,8 +71,8 @@ mod debug;
 
 pub use iced_core::alignment;
 pub use iced_core::{
-    Alignment, Background, Color, Font, Length, Padding, Point, Rectangle,
-    Size, Vector,
+    Alignment, Background, Color, ContentFit, Direction, Font, Length, Padding,
+    Point, Rectangle, Size, Vector,
 };
 pub use iced_futures::{executor, futures};
 
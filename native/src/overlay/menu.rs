@@ -468,6 +468,8 @@ where
                 },
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Center,
+                format: text::Format::default(),
+                decorations: &[],
             });
         }
     }
@@ -24,8 +24,9 @@ mod null;
 #[cfg(debug_assertions)]
 pub use null::Null;
 
+use crate::font;
 use crate::layout;
-use crate::{Background, Color, Element, Rectangle, Vector};
+use crate::{Background, Color, Direction, Element, Rectangle, Vector};
 
 /// A component that can take the state of a user interface and produce an
 /// output for its users.
@@ -42,6 +43,27 @@ pub trait Renderer: Sized {
         element.layout(self, limits)
     }
 
+    /// Returns the [`Direction`] used to lay out the root of a user interface.
+    ///
+    /// By default, it returns [`Direction::LeftToRight`].
+    fn direction(&self) -> Direction {
+        Direction::LeftToRight
+    }
+
+    /// Loads the font contained in the given bytes, making it available to
+    /// any [`Font::Family`] matching its properties.
+    ///
+    /// Returns the [`font::Descriptor`] of the loaded font. By default, it
+    /// returns [`font::Error::Unsupported`].
+    ///
+    /// [`Font::Family`]: crate::Font::Family
+    fn load_font(
+        &mut self,
+        _bytes: Vec<u8>,
+    ) -> Result<font::Descriptor, font::Error> {
+        Err(font::Error::Unsupported)
+    }
+
     /// Draws the primitives recorded in the given closure in a new layer.
     ///
     /// The layer will clip its contents to the provided `bounds`.
@@ -41,6 +41,7 @@ impl text::Renderer for Null {
     const ICON_FONT: Font = Font::Default;
     const CHECKMARK_ICON: char = '0';
     const ARROW_DOWN_ICON: char = '0';
+    const ARROW_RIGHT_ICON: char = '0';
 
     fn default_size(&self) -> u16 {
         20
@@ -52,6 +53,7 @@ impl text::Renderer for Null {
         _size: u16,
         _font: Font,
         _bounds: Size,
+        _format: text::Format,
     ) -> (f32, f32) {
         (0.0, 20.0)
     }
@@ -62,6 +64,7 @@ impl text::Renderer for Null {
         _size: f32,
         _font: Self::Font,
         _bounds: Size,
+        _format: text::Format,
         _point: Point,
         _nearest_only: bool,
     ) -> Option<text::Hit> {
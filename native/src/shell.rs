@@ -1,3 +1,6 @@
+use crate::window;
+use crate::Point;
+
 /// A connection to the state of a shell.
 ///
 /// A [`Widget`] can leverage a [`Shell`] to trigger changes in an application,
@@ -9,6 +12,8 @@ pub struct Shell<'a, Message> {
     messages: &'a mut Vec<Message>,
     is_layout_invalid: bool,
     are_widgets_invalid: bool,
+    ime_position: Option<Point>,
+    redraw_request: Option<window::RedrawRequest>,
 }
 
 impl<'a, Message> Shell<'a, Message> {
@@ -18,6 +23,8 @@ impl<'a, Message> Shell<'a, Message> {
             messages,
             is_layout_invalid: false,
             are_widgets_invalid: false,
+            ime_position: None,
+            redraw_request: None,
         }
     }
 
@@ -50,6 +57,24 @@ impl<'a, Message> Shell<'a, Message> {
         self.are_widgets_invalid = true;
     }
 
+    /// Requests the input method editor (IME) of the platform to be placed
+    /// at the given position, normally right below the text cursor of the
+    /// focused widget.
+    pub fn set_ime_position(&mut self, position: Point) {
+        self.ime_position = Some(position);
+    }
+
+    /// Requests a new frame to be drawn as soon as possible or at the given
+    /// time.
+    ///
+    /// When multiple redraws are requested, the earliest one is kept.
+    pub fn request_redraw(&mut self, request: window::RedrawRequest) {
+        self.redraw_request = Some(match self.redraw_request {
+            Some(current) => current.min(request),
+            None => request,
+        });
+    }
+
     /// Merges the current [`Shell`] with another one by applying the given
     /// function to the messages of the latter.
     ///
@@ -57,11 +82,38 @@ impl<'a, Message> Shell<'a, Message> {
     pub fn merge<B>(&mut self, other: Shell<'_, B>, f: impl Fn(B) -> Message) {
         self.messages.extend(other.messages.drain(..).map(f));
 
+        self.merge_requests(&other);
+    }
+
+    /// Merges the current [`Shell`] with another one by handing the messages
+    /// of the latter to the given function, instead of publishing them.
+    ///
+    /// This method is useful for widgets that react to the messages of their
+    /// children.
+    pub fn merge_with<B>(
+        &mut self,
+        other: Shell<'_, B>,
+        mut f: impl FnMut(B, &mut Self),
+    ) {
+        self.merge_requests(&other);
+
+        for message in other.messages.drain(..) {
+            f(message, self);
+        }
+    }
+
+    fn merge_requests<B>(&mut self, other: &Shell<'_, B>) {
         self.is_layout_invalid =
             self.is_layout_invalid || other.is_layout_invalid;
 
         self.are_widgets_invalid =
             self.are_widgets_invalid || other.are_widgets_invalid;
+
+        self.ime_position = other.ime_position.or(self.ime_position);
+
+        if let Some(request) = other.redraw_request {
+            self.request_redraw(request);
+        }
     }
 
     /// Returns whether the widgets of the current application have been
@@ -69,4 +121,15 @@ impl<'a, Message> Shell<'a, Message> {
     pub fn are_widgets_invalid(&self) -> bool {
         self.are_widgets_invalid
     }
+
+    /// Returns the position requested for the input method editor (IME), if
+    /// any.
+    pub fn ime_position(&self) -> Option<Point> {
+        self.ime_position
+    }
+
+    /// Returns the earliest redraw requested, if any.
+    pub fn redraw_request(&self) -> Option<window::RedrawRequest> {
+        self.redraw_request
+    }
 }
@@ -1,6 +1,9 @@
 //! Draw and interact with text.
 use crate::alignment;
-use crate::{Color, Point, Rectangle, Size, Vector};
+use crate::{Color, Direction, Point, Rectangle, Size, Vector};
+
+use std::hash::Hash;
+use std::ops::Range;
 
 /// A paragraph.
 #[derive(Debug, Clone, Copy)]
@@ -25,6 +28,181 @@ pub struct Text<'a, Font> {
 
     /// The vertical alignment of the [`Text`].
     pub vertical_alignment: alignment::Vertical,
+
+    /// The [`Format`] of the [`Text`].
+    pub format: Format,
+
+    /// The decorations of the [`Text`].
+    pub decorations: &'a [Decoration],
+}
+
+/// A decoration of a range of characters of some [`Text`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Decoration {
+    /// The range of character indices that is decorated.
+    pub range: Range<usize>,
+
+    /// The kind of [`Decoration`].
+    pub kind: Kind,
+
+    /// The color of the [`Decoration`].
+    ///
+    /// If `None`, the color of the [`Text`] is used.
+    pub color: Option<Color>,
+}
+
+impl Decoration {
+    /// Creates a [`Decoration`] that underlines the given range of
+    /// characters.
+    pub fn underline(range: Range<usize>) -> Self {
+        Decoration {
+            range,
+            kind: Kind::Underline,
+            color: None,
+        }
+    }
+
+    /// Creates a [`Decoration`] that strikes through the given range of
+    /// characters.
+    pub fn strikethrough(range: Range<usize>) -> Self {
+        Decoration {
+            range,
+            kind: Kind::Strikethrough,
+            color: None,
+        }
+    }
+
+    /// Creates a [`Decoration`] that fills the background of the given range
+    /// of characters with a [`Color`].
+    pub fn highlight(range: Range<usize>, color: Color) -> Self {
+        Decoration {
+            range,
+            kind: Kind::Highlight,
+            color: Some(color),
+        }
+    }
+
+    /// Sets the [`Color`] of the [`Decoration`].
+    pub fn color(mut self, color: impl Into<Color>) -> Self {
+        self.color = Some(color.into());
+        self
+    }
+}
+
+/// The kind of a [`Decoration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Kind {
+    /// A line under the characters, placed using the metrics of the font.
+    Underline,
+
+    /// A line through the characters, placed using the metrics of the font.
+    Strikethrough,
+
+    /// A background that covers the line height of the characters.
+    Highlight,
+}
+
+/// The formatting options of a paragraph.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Format {
+    /// How the lines of the paragraph wrap when they do not fit in its
+    /// bounds.
+    pub wrapping: Wrapping,
+
+    /// The maximum amount of lines of the paragraph.
+    ///
+    /// The last line is truncated with an ellipsis (…) when the paragraph
+    /// has more lines or when it overflows the bounds horizontally.
+    pub max_lines: Option<usize>,
+
+    /// The height of every line of the paragraph.
+    pub line_height: LineHeight,
+
+    /// The extra space added after every character, in pixels.
+    pub letter_spacing: f32,
+}
+
+impl Format {
+    /// Returns the [`Format`] with its lengths in pixels multiplied by the
+    /// given scale factor.
+    pub fn scale(self, factor: f32) -> Format {
+        Format {
+            line_height: match self.line_height {
+                LineHeight::Relative(ratio) => LineHeight::Relative(ratio),
+                LineHeight::Absolute(height) => {
+                    LineHeight::Absolute(height * factor)
+                }
+            },
+            letter_spacing: self.letter_spacing * factor,
+            ..self
+        }
+    }
+}
+
+impl Default for Format {
+    fn default() -> Format {
+        Format {
+            wrapping: Wrapping::default(),
+            max_lines: None,
+            line_height: LineHeight::default(),
+            letter_spacing: 0.0,
+        }
+    }
+}
+
+impl Hash for Format {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.wrapping.hash(state);
+        self.max_lines.hash(state);
+        self.line_height.hash(state);
+        self.letter_spacing.to_bits().hash(state);
+    }
+}
+
+/// The wrapping strategy of a paragraph.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Wrapping {
+    /// Lines never wrap.
+    None,
+
+    /// Lines wrap at word boundaries.
+    Word,
+
+    /// Lines wrap at any character.
+    Glyph,
+}
+
+impl Default for Wrapping {
+    fn default() -> Wrapping {
+        Wrapping::Word
+    }
+}
+
+/// The height of a line of text.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LineHeight {
+    /// A factor of the height defined by the metrics of the font.
+    Relative(f32),
+
+    /// An absolute height, in pixels.
+    Absolute(f32),
+}
+
+impl Default for LineHeight {
+    fn default() -> LineHeight {
+        LineHeight::Relative(1.0)
+    }
+}
+
+impl Hash for LineHeight {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        std::mem::discriminant(self).hash(state);
+
+        match self {
+            LineHeight::Relative(ratio) => ratio.to_bits().hash(state),
+            LineHeight::Absolute(height) => height.to_bits().hash(state),
+        }
+    }
 }
 
 /// The result of hit testing on text.
@@ -54,14 +232,64 @@ impl Hit {
     }
 }
 
+/// Returns the [`Direction`] of the first strong character of the given
+/// content, following the rules of the [Unicode Bidirectional Algorithm].
+///
+/// Returns `None` if the content does not contain any strong characters.
+///
+/// [Unicode Bidirectional Algorithm]: https://www.unicode.org/reports/tr9/#P2
+pub fn direction(content: &str) -> Option<Direction> {
+    use unicode_bidi::BidiClass;
+
+    content
+        .chars()
+        .find_map(|c| match unicode_bidi::bidi_class(c) {
+            BidiClass::L => Some(Direction::LeftToRight),
+            BidiClass::R | BidiClass::AL => Some(Direction::RightToLeft),
+            _ => None,
+        })
+}
+
 /// A renderer capable of measuring and drawing [`Text`].
 pub trait Renderer: crate::Renderer {
     /// The font type used.
-    type Font: Default + Clone;
+    ///
+    /// It is hashed by the widgets whose layout depends on it.
+    type Font: Default + Clone + Hash;
 
     /// The icon font of the backend.
     const ICON_FONT: Self::Font;
 
+    /// Returns the font used for strong text, like bold text in a
+    /// [`Markdown`] document.
+    ///
+    /// By default, it is the default font.
+    ///
+    /// [`Markdown`]: crate::widget::Markdown
+    fn bold_font() -> Self::Font {
+        Self::Font::default()
+    }
+
+    /// Returns the font used for emphasized text, like italic text in a
+    /// [`Markdown`] document.
+    ///
+    /// By default, it is the default font.
+    ///
+    /// [`Markdown`]: crate::widget::Markdown
+    fn italic_font() -> Self::Font {
+        Self::Font::default()
+    }
+
+    /// Returns the font used for code, like code spans and blocks in a
+    /// [`Markdown`] document.
+    ///
+    /// By default, it is the default font.
+    ///
+    /// [`Markdown`]: crate::widget::Markdown
+    fn monospace_font() -> Self::Font {
+        Self::Font::default()
+    }
+
     /// The `char` representing a ✔ icon in the [`ICON_FONT`].
     ///
     /// [`ICON_FONT`]: Self::ICON_FONT
@@ -72,22 +300,35 @@ pub trait Renderer: crate::Renderer {
     /// [`ICON_FONT`]: Self::ICON_FONT
     const ARROW_DOWN_ICON: char;
 
+    /// The `char` representing a ▶ icon in the built-in [`ICON_FONT`].
+    ///
+    /// [`ICON_FONT`]: Self::ICON_FONT
+    const ARROW_RIGHT_ICON: char;
+
     /// Returns the default size of [`Text`].
     fn default_size(&self) -> u16;
 
-    /// Measures the text in the given bounds and returns the minimum boundaries
-    /// that can fit the contents.
+    /// Measures the text in the given bounds, formatted with the given
+    /// [`Format`], and returns the minimum boundaries that can fit the
+    /// contents.
     fn measure(
         &self,
         content: &str,
         size: u16,
         font: Self::Font,
         bounds: Size,
+        format: Format,
     ) -> (f32, f32);
 
     /// Measures the width of the text as if it were laid out in a single line.
     fn measure_width(&self, content: &str, size: u16, font: Self::Font) -> f32 {
-        let (width, _) = self.measure(content, size, font, Size::INFINITY);
+        let (width, _) = self.measure(
+            content,
+            size,
+            font,
+            Size::INFINITY,
+            Format::default(),
+        );
 
         width
     }
@@ -96,15 +337,19 @@ pub trait Renderer: crate::Renderer {
     /// laid out with the given parameters, returning information about
     /// the nearest character.
     ///
+    /// The text should be laid out with the same [`Format`] it is drawn with.
+    ///
     /// If `nearest_only` is true, the hit test does not consider whether the
     /// the point is interior to any glyph bounds, returning only the character
     /// with the nearest centeroid.
+    #[allow(clippy::too_many_arguments)]
     fn hit_test(
         &self,
         contents: &str,
         size: f32,
         font: Self::Font,
         bounds: Size,
+        format: Format,
         point: Point,
         nearest_only: bool,
     ) -> Option<Hit>;
@@ -4,6 +4,7 @@ use crate::layout;
 use crate::mouse;
 use crate::overlay;
 use crate::renderer;
+use crate::window;
 use crate::{Clipboard, Element, Layout, Point, Rectangle, Shell, Size};
 
 use std::hash::Hasher;
@@ -26,6 +27,8 @@ pub struct UserInterface<'a, Message, Renderer> {
     base: Layer,
     overlay: Option<Layer>,
     bounds: Size,
+    ime_position: Option<Point>,
+    redraw_request: Option<window::RedrawRequest>,
 }
 
 impl<'a, Message, Renderer> UserInterface<'a, Message, Renderer>
@@ -111,7 +114,8 @@ where
                 (
                     renderer.layout(
                         &root,
-                        &layout::Limits::new(Size::ZERO, bounds),
+                        &layout::Limits::new(Size::ZERO, bounds)
+                            .with_direction(renderer.direction()),
                     ),
                     None,
                 )
@@ -125,6 +129,8 @@ where
             base,
             overlay,
             bounds,
+            ime_position: None,
+            redraw_request: None,
         }
     }
 
@@ -204,6 +210,8 @@ where
         messages: &mut Vec<Message>,
     ) -> (State, Vec<event::Status>) {
         let mut state = State::Updated;
+        let mut ime_position = None;
+        let mut redraw_request: Option<window::RedrawRequest> = None;
 
         let (base_cursor, overlay_statuses) = if let Some(mut overlay) =
             self.root.overlay(Layout::new(&self.base.layout), renderer)
@@ -245,6 +253,12 @@ where
                         state = State::Outdated;
                     }
 
+                    ime_position = shell.ime_position().or(ime_position);
+                    redraw_request = redraw_request
+                        .into_iter()
+                        .chain(shell.redraw_request())
+                        .min();
+
                     event_status
                 })
                 .collect();
@@ -290,7 +304,8 @@ where
 
                     let layout = renderer.layout(
                         &self.root,
-                        &layout::Limits::new(Size::ZERO, self.bounds),
+                        &layout::Limits::new(Size::ZERO, self.bounds)
+                            .with_direction(renderer.direction()),
                     );
 
                     self.base = Layer { layout, hash };
@@ -301,10 +316,19 @@ where
                     state = State::Outdated;
                 }
 
+                ime_position = shell.ime_position().or(ime_position);
+                redraw_request = redraw_request
+                    .into_iter()
+                    .chain(shell.redraw_request())
+                    .min();
+
                 event_status.merge(overlay_status)
             })
             .collect();
 
+        self.ime_position = ime_position;
+        self.redraw_request = redraw_request;
+
         (state, event_statuses)
     }
 
@@ -484,6 +508,28 @@ where
             .unwrap_or(base_interaction)
     }
 
+    /// Returns the position requested for the input method editor (IME) of
+    /// the platform during the last [`update`], if any.
+    ///
+    /// You should move the IME of your system accordingly, so its candidate
+    /// window is shown next to the text being composed.
+    ///
+    /// [`update`]: Self::update
+    pub fn ime_position(&self) -> Option<Point> {
+        self.ime_position
+    }
+
+    /// Returns the earliest redraw requested by the widgets during the last
+    /// [`update`], if any.
+    ///
+    /// You should dispatch a [`window::Event::RedrawRequested`] when the
+    /// request is due, so animated widgets can keep advancing.
+    ///
+    /// [`update`]: Self::update
+    pub fn redraw_request(&self) -> Option<window::RedrawRequest> {
+        self.redraw_request
+    }
+
     /// Relayouts and returns a new  [`UserInterface`] using the provided
     /// bounds.
     pub fn relayout(self, bounds: Size, renderer: &mut Renderer) -> Self {
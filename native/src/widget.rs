@@ -12,14 +12,29 @@
 //!
 //! [renderer]: crate::renderer
 pub mod button;
+pub mod carousel;
 pub mod checkbox;
+#[cfg(feature = "code_editor")]
+pub mod code_editor;
+pub mod collapsible;
+pub mod color_picker;
 pub mod column;
 pub mod container;
+pub mod date_picker;
+pub mod drag_and_drop;
 pub mod image;
+#[cfg(feature = "markdown")]
+pub mod markdown;
+pub mod mouse_area;
+pub mod number_input;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
+pub mod progress_ring;
 pub mod radio;
+pub mod radio_group;
+pub mod range_slider;
+pub mod reorderable_list;
 pub mod row;
 pub mod rule;
 pub mod scrollable;
@@ -28,19 +43,40 @@ pub mod space;
 pub mod svg;
 pub mod text;
 pub mod text_input;
+pub mod time_picker;
 pub mod toggler;
 pub mod tooltip;
 
 #[doc(no_inline)]
 pub use button::Button;
 #[doc(no_inline)]
+pub use carousel::Carousel;
+#[doc(no_inline)]
 pub use checkbox::Checkbox;
+#[cfg(feature = "code_editor")]
+#[doc(no_inline)]
+pub use code_editor::CodeEditor;
+#[doc(no_inline)]
+pub use collapsible::{Accordion, Collapsible};
+#[doc(no_inline)]
+pub use color_picker::ColorPicker;
 #[doc(no_inline)]
 pub use column::Column;
 #[doc(no_inline)]
 pub use container::Container;
 #[doc(no_inline)]
+pub use date_picker::DatePicker;
+#[doc(no_inline)]
+pub use drag_and_drop::{DragSource, DropTarget};
+#[doc(no_inline)]
 pub use image::Image;
+#[cfg(feature = "markdown")]
+#[doc(no_inline)]
+pub use markdown::Markdown;
+#[doc(no_inline)]
+pub use mouse_area::MouseArea;
+#[doc(no_inline)]
+pub use number_input::NumberInput;
 #[doc(no_inline)]
 pub use pane_grid::PaneGrid;
 #[doc(no_inline)]
@@ -48,8 +84,16 @@ pub use pick_list::PickList;
 #[doc(no_inline)]
 pub use progress_bar::ProgressBar;
 #[doc(no_inline)]
+pub use progress_ring::ProgressRing;
+#[doc(no_inline)]
 pub use radio::Radio;
 #[doc(no_inline)]
+pub use radio_group::RadioGroup;
+#[doc(no_inline)]
+pub use range_slider::RangeSlider;
+#[doc(no_inline)]
+pub use reorderable_list::ReorderableList;
+#[doc(no_inline)]
 pub use row::Row;
 #[doc(no_inline)]
 pub use rule::Rule;
@@ -66,6 +110,8 @@ pub use text::Text;
 #[doc(no_inline)]
 pub use text_input::TextInput;
 #[doc(no_inline)]
+pub use time_picker::TimePicker;
+#[doc(no_inline)]
 pub use toggler::Toggler;
 #[doc(no_inline)]
 pub use tooltip::Tooltip;
@@ -0,0 +1,751 @@
+//! Page through content horizontally.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::window;
+use crate::{
+    Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+use std::time::Instant;
+
+pub use iced_style::carousel::{Style, StyleSheet};
+
+/// A horizontally paged container that can be swiped or dragged with the
+/// mouse, snapping to the nearest page.
+///
+/// # Example
+/// ```
+/// # type Carousel<'a, Message> =
+/// #     iced_native::widget::Carousel<'a, Message, iced_native::renderer::Null>;
+/// # type Text = iced_native::widget::Text<iced_native::renderer::Null>;
+/// #
+/// use iced_native::widget::carousel;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     PageChanged(usize),
+/// }
+///
+/// let mut state = carousel::State::new();
+///
+/// let carousel = Carousel::new(&mut state)
+///     .push(Text::new("Welcome!"))
+///     .push(Text::new("Let's get started."))
+///     .on_page_change(Message::PageChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Carousel<'a, Message, Renderer> {
+    state: &'a mut State,
+    pages: Vec<Element<'a, Message, Renderer>>,
+    on_page_change: Option<Box<dyn Fn(usize) -> Message + 'a>>,
+    width: Length,
+    height: Length,
+    indicators: bool,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> Carousel<'a, Message, Renderer> {
+    /// The distance the cursor needs to travel before a press turns into a
+    /// drag, letting the pages receive their clicks.
+    const DRAG_THRESHOLD: f32 = 8.0;
+
+    /// How far in the future, in seconds, the velocity of a swipe is
+    /// projected to pick the page to snap to.
+    const MOMENTUM: f32 = 0.3;
+
+    /// How quickly a [`Carousel`] snaps to its page, per second.
+    const SNAP_SPEED: f32 = 12.0;
+
+    /// The height of the area where the page indicators are drawn.
+    const INDICATORS_HEIGHT: f32 = 24.0;
+
+    /// The diameter of a page indicator.
+    const INDICATOR_SIZE: f32 = 8.0;
+
+    /// Creates an empty [`Carousel`] with the given [`State`].
+    pub fn new(state: &'a mut State) -> Self {
+        state.page_count = 0;
+
+        Carousel {
+            state,
+            pages: Vec::new(),
+            on_page_change: None,
+            width: Length::Fill,
+            height: Length::Shrink,
+            indicators: true,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Adds a page to the [`Carousel`].
+    pub fn push<E>(mut self, page: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.pages.push(page.into());
+        self.state.page_count = self.pages.len();
+        self
+    }
+
+    /// Sets the message that should be produced when the user changes the
+    /// page of the [`Carousel`].
+    pub fn on_page_change<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(usize) -> Message,
+    {
+        self.on_page_change = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`Carousel`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`Carousel`].
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets whether the page indicators of the [`Carousel`] are shown.
+    ///
+    /// They are shown by default.
+    pub fn indicators(mut self, indicators: bool) -> Self {
+        self.indicators = indicators;
+        self
+    }
+
+    /// Sets the style of the [`Carousel`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn indicators_height(&self) -> f32 {
+        if self.indicators {
+            Self::INDICATORS_HEIGHT
+        } else {
+            0.0
+        }
+    }
+
+    /// Returns the bounds of the page indicators of a [`Carousel`] with the
+    /// given bounds.
+    fn indicator_bounds(
+        &self,
+        bounds: Rectangle,
+    ) -> impl Iterator<Item = Rectangle> {
+        let size = Self::INDICATOR_SIZE;
+        let count = self.pages.len() as f32;
+        let total = count * size * 2.0 - size;
+
+        let x = bounds.x + (bounds.width - total) / 2.0;
+        let y =
+            bounds.y + bounds.height - (Self::INDICATORS_HEIGHT + size) / 2.0;
+
+        (0..self.pages.len()).map(move |i| Rectangle {
+            x: x + i as f32 * size * 2.0,
+            y,
+            width: size,
+            height: size,
+        })
+    }
+
+    /// Returns the indices of the pages that are currently visible.
+    fn visible_pages(&self) -> RangeInclusive<usize> {
+        let position = self.state.position;
+        let first = position.floor().max(0.0) as usize;
+        let last = (position.ceil().max(0.0) as usize)
+            .min(self.pages.len().saturating_sub(1));
+
+        first..=last
+    }
+
+    /// Returns the horizontal translation of the page with the given index.
+    fn translation(&self, page: usize, width: f32) -> f32 {
+        (page as f32 - self.state.position) * width
+    }
+
+    /// Returns the page to snap to when a drag that started at the given
+    /// position is released with the given velocity, in pixels per second.
+    ///
+    /// A drag moves the [`Carousel`] by one page at most.
+    fn release_page(&self, start: f32, velocity: f32, width: f32) -> usize {
+        let projected =
+            self.state.position - velocity / width.max(1.0) * Self::MOMENTUM;
+
+        let start = start.round();
+        let last = self.pages.len().saturating_sub(1) as f32;
+
+        projected
+            .round()
+            .max(start - 1.0)
+            .min(start + 1.0)
+            .max(0.0)
+            .min(last) as usize
+    }
+
+    fn change_page(&mut self, page: usize, shell: &mut Shell<'_, Message>) {
+        if page != self.state.page {
+            self.state.page = page;
+
+            if let Some(on_page_change) = &self.on_page_change {
+                shell.publish(on_page_change(page));
+            }
+        }
+
+        self.state.last_frame = None;
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+}
+
+/// The local state of a [`Carousel`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    page: usize,
+    page_count: usize,
+    position: f32,
+    drag: Option<Drag>,
+    last_frame: Option<Instant>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: f32,
+    start: f32,
+    is_dragging: bool,
+    last_x: f32,
+    last_moved_at: Instant,
+    velocity: f32,
+}
+
+impl State {
+    /// Creates a new [`State`] showing the first page.
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns the index of the current page.
+    pub fn page(&self) -> usize {
+        self.page
+    }
+
+    /// Scrolls to the page with the given index.
+    ///
+    /// The last page is shown if there is no page with the given index.
+    pub fn go_to(&mut self, page: usize) {
+        self.page = page;
+        self.last_frame = None;
+    }
+
+    /// Scrolls to the next page, if there is one.
+    pub fn next(&mut self) {
+        if self.page + 1 < self.page_count {
+            self.go_to(self.page + 1);
+        }
+    }
+
+    /// Scrolls to the previous page, if there is one.
+    pub fn previous(&mut self) {
+        if self.page > 0 {
+            self.go_to(self.page - 1);
+        }
+    }
+
+    fn is_snapping(&self) -> bool {
+        self.drag.is_none()
+            && (self.position - self.page as f32).abs() > f32::EPSILON
+    }
+
+    /// Moves towards the current page and returns whether the snapping is
+    /// still running.
+    fn snap(&mut self, now: Instant, speed: f32) -> bool {
+        let elapsed = self
+            .last_frame
+            .map(|last_frame| {
+                now.saturating_duration_since(last_frame).as_secs_f32()
+            })
+            .unwrap_or(0.0);
+
+        let target = self.page as f32;
+        let step = 1.0 - (-speed * elapsed).exp();
+
+        self.position += (target - self.position) * step;
+
+        if (target - self.position).abs() < 0.001 {
+            self.position = target;
+            self.last_frame = None;
+
+            false
+        } else {
+            self.last_frame = Some(now);
+
+            true
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Carousel<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let indicators_height = self.indicators_height();
+
+        let limits = limits.width(self.width).height(self.height);
+        let page_limits =
+            limits.shrink(Size::new(0.0, indicators_height)).loose();
+
+        let mut pages: Vec<_> = self
+            .pages
+            .iter()
+            .map(|page| page.layout(renderer, &page_limits))
+            .collect();
+
+        let content = pages.iter().fold(Size::ZERO, |size, page| {
+            Size::new(
+                size.width.max(page.size().width),
+                size.height.max(page.size().height),
+            )
+        });
+
+        let size = limits.resolve(Size::new(
+            content.width,
+            content.height + indicators_height,
+        ));
+
+        let area = Size::new(size.width, size.height - indicators_height);
+
+        for page in &mut pages {
+            let page_size = page.size();
+
+            page.move_to(Point::new(
+                (area.width - page_size.width) / 2.0,
+                (area.height - page_size.height) / 2.0,
+            ));
+        }
+
+        layout::Node::with_children(size, pages)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let area = Rectangle {
+            height: bounds.height - self.indicators_height(),
+            ..bounds
+        };
+
+        // Pages may have been removed since the last time
+        self.state.page =
+            self.state.page.min(self.pages.len().saturating_sub(1));
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now))
+                if self.state.is_snapping() =>
+            {
+                let is_running = self.state.snap(now, Self::SNAP_SPEED);
+
+                if is_running {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let position = match event {
+                    Event::Touch(touch::Event::FingerPressed {
+                        position,
+                        ..
+                    }) => position,
+                    _ => cursor_position,
+                };
+
+                if self.indicators {
+                    if let Some(page) = self
+                        .indicator_bounds(bounds)
+                        .position(|indicator| indicator.contains(position))
+                    {
+                        self.change_page(page, shell);
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                if area.contains(position) && !self.pages.is_empty() {
+                    self.state.drag = Some(Drag {
+                        origin: position.x,
+                        start: self.state.position,
+                        is_dragging: false,
+                        last_x: position.x,
+                        last_moved_at: Instant::now(),
+                        velocity: 0.0,
+                    });
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(drag) = &mut self.state.drag {
+                    let delta = position.x - drag.origin;
+
+                    if !drag.is_dragging && delta.abs() > Self::DRAG_THRESHOLD {
+                        drag.is_dragging = true;
+                    }
+
+                    if drag.is_dragging {
+                        let now = Instant::now();
+                        let elapsed = now
+                            .saturating_duration_since(drag.last_moved_at)
+                            .as_secs_f32();
+
+                        if elapsed > 0.0 {
+                            drag.velocity =
+                                (position.x - drag.last_x) / elapsed;
+                        }
+
+                        drag.last_x = position.x;
+                        drag.last_moved_at = now;
+
+                        let last = self.pages.len().saturating_sub(1) as f32;
+
+                        self.state.position = (drag.start
+                            - delta / area.width.max(1.0))
+                        .max(0.0)
+                        .min(last);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if let Some(drag) = self.state.drag.take() {
+                    if drag.is_dragging {
+                        // A swipe that stopped a while ago has no momentum
+                        let velocity =
+                            if drag.last_moved_at.elapsed().as_secs_f32()
+                                < Self::MOMENTUM
+                            {
+                                drag.velocity
+                            } else {
+                                0.0
+                            };
+
+                        let page =
+                            self.release_page(drag.start, velocity, area.width);
+
+                        self.change_page(page, shell);
+
+                        return event::Status::Captured;
+                    }
+                }
+            }
+            _ => {}
+        }
+
+        // The page may have been changed by the application
+        if self.state.is_snapping() && self.state.last_frame.is_none() {
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+
+        let width = area.width;
+        let cursor_position = if area.contains(cursor_position) {
+            cursor_position
+        } else {
+            Point::new(-1.0, -1.0)
+        };
+
+        let visible = self.visible_pages();
+        let position = self.state.position;
+
+        self.pages
+            .iter_mut()
+            .zip(layout.children())
+            .enumerate()
+            .filter(|(i, _)| visible.contains(i))
+            .map(|(i, (page, layout))| {
+                let translation = (i as f32 - position) * width;
+
+                page.on_event(
+                    event.clone(),
+                    layout,
+                    cursor_position - Vector::new(translation, 0.0),
+                    renderer,
+                    clipboard,
+                    shell,
+                )
+            })
+            .fold(event::Status::Ignored, event::Status::merge)
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+
+        if matches!(
+            self.state.drag,
+            Some(Drag {
+                is_dragging: true,
+                ..
+            })
+        ) {
+            return mouse::Interaction::Grabbing;
+        }
+
+        if self.indicators
+            && self
+                .indicator_bounds(bounds)
+                .any(|indicator| indicator.contains(cursor_position))
+        {
+            return mouse::Interaction::Pointer;
+        }
+
+        let area = Rectangle {
+            height: bounds.height - self.indicators_height(),
+            ..bounds
+        };
+
+        if !area.contains(cursor_position) {
+            return mouse::Interaction::default();
+        }
+
+        self.visible_pages()
+            .filter_map(|i| {
+                let page = self.pages.get(i)?;
+                let layout = layout.children().nth(i)?;
+                let translation = self.translation(i, area.width);
+
+                Some(page.mouse_interaction(
+                    layout,
+                    cursor_position - Vector::new(translation, 0.0),
+                    viewport,
+                    renderer,
+                ))
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let appearance = self.style_sheet.style();
+
+        if let Some(background) = appearance.background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: 0.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        let area = Rectangle {
+            height: bounds.height - self.indicators_height(),
+            ..bounds
+        };
+
+        let cursor_position = if area.contains(cursor_position) {
+            cursor_position
+        } else {
+            Point::new(-1.0, -1.0)
+        };
+
+        renderer.with_layer(area, |renderer| {
+            for i in self.visible_pages() {
+                let (page, layout) =
+                    match (self.pages.get(i), layout.children().nth(i)) {
+                        (Some(page), Some(layout)) => (page, layout),
+                        _ => continue,
+                    };
+
+                let translation =
+                    Vector::new(self.translation(i, area.width), 0.0);
+
+                renderer.with_translation(translation, |renderer| {
+                    page.draw(
+                        renderer,
+                        style,
+                        layout,
+                        cursor_position - translation,
+                        &Rectangle {
+                            x: viewport.x - translation.x,
+                            ..*viewport
+                        },
+                    );
+                });
+            }
+        });
+
+        if self.indicators {
+            for (i, indicator) in self.indicator_bounds(bounds).enumerate() {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: indicator,
+                        border_radius: Self::INDICATOR_SIZE / 2.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    if i == self.state.page {
+                        appearance.active_indicator_color
+                    } else {
+                        appearance.indicator_color
+                    },
+                );
+            }
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.indicators.hash(state);
+
+        for page in &self.pages {
+            page.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        // Overlays are only displayed once the current page is settled
+        if self.state.drag.is_some() || self.state.is_snapping() {
+            return None;
+        }
+
+        let page = self.state.page;
+
+        self.pages
+            .get_mut(page)?
+            .overlay(layout.children().nth(page)?, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Carousel<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+{
+    fn from(
+        carousel: Carousel<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(carousel)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+    use crate::widget::Space;
+
+    use std::time::Duration;
+
+    fn release(position: f32, start: f32, velocity: f32) -> usize {
+        let mut state = State::new();
+
+        let carousel = (0..3)
+            .fold(Carousel::<(), Null>::new(&mut state), |carousel, _| {
+                carousel.push(Space::with_width(Length::Fill))
+            });
+
+        carousel.state.position = position;
+        carousel.release_page(start, velocity, 400.0)
+    }
+
+    #[test]
+    fn releases_on_the_nearest_page() {
+        assert_eq!(release(1.4, 1.0, 0.0), 1);
+        assert_eq!(release(1.6, 1.0, 0.0), 2);
+        assert_eq!(release(0.6, 1.0, 0.0), 1);
+        assert_eq!(release(0.4, 1.0, 0.0), 0);
+    }
+
+    #[test]
+    fn releases_with_momentum() {
+        // 400 pixels per second move a 400 pixels wide page by 0.3 pages
+        assert_eq!(release(1.3, 1.0, -400.0), 2);
+        assert_eq!(release(0.7, 1.0, 400.0), 0);
+        assert_eq!(release(1.3, 1.0, 400.0), 1);
+    }
+
+    #[test]
+    fn releases_one_page_away_at_most() {
+        assert_eq!(release(0.2, 0.0, -10_000.0), 1);
+        assert_eq!(release(1.0, 1.0, 10_000.0), 0);
+        assert_eq!(release(2.0, 2.0, -10_000.0), 2);
+    }
+
+    #[test]
+    fn snaps_to_the_current_page() {
+        let start = Instant::now();
+
+        let mut state = State::new();
+        state.page_count = 3;
+        state.next();
+
+        assert!(state.is_snapping());
+        assert!(state.snap(start, 12.0));
+        assert_eq!(state.position, 0.0);
+
+        assert!(state.snap(start + Duration::from_millis(100), 12.0));
+        assert!(state.position > 0.6 && state.position < 0.8);
+
+        assert!(!state.snap(start + Duration::from_secs(1), 12.0));
+        assert_eq!(state.position, 1.0);
+        assert!(!state.is_snapping());
+    }
+}
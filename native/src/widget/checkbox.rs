@@ -10,8 +10,8 @@ use crate::text;
 use crate::touch;
 use crate::widget::{self, Row, Text};
 use crate::{
-    Alignment, Clipboard, Element, Hasher, Layout, Length, Point, Rectangle,
-    Shell, Widget,
+    Alignment, Clipboard, Color, Element, Hasher, Layout, Length, Point,
+    Rectangle, Shell, Widget,
 };
 
 pub use iced_style::checkbox::{Style, StyleSheet};
@@ -32,10 +32,15 @@ pub use iced_style::checkbox::{Style, StyleSheet};
 /// Checkbox::new(is_checked, "Toggle me!", Message::CheckboxToggled);
 /// ```
 ///
+/// A [`Checkbox`] can also be [`indeterminate`](Checkbox::indeterminate),
+/// which is useful to summarize a group of other checkboxes where only some
+/// of them are checked.
+///
 /// ![Checkbox drawn by `iced_wgpu`](https://github.com/iced-rs/iced/blob/7760618fb112074bc40b148944521f312152012a/docs/images/checkbox.png?raw=true)
 #[allow(missing_debug_implementations)]
 pub struct Checkbox<'a, Message, Renderer: text::Renderer> {
     is_checked: bool,
+    is_indeterminate: bool,
     on_toggle: Box<dyn Fn(bool) -> Message>,
     label: String,
     width: Length,
@@ -67,6 +72,7 @@ impl<'a, Message, Renderer: text::Renderer> Checkbox<'a, Message, Renderer> {
     {
         Checkbox {
             is_checked,
+            is_indeterminate: false,
             on_toggle: Box::new(f),
             label: label.into(),
             width: Length::Shrink,
@@ -78,6 +84,15 @@ impl<'a, Message, Renderer: text::Renderer> Checkbox<'a, Message, Renderer> {
         }
     }
 
+    /// Sets whether the [`Checkbox`] is neither checked nor unchecked.
+    ///
+    /// An indeterminate [`Checkbox`] displays a dash instead of a checkmark
+    /// and becomes checked when toggled.
+    pub fn indeterminate(mut self, is_indeterminate: bool) -> Self {
+        self.is_indeterminate = is_indeterminate;
+        self
+    }
+
     /// Sets the size of the [`Checkbox`].
     pub fn size(mut self, size: u16) -> Self {
         self.size = size;
@@ -171,7 +186,9 @@ where
                 let mouse_over = layout.bounds().contains(cursor_position);
 
                 if mouse_over {
-                    shell.publish((self.on_toggle)(!self.is_checked));
+                    let is_checked = self.is_indeterminate || !self.is_checked;
+
+                    shell.publish((self.on_toggle)(is_checked));
 
                     return event::Status::Captured;
                 }
@@ -209,10 +226,11 @@ where
 
         let mut children = layout.children();
 
-        let custom_style = if is_mouse_over {
-            self.style_sheet.hovered(self.is_checked)
-        } else {
-            self.style_sheet.active(self.is_checked)
+        let custom_style = match (is_mouse_over, self.is_indeterminate) {
+            (true, true) => self.style_sheet.hovered_indeterminate(),
+            (true, false) => self.style_sheet.hovered(self.is_checked),
+            (false, true) => self.style_sheet.active_indeterminate(),
+            (false, false) => self.style_sheet.active(self.is_checked),
         };
 
         {
@@ -229,7 +247,25 @@ where
                 custom_style.background,
             );
 
-            if self.is_checked {
+            if self.is_indeterminate {
+                let dash_width = (bounds.width * 0.5).round();
+                let dash_height = (bounds.height * 0.1).round().max(2.0);
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            x: bounds.center_x() - dash_width / 2.0,
+                            y: bounds.center_y() - dash_height / 2.0,
+                            width: dash_width,
+                            height: dash_height,
+                        },
+                        border_radius: dash_height / 2.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    custom_style.checkmark_color,
+                );
+            } else if self.is_checked {
                 renderer.fill_text(text::Text {
                     content: &Renderer::CHECKMARK_ICON.to_string(),
                     font: Renderer::ICON_FONT,
@@ -242,6 +278,8 @@ where
                     color: custom_style.checkmark_color,
                     horizontal_alignment: alignment::Horizontal::Center,
                     vertical_alignment: alignment::Vertical::Center,
+                    format: text::Format::default(),
+                    decorations: &[],
                 });
             }
         }
@@ -259,6 +297,8 @@ where
                 custom_style.text_color,
                 alignment::Horizontal::Left,
                 alignment::Vertical::Center,
+                Default::default(),
+                &[],
             );
         }
     }
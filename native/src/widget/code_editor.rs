@@ -0,0 +1,1378 @@
+//! Display and edit source code with syntax highlighting.
+//!
+//! A [`CodeEditor`] has some local [`State`], which owns the code.
+mod buffer;
+mod highlighter;
+
+pub use buffer::Position;
+pub use highlighter::Theme;
+
+use buffer::{Buffer, INDENT};
+use highlighter::Highlighter;
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse::{self, click};
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::{
+    Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Shell,
+    Size, Vector, Widget,
+};
+
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::hash::Hash;
+
+pub use iced_style::code_editor::{Style, StyleSheet};
+
+/// The horizontal space around the line numbers.
+const GUTTER_PADDING: f32 = 8.0;
+
+/// The space around the code.
+const PADDING: f32 = 4.0;
+
+const SCROLLBAR_WIDTH: f32 = 6.0;
+const SCROLLBAR_MARGIN: f32 = 2.0;
+const MIN_THUMB_HEIGHT: f32 = 20.0;
+
+/// A view of source code with line numbers and syntax highlighting, which
+/// can optionally be edited.
+///
+/// It only lays out and highlights the lines that are visible, so it can
+/// display files with many thousands of lines.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::code_editor;
+/// # type CodeEditor<'a, Message> =
+/// #     iced_native::widget::CodeEditor<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     CodeEdited,
+/// }
+///
+/// let mut state = code_editor::State::new("fn main() {}", "rs");
+///
+/// let editor = CodeEditor::new(&mut state).on_edit(Message::CodeEdited);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct CodeEditor<'a, Message, Renderer: text::Renderer> {
+    state: &'a mut State,
+    on_edit: Option<Message>,
+    font: Renderer::Font,
+    size: Option<u16>,
+    width: Length,
+    height: Length,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> CodeEditor<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Creates a new read-only [`CodeEditor`] displaying the code of the
+    /// given [`State`].
+    ///
+    /// Its code can be selected and copied, but not edited.
+    pub fn new(state: &'a mut State) -> Self {
+        CodeEditor {
+            state,
+            on_edit: None,
+            font: Renderer::monospace_font(),
+            size: None,
+            width: Length::Fill,
+            height: Length::Fill,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Makes the [`CodeEditor`] editable, producing the given message every
+    /// time its code changes.
+    ///
+    /// The new code can be obtained with [`State::text`].
+    pub fn on_edit(mut self, message: Message) -> Self {
+        self.on_edit = Some(message);
+        self
+    }
+
+    /// Sets the font of the [`CodeEditor`].
+    ///
+    /// It should be a monospace font. By default, the [`monospace_font`] of
+    /// the renderer is used.
+    ///
+    /// [`monospace_font`]: text::Renderer::monospace_font
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the text size of the [`CodeEditor`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self
+    }
+
+    /// Sets the width of the [`CodeEditor`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`CodeEditor`].
+    ///
+    /// With [`Length::Shrink`], it grows to fit all of its lines.
+    pub fn height(mut self, height: Length) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the style of the [`CodeEditor`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn metrics(&self, renderer: &Renderer) -> Metrics {
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let (_, line_height) = renderer.measure(
+            " ",
+            size,
+            self.font.clone(),
+            Size::INFINITY,
+            text::Format::default(),
+        );
+
+        let digits = self.state.buffer.line_count().to_string().len().max(2);
+
+        let gutter_width = renderer.measure_width(
+            &"9".repeat(digits),
+            size,
+            self.font.clone(),
+        ) + GUTTER_PADDING * 2.0;
+
+        Metrics {
+            size,
+            line_height,
+            gutter_width,
+        }
+    }
+
+    /// Returns the horizontal position of the given [`Position`], relative
+    /// to the start of its line.
+    fn x(&self, renderer: &Renderer, size: u16, position: Position) -> f32 {
+        let prefix: String = self
+            .state
+            .buffer
+            .line(position.line)
+            .chars()
+            .take(position.column)
+            .collect();
+
+        renderer.measure_width(&expand(&prefix), size, self.font.clone())
+    }
+
+    /// Returns the [`Position`] in the code under the given point.
+    fn position_at(
+        &self,
+        renderer: &Renderer,
+        metrics: Metrics,
+        bounds: Rectangle,
+        point: Point,
+    ) -> Position {
+        let buffer = &self.state.buffer;
+
+        let line = ((point.y - bounds.y - PADDING + self.state.scroll.y)
+            / metrics.line_height)
+            .floor()
+            .max(0.0) as usize;
+
+        let line = line.min(buffer.line_count() - 1);
+        let content = buffer.line(line);
+
+        let x = point.x - bounds.x - metrics.gutter_width - PADDING
+            + self.state.scroll.x;
+
+        if x <= 0.0 {
+            return Position::new(line, 0);
+        }
+
+        let index = renderer
+            .hit_test(
+                &expand(content),
+                f32::from(metrics.size),
+                self.font.clone(),
+                Size::INFINITY,
+                text::Format::default(),
+                Point::new(x, metrics.line_height / 2.0),
+                true,
+            )
+            .map(text::Hit::cursor)
+            .unwrap_or(0);
+
+        // Tabs take more than one character once expanded
+        let mut start = 0;
+
+        for (column, c) in content.chars().enumerate() {
+            let width = if c == '\t' { INDENT.len() } else { 1 };
+
+            if start + width > index {
+                let column = if index - start > width / 2 {
+                    column + 1
+                } else {
+                    column
+                };
+
+                return Position::new(line, column);
+            }
+
+            start += width;
+        }
+
+        Position::new(line, buffer.line_length(line))
+    }
+
+    /// Scrolls the [`CodeEditor`] so its cursor is visible.
+    fn scroll_to_cursor(
+        &mut self,
+        renderer: &Renderer,
+        metrics: Metrics,
+        bounds: Rectangle,
+    ) {
+        let cursor = self.state.cursor;
+        let top = cursor.line as f32 * metrics.line_height;
+
+        let scroll = &mut self.state.scroll;
+
+        if top < scroll.y {
+            scroll.y = top;
+        } else if top + metrics.line_height + PADDING * 2.0
+            > scroll.y + bounds.height
+        {
+            scroll.y =
+                top + metrics.line_height + PADDING * 2.0 - bounds.height;
+        }
+
+        let x = self.x(renderer, metrics.size, cursor);
+        let width = text_bounds(bounds, metrics).width - PADDING * 2.0;
+
+        let scroll = &mut self.state.scroll;
+
+        if x < scroll.x {
+            scroll.x = x;
+        } else if x > scroll.x + width {
+            scroll.x = x - width;
+        }
+
+        self.clamp_scroll(renderer, metrics, bounds);
+    }
+
+    /// Keeps the scroll offset of the [`CodeEditor`] within its content.
+    fn clamp_scroll(
+        &mut self,
+        renderer: &Renderer,
+        metrics: Metrics,
+        bounds: Rectangle,
+    ) {
+        let max_y = (content_height(&self.state.buffer, metrics)
+            - bounds.height)
+            .max(0.0);
+
+        self.state.scroll.y = self.state.scroll.y.clamp(0.0, max_y);
+
+        // Only the visible lines are measured, which is enough to scroll
+        // horizontally through them
+        let (first, last) = visible_lines(
+            &self.state.buffer,
+            self.state.scroll.y,
+            bounds,
+            metrics,
+        );
+
+        let widest = (first..=last)
+            .map(|line| {
+                renderer.measure_width(
+                    &expand(self.state.buffer.line(line)),
+                    metrics.size,
+                    self.font.clone(),
+                )
+            })
+            .fold(0.0, f32::max);
+
+        let max_x = (widest + PADDING * 2.0
+            - text_bounds(bounds, metrics).width)
+            .max(0.0);
+
+        self.state.scroll.x = self.state.scroll.x.clamp(0.0, max_x);
+    }
+
+    fn publish_edit(&self, shell: &mut Shell<'_, Message>)
+    where
+        Message: Clone,
+    {
+        if let Some(on_edit) = &self.on_edit {
+            shell.publish(on_edit.clone());
+        }
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for CodeEditor<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        self.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(self.height);
+        let metrics = self.metrics(renderer);
+
+        let size = limits.resolve(Size::new(
+            limits.max().width,
+            content_height(&self.state.buffer, metrics),
+        ));
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let metrics = self.metrics(renderer);
+        let is_editable = self.on_edit.is_some();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_clicked = bounds.contains(cursor_position);
+
+                self.state.is_focused = is_clicked;
+
+                if !is_clicked {
+                    return event::Status::Ignored;
+                }
+
+                let content_height =
+                    content_height(&self.state.buffer, metrics);
+
+                if let Some(thumb) =
+                    thumb(bounds, content_height, self.state.scroll.y)
+                {
+                    if cursor_position.x >= thumb.x - SCROLLBAR_MARGIN {
+                        let grabbed_at = if thumb.contains(cursor_position) {
+                            cursor_position.y - thumb.y
+                        } else {
+                            thumb.height / 2.0
+                        };
+
+                        self.state.scrollbar_grabbed_at = Some(grabbed_at);
+                        self.state.scroll_to_thumb(
+                            bounds,
+                            content_height,
+                            cursor_position.y - grabbed_at,
+                        );
+
+                        return event::Status::Captured;
+                    }
+                }
+
+                let position = self.position_at(
+                    renderer,
+                    metrics,
+                    bounds,
+                    cursor_position,
+                );
+
+                let click =
+                    mouse::Click::new(cursor_position, self.state.last_click);
+
+                match click.kind() {
+                    click::Kind::Single => {
+                        let extend = self.state.keyboard_modifiers.shift();
+
+                        self.state.select(position, extend);
+                        self.state.is_dragging = true;
+                    }
+                    click::Kind::Double => {
+                        let (start, end) = self.state.buffer.word_at(position);
+
+                        self.state.select(start, false);
+                        self.state.select(end, true);
+                    }
+                    click::Kind::Triple => {
+                        let end = self.state.buffer.next(Position::new(
+                            position.line,
+                            self.state.buffer.line_length(position.line),
+                        ));
+
+                        self.state
+                            .select(Position::new(position.line, 0), false);
+                        self.state.select(end, true);
+                    }
+                }
+
+                self.state.last_click = Some(click);
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.is_dragging = false;
+                self.state.scrollbar_grabbed_at = None;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                if let Some(grabbed_at) = self.state.scrollbar_grabbed_at {
+                    let content_height =
+                        content_height(&self.state.buffer, metrics);
+
+                    self.state.scroll_to_thumb(
+                        bounds,
+                        content_height,
+                        position.y - grabbed_at,
+                    );
+
+                    return event::Status::Captured;
+                }
+
+                if self.state.is_dragging {
+                    let position =
+                        self.position_at(renderer, metrics, bounds, position);
+
+                    self.state.select(position, true);
+                    self.scroll_to_cursor(renderer, metrics, bounds);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let delta = match delta {
+                    mouse::ScrollDelta::Lines { x, y } => {
+                        Vector::new(x, y) * 60.0
+                    }
+                    mouse::ScrollDelta::Pixels { x, y } => Vector::new(x, y),
+                };
+
+                self.state.scroll = self.state.scroll - delta;
+                self.clamp_scroll(renderer, metrics, bounds);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::CharacterReceived(c))
+                if self.state.is_focused
+                    && is_editable
+                    && !self.state.keyboard_modifiers.command()
+                    && !c.is_control() =>
+            {
+                self.state.type_character(c);
+                self.scroll_to_cursor(renderer, metrics, bounds);
+                self.publish_edit(shell);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.state.is_focused => {
+                let state = &mut *self.state;
+                let buffer = &state.buffer;
+
+                let extend = modifiers.shift();
+                let jump = platform::is_jump_modifier_pressed(modifiers);
+                let page = (bounds.height / metrics.line_height) as isize;
+
+                let mut is_edited = false;
+
+                match key_code {
+                    keyboard::KeyCode::Left => {
+                        let position = match state.selection_range() {
+                            Some((start, _)) if !extend => start,
+                            _ if jump => {
+                                buffer.previous_word_start(state.cursor)
+                            }
+                            _ => buffer.previous(state.cursor),
+                        };
+
+                        state.select(position, extend);
+                    }
+                    keyboard::KeyCode::Right => {
+                        let position = match state.selection_range() {
+                            Some((_, end)) if !extend => end,
+                            _ if jump => buffer.next_word_end(state.cursor),
+                            _ => buffer.next(state.cursor),
+                        };
+
+                        state.select(position, extend);
+                    }
+                    keyboard::KeyCode::Up => state.move_vertically(-1, extend),
+                    keyboard::KeyCode::Down => state.move_vertically(1, extend),
+                    keyboard::KeyCode::PageUp => {
+                        state.move_vertically(-page, extend)
+                    }
+                    keyboard::KeyCode::PageDown => {
+                        state.move_vertically(page, extend)
+                    }
+                    keyboard::KeyCode::Home => {
+                        let position = if modifiers.command() {
+                            Position::default()
+                        } else {
+                            // Go to the start of the code of the line first,
+                            // then to the start of the line
+                            let line = state.cursor.line;
+                            let indentation =
+                                buffer.indentation(line).chars().count();
+
+                            let column = if state.cursor.column == indentation {
+                                0
+                            } else {
+                                indentation
+                            };
+
+                            Position::new(line, column)
+                        };
+
+                        state.select(position, extend);
+                    }
+                    keyboard::KeyCode::End => {
+                        let position = if modifiers.command() {
+                            buffer.end()
+                        } else {
+                            let line = state.cursor.line;
+
+                            Position::new(line, buffer.line_length(line))
+                        };
+
+                        state.select(position, extend);
+                    }
+                    keyboard::KeyCode::A if modifiers.command() => {
+                        state.select_all();
+                    }
+                    keyboard::KeyCode::C if modifiers.command() => {
+                        if let Some(selection) = state.selection() {
+                            clipboard.write(selection);
+                        }
+                    }
+                    keyboard::KeyCode::X
+                        if modifiers.command() && is_editable =>
+                    {
+                        if let Some(selection) = state.selection() {
+                            clipboard.write(selection);
+
+                            is_edited = state.delete_selection();
+                        }
+                    }
+                    keyboard::KeyCode::V
+                        if modifiers.command() && is_editable =>
+                    {
+                        if let Some(content) = clipboard.read() {
+                            state.replace_selection(&content);
+
+                            is_edited = true;
+                        }
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter
+                        if is_editable =>
+                    {
+                        state.newline();
+
+                        is_edited = true;
+                    }
+                    keyboard::KeyCode::Backspace if is_editable => {
+                        is_edited = state.backspace();
+                    }
+                    keyboard::KeyCode::Delete if is_editable => {
+                        is_edited = state.delete();
+                    }
+                    keyboard::KeyCode::Tab if is_editable => {
+                        if extend {
+                            is_edited = state.dedent();
+                        } else {
+                            state.indent();
+
+                            is_edited = true;
+                        }
+                    }
+                    keyboard::KeyCode::Escape => {
+                        state.is_focused = false;
+                        state.is_dragging = false;
+                    }
+                    _ => return event::Status::Ignored,
+                }
+
+                self.scroll_to_cursor(renderer, metrics, bounds);
+
+                if is_edited {
+                    self.publish_edit(shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::ModifiersChanged(modifiers)) => {
+                self.state.keyboard_modifiers = modifiers;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let metrics = self.metrics(renderer);
+
+        let thumb = thumb(
+            bounds,
+            content_height(&self.state.buffer, metrics),
+            self.state.scroll.y,
+        );
+
+        let is_over_thumb = matches!(
+            thumb, Some(thumb) if thumb.contains(cursor_position)
+        );
+
+        if text_bounds(bounds, metrics).contains(cursor_position)
+            && !is_over_thumb
+        {
+            mouse::Interaction::Text
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let state = &self.state;
+        let buffer = &state.buffer;
+
+        let bounds = layout.bounds();
+        let metrics = self.metrics(renderer);
+        let text_bounds = text_bounds(bounds, metrics);
+        let size = f32::from(metrics.size);
+
+        let style = if state.is_focused {
+            self.style_sheet.focused()
+        } else {
+            self.style_sheet.active()
+        };
+
+        let mut highlighter = state.highlighter.borrow_mut();
+        let palette = highlighter.palette();
+
+        let (first, last) =
+            visible_lines(buffer, state.scroll.y, bounds, metrics);
+
+        highlighter.highlight(buffer, last);
+
+        let line_y = |line: usize| {
+            bounds.y + PADDING + line as f32 * metrics.line_height
+                - state.scroll.y
+        };
+
+        let origin = text_bounds.x + PADDING - state.scroll.x;
+
+        let fill =
+            |renderer: &mut Renderer, bounds: Rectangle, color: Color| {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    color,
+                );
+            };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: style.border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            palette.background,
+        );
+
+        // The gutter is only rounded on its left side
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    width: metrics.gutter_width,
+                    ..bounds
+                },
+                border_radius: style.border_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            palette.gutter,
+        );
+
+        fill(
+            renderer,
+            Rectangle {
+                x: bounds.x + metrics.gutter_width / 2.0,
+                width: metrics.gutter_width / 2.0,
+                ..bounds
+            },
+            palette.gutter,
+        );
+
+        let selection = state.selection_range();
+
+        renderer.with_layer(text_bounds, |renderer| {
+            for line in first..=last {
+                let y = line_y(line);
+                let content = buffer.line(line);
+
+                if state.is_focused
+                    && selection.is_none()
+                    && line == state.cursor.line
+                {
+                    fill(
+                        renderer,
+                        Rectangle {
+                            y,
+                            height: metrics.line_height,
+                            ..text_bounds
+                        },
+                        palette.current_line,
+                    );
+                }
+
+                if let Some((start, end)) = selection {
+                    if start.line <= line && line <= end.line {
+                        let left = if line == start.line {
+                            self.x(renderer, metrics.size, start)
+                        } else {
+                            0.0
+                        };
+
+                        // Selected line breaks are shown as a space
+                        let right = if line == end.line {
+                            self.x(renderer, metrics.size, end)
+                        } else {
+                            let end =
+                                Position::new(line, buffer.line_length(line));
+
+                            self.x(renderer, metrics.size, end)
+                                + renderer.measure_width(
+                                    " ",
+                                    metrics.size,
+                                    self.font.clone(),
+                                )
+                        };
+
+                        fill(
+                            renderer,
+                            Rectangle {
+                                x: origin + left,
+                                y,
+                                width: right - left,
+                                height: metrics.line_height,
+                            },
+                            palette.selection,
+                        );
+                    }
+                }
+
+                let mut x = origin;
+
+                for span in highlighter.spans(line) {
+                    let text = expand(&content[span.range.clone()]);
+
+                    let width = renderer.measure_width(
+                        &text,
+                        metrics.size,
+                        self.font.clone(),
+                    );
+
+                    if x + width >= text_bounds.x
+                        && x <= text_bounds.x + text_bounds.width
+                    {
+                        renderer.fill_text(Text {
+                            content: &text,
+                            bounds: Rectangle {
+                                x,
+                                y,
+                                width: f32::INFINITY,
+                                height: metrics.line_height,
+                            },
+                            size,
+                            color: span.color,
+                            font: self.font.clone(),
+                            horizontal_alignment: alignment::Horizontal::Left,
+                            vertical_alignment: alignment::Vertical::Top,
+                            format: text::Format::default(),
+                            decorations: &[],
+                        });
+                    }
+
+                    x += width;
+                }
+            }
+
+            if state.is_focused {
+                if let Some((bracket, other)) =
+                    buffer.matching_brackets(state.cursor)
+                {
+                    for position in [bracket, other].iter() {
+                        if position.line < first || position.line > last {
+                            continue;
+                        }
+
+                        let left = self.x(renderer, metrics.size, *position);
+                        let right = self.x(
+                            renderer,
+                            metrics.size,
+                            Position::new(position.line, position.column + 1),
+                        );
+
+                        renderer.fill_quad(
+                            renderer::Quad {
+                                bounds: Rectangle {
+                                    x: origin + left,
+                                    y: line_y(position.line),
+                                    width: right - left,
+                                    height: metrics.line_height,
+                                },
+                                border_radius: 0.0,
+                                border_width: 1.0,
+                                border_color: palette.bracket,
+                            },
+                            Color::TRANSPARENT,
+                        );
+                    }
+                }
+
+                fill(
+                    renderer,
+                    Rectangle {
+                        x: origin
+                            + self.x(renderer, metrics.size, state.cursor),
+                        y: line_y(state.cursor.line),
+                        width: 1.0,
+                        height: metrics.line_height,
+                    },
+                    palette.cursor,
+                );
+            }
+        });
+
+        let gutter = Rectangle {
+            width: metrics.gutter_width,
+            ..bounds
+        };
+
+        renderer.with_layer(gutter, |renderer| {
+            for line in first..=last {
+                renderer.fill_text(Text {
+                    content: &(line + 1).to_string(),
+                    bounds: Rectangle {
+                        x: gutter.x + gutter.width - GUTTER_PADDING,
+                        y: line_y(line),
+                        width: gutter.width,
+                        height: metrics.line_height,
+                    },
+                    size,
+                    color: if line == state.cursor.line {
+                        palette.text
+                    } else {
+                        palette.line_number
+                    },
+                    font: self.font.clone(),
+                    horizontal_alignment: alignment::Horizontal::Right,
+                    vertical_alignment: alignment::Vertical::Top,
+                    format: text::Format::default(),
+                    decorations: &[],
+                });
+            }
+        });
+
+        if let Some(thumb) =
+            thumb(bounds, content_height(buffer, metrics), state.scroll.y)
+        {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: thumb,
+                    border_radius: SCROLLBAR_WIDTH / 2.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                style.scrollbar_color,
+            );
+        }
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: style.border_radius,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            },
+            Color::TRANSPARENT,
+        );
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.size.hash(state);
+        self.state.buffer.line_count().hash(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<CodeEditor<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        code_editor: CodeEditor<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(code_editor)
+    }
+}
+
+/// The local state of a [`CodeEditor`].
+///
+/// It owns the code being displayed, which can be obtained with
+/// [`State::text`].
+#[derive(Debug)]
+pub struct State {
+    buffer: Buffer,
+    language: String,
+    theme: Theme,
+    highlighter: RefCell<Highlighter>,
+    cursor: Position,
+    anchor: Position,
+    preferred_column: Option<usize>,
+    scroll: Vector,
+    is_focused: bool,
+    is_dragging: bool,
+    scrollbar_grabbed_at: Option<f32>,
+    last_click: Option<mouse::Click>,
+    keyboard_modifiers: keyboard::Modifiers,
+}
+
+impl State {
+    /// Creates a new [`State`] with the given code, highlighted as the
+    /// language with the given name or file extension, like `"Rust"`,
+    /// `"json"`, or `"sql"`.
+    ///
+    /// Code in an unknown language is displayed as plain text.
+    pub fn new(text: &str, language: &str) -> Self {
+        let theme = Theme::default();
+
+        State {
+            buffer: Buffer::new(text),
+            language: String::from(language),
+            theme,
+            highlighter: RefCell::new(Highlighter::new(language, theme)),
+            cursor: Position::default(),
+            anchor: Position::default(),
+            preferred_column: None,
+            scroll: Vector::new(0.0, 0.0),
+            is_focused: false,
+            is_dragging: false,
+            scrollbar_grabbed_at: None,
+            last_click: None,
+            keyboard_modifiers: keyboard::Modifiers::default(),
+        }
+    }
+
+    /// Returns the code of the [`State`].
+    pub fn text(&self) -> String {
+        self.buffer.text()
+    }
+
+    /// Replaces the code of the [`State`].
+    pub fn set_text(&mut self, text: &str) {
+        self.buffer = Buffer::new(text);
+        self.cursor = self.buffer.clamp(self.cursor);
+        self.anchor = self.cursor;
+        self.invalidate(0);
+    }
+
+    /// Returns the amount of lines of the code.
+    pub fn line_count(&self) -> usize {
+        self.buffer.line_count()
+    }
+
+    /// Returns the language of the code.
+    pub fn language(&self) -> &str {
+        &self.language
+    }
+
+    /// Changes the language used to highlight the code.
+    pub fn set_language(&mut self, language: &str) {
+        self.language = String::from(language);
+        self.highlighter = RefCell::new(Highlighter::new(language, self.theme));
+    }
+
+    /// Returns the [`Theme`] used to highlight the code.
+    pub fn theme(&self) -> Theme {
+        self.theme
+    }
+
+    /// Changes the [`Theme`] used to highlight the code.
+    pub fn set_theme(&mut self, theme: Theme) {
+        self.theme = theme;
+        self.highlighter =
+            RefCell::new(Highlighter::new(&self.language, theme));
+    }
+
+    /// Returns the [`Position`] of the cursor.
+    pub fn cursor(&self) -> Position {
+        self.cursor
+    }
+
+    /// Moves the cursor to the given [`Position`], clearing the selection.
+    pub fn move_cursor_to(&mut self, position: Position) {
+        self.select(position, false);
+    }
+
+    /// Returns the selected code, if any.
+    pub fn selection(&self) -> Option<String> {
+        self.selection_range()
+            .map(|(start, end)| self.buffer.slice(start, end))
+    }
+
+    /// Selects all the code.
+    pub fn select_all(&mut self) {
+        self.select(Position::default(), false);
+        self.select(self.buffer.end(), true);
+    }
+
+    /// Returns whether the [`CodeEditor`] is focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focuses the [`CodeEditor`].
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`CodeEditor`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+
+    fn selection_range(&self) -> Option<(Position, Position)> {
+        if self.anchor == self.cursor {
+            None
+        } else {
+            Some((self.anchor.min(self.cursor), self.anchor.max(self.cursor)))
+        }
+    }
+
+    /// Moves the cursor to the given [`Position`], extending the selection
+    /// if needed.
+    fn select(&mut self, position: Position, extend: bool) {
+        self.cursor = self.buffer.clamp(position);
+        self.preferred_column = None;
+
+        if !extend {
+            self.anchor = self.cursor;
+        }
+    }
+
+    /// Moves the cursor up or down by the given amount of lines, keeping
+    /// its column when going through shorter lines.
+    fn move_vertically(&mut self, lines: isize, extend: bool) {
+        let column = self.preferred_column.unwrap_or(self.cursor.column);
+        let line = (self.cursor.line as isize + lines).max(0) as usize;
+
+        self.select(Position::new(line, column), extend);
+        self.preferred_column = Some(column);
+    }
+
+    fn invalidate(&mut self, line: usize) {
+        self.highlighter.get_mut().invalidate(line);
+    }
+
+    fn delete_selection(&mut self) -> bool {
+        match self.selection_range() {
+            Some((start, end)) => {
+                self.buffer.delete(start, end);
+                self.select(start, false);
+                self.invalidate(start.line);
+
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn replace_selection(&mut self, text: &str) {
+        let _ = self.delete_selection();
+
+        let line = self.cursor.line;
+        let end = self.buffer.insert(self.cursor, text);
+
+        self.select(end, false);
+        self.invalidate(line);
+    }
+
+    fn type_character(&mut self, c: char) {
+        let _ = self.delete_selection();
+
+        let line = self.cursor.line;
+
+        // Closing brackets typed at the start of a line undo a level of
+        // indentation
+        let is_closing = c == ')' || c == ']' || c == '}';
+        let is_indentation =
+            self.buffer.indentation(line).chars().count() == self.cursor.column;
+
+        if is_closing && is_indentation && self.cursor.column > 0 {
+            let removed = self.buffer.dedent(line);
+
+            self.select(
+                Position::new(line, self.cursor.column - removed),
+                false,
+            );
+        }
+
+        self.replace_selection(&c.to_string());
+    }
+
+    fn newline(&mut self) {
+        let _ = self.delete_selection();
+
+        let line = self.cursor.line;
+        let cursor = self.buffer.newline(self.cursor);
+
+        self.select(cursor, false);
+        self.invalidate(line);
+    }
+
+    fn backspace(&mut self) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+
+        let start = self.buffer.previous(self.cursor);
+
+        if start == self.cursor {
+            return false;
+        }
+
+        self.buffer.delete(start, self.cursor);
+        self.select(start, false);
+        self.invalidate(start.line);
+
+        true
+    }
+
+    fn delete(&mut self) -> bool {
+        if self.delete_selection() {
+            return true;
+        }
+
+        let end = self.buffer.next(self.cursor);
+
+        if end == self.cursor {
+            return false;
+        }
+
+        self.buffer.delete(self.cursor, end);
+        self.invalidate(self.cursor.line);
+
+        true
+    }
+
+    /// Indents the selected lines, or inserts an indentation at the cursor.
+    fn indent(&mut self) {
+        match self.selection_range() {
+            Some((start, end)) if start.line != end.line => {
+                for line in start.line..=end.line {
+                    let _ = self.buffer.insert(Position::new(line, 0), INDENT);
+                }
+
+                self.anchor.column += INDENT.len();
+                self.cursor.column += INDENT.len();
+                self.invalidate(start.line);
+            }
+            _ => self.replace_selection(INDENT),
+        }
+    }
+
+    /// Removes a level of indentation from the selected lines.
+    fn dedent(&mut self) -> bool {
+        let (start, end) =
+            self.selection_range().unwrap_or((self.cursor, self.cursor));
+
+        let mut is_dedented = false;
+
+        for line in start.line..=end.line {
+            let removed = self.buffer.dedent(line);
+
+            for position in [&mut self.anchor, &mut self.cursor].iter_mut() {
+                if position.line == line {
+                    position.column = position.column.saturating_sub(removed);
+                }
+            }
+
+            is_dedented = is_dedented || removed > 0;
+        }
+
+        if is_dedented {
+            self.invalidate(start.line);
+        }
+
+        is_dedented
+    }
+
+    fn scroll_to_thumb(
+        &mut self,
+        bounds: Rectangle,
+        content_height: f32,
+        thumb_y: f32,
+    ) {
+        let thumb_height = thumb_height(bounds, content_height);
+        let track = bounds.height - thumb_height;
+
+        if track > 0.0 {
+            let ratio = ((thumb_y - bounds.y) / track).clamp(0.0, 1.0);
+
+            self.scroll.y = ratio * (content_height - bounds.height);
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Metrics {
+    size: u16,
+    line_height: f32,
+    gutter_width: f32,
+}
+
+/// Returns the bounds of the code of a [`CodeEditor`], next to its gutter.
+fn text_bounds(bounds: Rectangle, metrics: Metrics) -> Rectangle {
+    Rectangle {
+        x: bounds.x + metrics.gutter_width,
+        width: (bounds.width - metrics.gutter_width).max(0.0),
+        ..bounds
+    }
+}
+
+fn content_height(buffer: &Buffer, metrics: Metrics) -> f32 {
+    buffer.line_count() as f32 * metrics.line_height + PADDING * 2.0
+}
+
+/// Returns the first and last lines visible with the given scroll offset.
+fn visible_lines(
+    buffer: &Buffer,
+    scroll: f32,
+    bounds: Rectangle,
+    metrics: Metrics,
+) -> (usize, usize) {
+    let first = ((scroll - PADDING) / metrics.line_height).floor().max(0.0);
+    let last = ((scroll + bounds.height - PADDING) / metrics.line_height)
+        .ceil()
+        .max(0.0);
+
+    let last_line = buffer.line_count() - 1;
+
+    (
+        (first as usize).min(last_line),
+        (last as usize).min(last_line),
+    )
+}
+
+fn thumb_height(bounds: Rectangle, content_height: f32) -> f32 {
+    (bounds.height * bounds.height / content_height)
+        .max(MIN_THUMB_HEIGHT)
+        .min(bounds.height)
+}
+
+/// Returns the bounds of the thumb of the scrollbar, if the code does not
+/// fit vertically.
+fn thumb(
+    bounds: Rectangle,
+    content_height: f32,
+    scroll: f32,
+) -> Option<Rectangle> {
+    if content_height <= bounds.height {
+        return None;
+    }
+
+    let height = thumb_height(bounds, content_height);
+    let ratio = scroll / (content_height - bounds.height);
+
+    Some(Rectangle {
+        x: bounds.x + bounds.width - SCROLLBAR_WIDTH - SCROLLBAR_MARGIN,
+        y: bounds.y + ratio * (bounds.height - height),
+        width: SCROLLBAR_WIDTH,
+        height,
+    })
+}
+
+/// Replaces the tabs of some text with spaces, since their width varies
+/// between fonts.
+fn expand(text: &str) -> Cow<'_, str> {
+    if text.contains('\t') {
+        Cow::Owned(text.replace('\t', INDENT))
+    } else {
+        Cow::Borrowed(text)
+    }
+}
+
+mod platform {
+    use crate::keyboard;
+
+    pub fn is_jump_modifier_pressed(modifiers: keyboard::Modifiers) -> bool {
+        if cfg!(target_os = "macos") {
+            modifiers.alt()
+        } else {
+            modifiers.control()
+        }
+    }
+}
@@ -0,0 +1,553 @@
+/// The text inserted by a single level of indentation.
+pub const INDENT: &str = "    ";
+
+/// The maximum amount of lines scanned when looking for a matching bracket.
+const MAX_BRACKET_DISTANCE: usize = 1_000;
+
+/// A position in the text of a [`CodeEditor`].
+///
+/// Positions are ordered by line and then by column.
+///
+/// [`CodeEditor`]: crate::widget::CodeEditor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Position {
+    /// The index of the line.
+    pub line: usize,
+
+    /// The index of the character in the line.
+    pub column: usize,
+}
+
+impl Position {
+    /// Creates a new [`Position`] with the given line and column.
+    pub fn new(line: usize, column: usize) -> Position {
+        Position { line, column }
+    }
+}
+
+/// The lines of text of a code editor.
+#[derive(Debug, Clone)]
+pub struct Buffer {
+    lines: Vec<String>,
+}
+
+impl Buffer {
+    pub fn new(text: &str) -> Self {
+        Buffer {
+            lines: split(text).map(String::from).collect(),
+        }
+    }
+
+    pub fn text(&self) -> String {
+        self.lines.join("\n")
+    }
+
+    pub fn line_count(&self) -> usize {
+        self.lines.len()
+    }
+
+    pub fn line(&self, index: usize) -> &str {
+        &self.lines[index]
+    }
+
+    pub fn line_length(&self, index: usize) -> usize {
+        self.lines[index].chars().count()
+    }
+
+    pub fn end(&self) -> Position {
+        let line = self.lines.len() - 1;
+
+        Position::new(line, self.line_length(line))
+    }
+
+    /// Returns the closest valid [`Position`] to the given one.
+    pub fn clamp(&self, position: Position) -> Position {
+        let line = position.line.min(self.lines.len() - 1);
+
+        Position::new(line, position.column.min(self.line_length(line)))
+    }
+
+    pub fn char_at(&self, position: Position) -> Option<char> {
+        self.lines[position.line][self.offset(position)..]
+            .chars()
+            .next()
+    }
+
+    pub fn char_before(&self, position: Position) -> Option<char> {
+        if position.column == 0 {
+            None
+        } else {
+            self.char_at(Position::new(position.line, position.column - 1))
+        }
+    }
+
+    /// Returns the leading whitespace of the given line.
+    pub fn indentation(&self, line: usize) -> &str {
+        let line = &self.lines[line];
+        let content = line.trim_start_matches(&[' ', '\t'][..]);
+
+        &line[..line.len() - content.len()]
+    }
+
+    pub fn slice(&self, start: Position, end: Position) -> String {
+        let start_offset = self.offset(start);
+        let end_offset = self.offset(end);
+
+        if start.line == end.line {
+            return self.lines[start.line][start_offset..end_offset].to_owned();
+        }
+
+        let mut text = self.lines[start.line][start_offset..].to_owned();
+
+        for line in &self.lines[start.line + 1..end.line] {
+            text.push('\n');
+            text.push_str(line);
+        }
+
+        text.push('\n');
+        text.push_str(&self.lines[end.line][..end_offset]);
+
+        text
+    }
+
+    /// Inserts some text at the given [`Position`], returning the
+    /// [`Position`] at the end of the inserted text.
+    pub fn insert(&mut self, position: Position, text: &str) -> Position {
+        let offset = self.offset(position);
+        let rest = self.lines[position.line].split_off(offset);
+
+        let mut inserted = split(text);
+
+        self.lines[position.line].push_str(inserted.next().unwrap_or(""));
+
+        let new_lines: Vec<String> = inserted.map(String::from).collect();
+        let last = position.line + new_lines.len();
+
+        let _ = self
+            .lines
+            .splice(position.line + 1..position.line + 1, new_lines);
+
+        let column = if last == position.line {
+            position.column + text.chars().count()
+        } else {
+            self.line_length(last)
+        };
+
+        self.lines[last].push_str(&rest);
+
+        Position::new(last, column)
+    }
+
+    /// Deletes the text between the given positions.
+    pub fn delete(&mut self, start: Position, end: Position) {
+        let tail = self.lines[end.line][self.offset(end)..].to_owned();
+        let offset = self.offset(start);
+
+        self.lines[start.line].truncate(offset);
+        self.lines[start.line].push_str(&tail);
+
+        let _ = self.lines.drain(start.line + 1..=end.line);
+    }
+
+    /// Inserts a line break at the given [`Position`], keeping the
+    /// indentation of the current line and indenting further after an
+    /// opening bracket.
+    ///
+    /// It returns the [`Position`] where the cursor should be placed.
+    pub fn newline(&mut self, position: Position) -> Position {
+        let offset = self.offset(position);
+        let line = &self.lines[position.line];
+
+        let indentation: String = line
+            .chars()
+            .take(position.column)
+            .take_while(|c| *c == ' ' || *c == '\t')
+            .collect();
+
+        let before = line[..offset].trim_end().chars().last();
+        let after = line[offset..].trim_start().chars().next();
+
+        match before.and_then(closing_bracket) {
+            Some(closing) if after == Some(closing) => {
+                let _ = self.insert(
+                    position,
+                    &format!("\n{}{}\n{}", indentation, INDENT, indentation),
+                );
+
+                Position::new(
+                    position.line + 1,
+                    indentation.chars().count() + INDENT.len(),
+                )
+            }
+            Some(_) => {
+                self.insert(position, &format!("\n{}{}", indentation, INDENT))
+            }
+            None => self.insert(position, &format!("\n{}", indentation)),
+        }
+    }
+
+    /// Removes a level of indentation from the given line, returning the
+    /// amount of characters removed.
+    pub fn dedent(&mut self, line: usize) -> usize {
+        let removed = if self.lines[line].starts_with('\t') {
+            1
+        } else {
+            self.lines[line]
+                .chars()
+                .take(INDENT.len())
+                .take_while(|c| *c == ' ')
+                .count()
+        };
+
+        let _ = self.lines[line].drain(..removed);
+
+        removed
+    }
+
+    /// Returns the [`Position`] of the character before the given one,
+    /// wrapping to the end of the previous line.
+    pub fn previous(&self, position: Position) -> Position {
+        if position.column > 0 {
+            Position::new(position.line, position.column - 1)
+        } else if position.line > 0 {
+            let line = position.line - 1;
+
+            Position::new(line, self.line_length(line))
+        } else {
+            position
+        }
+    }
+
+    /// Returns the [`Position`] of the character after the given one,
+    /// wrapping to the start of the next line.
+    pub fn next(&self, position: Position) -> Position {
+        if position.column < self.line_length(position.line) {
+            Position::new(position.line, position.column + 1)
+        } else if position.line + 1 < self.lines.len() {
+            Position::new(position.line + 1, 0)
+        } else {
+            position
+        }
+    }
+
+    /// Returns the [`Position`] of the start of the word before the given
+    /// one.
+    pub fn previous_word_start(&self, position: Position) -> Position {
+        if position.column == 0 {
+            return if position.line == 0 {
+                position
+            } else {
+                let line = position.line - 1;
+
+                Position::new(line, self.line_length(line))
+            };
+        }
+
+        let chars: Vec<char> = self.lines[position.line]
+            .chars()
+            .take(position.column)
+            .collect();
+
+        let mut column = chars.len();
+
+        while column > 0 && chars[column - 1].is_whitespace() {
+            column -= 1;
+        }
+
+        if column > 0 && is_word(chars[column - 1]) {
+            while column > 0 && is_word(chars[column - 1]) {
+                column -= 1;
+            }
+        } else {
+            column = column.saturating_sub(1);
+        }
+
+        Position::new(position.line, column)
+    }
+
+    /// Returns the [`Position`] of the end of the word after the given one.
+    pub fn next_word_end(&self, position: Position) -> Position {
+        let chars: Vec<char> = self.lines[position.line].chars().collect();
+
+        if position.column >= chars.len() {
+            return if position.line + 1 < self.lines.len() {
+                Position::new(position.line + 1, 0)
+            } else {
+                position
+            };
+        }
+
+        let mut column = position.column;
+
+        while column < chars.len() && chars[column].is_whitespace() {
+            column += 1;
+        }
+
+        if column < chars.len() && is_word(chars[column]) {
+            while column < chars.len() && is_word(chars[column]) {
+                column += 1;
+            }
+        } else if column < chars.len() {
+            column += 1;
+        }
+
+        Position::new(position.line, column)
+    }
+
+    /// Returns the range of the word at the given [`Position`].
+    pub fn word_at(&self, position: Position) -> (Position, Position) {
+        let chars: Vec<char> = self.lines[position.line].chars().collect();
+
+        let mut start = position.column.min(chars.len());
+        let mut end = start;
+
+        while start > 0 && is_word(chars[start - 1]) {
+            start -= 1;
+        }
+
+        while end < chars.len() && is_word(chars[end]) {
+            end += 1;
+        }
+
+        (
+            Position::new(position.line, start),
+            Position::new(position.line, end),
+        )
+    }
+
+    /// Returns the positions of the bracket at or right before the given
+    /// [`Position`] and of the bracket matching it, if any.
+    pub fn matching_brackets(
+        &self,
+        position: Position,
+    ) -> Option<(Position, Position)> {
+        let at = self.char_at(position).map(|c| (position, c));
+
+        let before = self
+            .char_before(position)
+            .map(|c| (Position::new(position.line, position.column - 1), c));
+
+        at.into_iter().chain(before).find_map(|(bracket, c)| {
+            self.find_match(bracket, c).map(|other| (bracket, other))
+        })
+    }
+
+    fn find_match(
+        &self,
+        position: Position,
+        bracket: char,
+    ) -> Option<Position> {
+        let mut depth = 0;
+
+        if let Some(closing) = closing_bracket(bracket) {
+            for (index, line) in self
+                .lines
+                .iter()
+                .enumerate()
+                .skip(position.line)
+                .take(MAX_BRACKET_DISTANCE)
+            {
+                let skip = if index == position.line {
+                    position.column
+                } else {
+                    0
+                };
+
+                for (column, c) in line.chars().enumerate().skip(skip) {
+                    if c == bracket {
+                        depth += 1;
+                    } else if c == closing {
+                        depth -= 1;
+
+                        if depth == 0 {
+                            return Some(Position::new(index, column));
+                        }
+                    }
+                }
+            }
+        } else if let Some(opening) = opening_bracket(bracket) {
+            for (index, line) in self.lines[..=position.line]
+                .iter()
+                .enumerate()
+                .rev()
+                .take(MAX_BRACKET_DISTANCE)
+            {
+                let chars: Vec<char> = line.chars().collect();
+
+                let end = if index == position.line {
+                    position.column + 1
+                } else {
+                    chars.len()
+                };
+
+                for column in (0..end).rev() {
+                    if chars[column] == bracket {
+                        depth += 1;
+                    } else if chars[column] == opening {
+                        depth -= 1;
+
+                        if depth == 0 {
+                            return Some(Position::new(index, column));
+                        }
+                    }
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the byte offset of the given [`Position`] in its line.
+    fn offset(&self, position: Position) -> usize {
+        let line = &self.lines[position.line];
+
+        line.char_indices()
+            .nth(position.column)
+            .map_or(line.len(), |(offset, _)| offset)
+    }
+}
+
+/// Returns the bracket closing the given one, if it is an opening bracket.
+pub fn closing_bracket(c: char) -> Option<char> {
+    match c {
+        '(' => Some(')'),
+        '[' => Some(']'),
+        '{' => Some('}'),
+        _ => None,
+    }
+}
+
+fn opening_bracket(c: char) -> Option<char> {
+    match c {
+        ')' => Some('('),
+        ']' => Some('['),
+        '}' => Some('{'),
+        _ => None,
+    }
+}
+
+fn is_word(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+fn split(text: &str) -> impl Iterator<Item = &str> {
+    text.split('\n')
+        .map(|line| line.strip_suffix('\r').unwrap_or(line))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn inserts_and_deletes_across_lines() {
+        let mut buffer = Buffer::new("fn main() {}");
+
+        let end = buffer.insert(Position::new(0, 11), "\n    let a = 1;\n");
+
+        assert_eq!(buffer.text(), "fn main() {\n    let a = 1;\n}");
+        assert_eq!(end, Position::new(2, 0));
+
+        assert_eq!(
+            buffer.slice(Position::new(0, 10), Position::new(1, 7)),
+            "{\n    let"
+        );
+
+        buffer.delete(Position::new(0, 11), Position::new(2, 0));
+
+        assert_eq!(buffer.text(), "fn main() {}");
+        assert_eq!(buffer.line_count(), 1);
+    }
+
+    #[test]
+    fn handles_multibyte_characters() {
+        let mut buffer = Buffer::new("héllo");
+
+        let end = buffer.insert(Position::new(0, 2), "ü");
+
+        assert_eq!(buffer.text(), "héüllo");
+        assert_eq!(end, Position::new(0, 3));
+        assert_eq!(buffer.char_at(end), Some('l'));
+    }
+
+    #[test]
+    fn indents_new_lines() {
+        let mut buffer = Buffer::new("    let a = 1;");
+        let cursor = buffer.newline(Position::new(0, 14));
+
+        assert_eq!(buffer.text(), "    let a = 1;\n    ");
+        assert_eq!(cursor, Position::new(1, 4));
+
+        let mut buffer = Buffer::new("fn main() {}");
+        let cursor = buffer.newline(Position::new(0, 11));
+
+        assert_eq!(buffer.text(), "fn main() {\n    \n}");
+        assert_eq!(cursor, Position::new(1, 4));
+
+        let mut buffer = Buffer::new("  [");
+        let cursor = buffer.newline(Position::new(0, 3));
+
+        assert_eq!(buffer.text(), "  [\n      ");
+        assert_eq!(cursor, Position::new(1, 6));
+    }
+
+    #[test]
+    fn dedents_lines() {
+        let mut buffer = Buffer::new("      a\n\tb\n  c\nd");
+
+        assert_eq!(buffer.dedent(0), 4);
+        assert_eq!(buffer.dedent(1), 1);
+        assert_eq!(buffer.dedent(2), 2);
+        assert_eq!(buffer.dedent(3), 0);
+
+        assert_eq!(buffer.text(), "  a\nb\nc\nd");
+    }
+
+    #[test]
+    fn matches_brackets() {
+        let buffer = Buffer::new("f(a[0], {\n    b: (c)\n})");
+
+        assert_eq!(
+            buffer.matching_brackets(Position::new(0, 1)),
+            Some((Position::new(0, 1), Position::new(2, 1)))
+        );
+
+        assert_eq!(
+            buffer.matching_brackets(Position::new(2, 0)),
+            Some((Position::new(2, 0), Position::new(0, 8)))
+        );
+
+        assert_eq!(
+            buffer.matching_brackets(Position::new(1, 10)),
+            Some((Position::new(1, 9), Position::new(1, 7)))
+        );
+
+        assert_eq!(buffer.matching_brackets(Position::new(1, 5)), None);
+    }
+
+    #[test]
+    fn moves_by_words() {
+        let buffer = Buffer::new("let value = foo_bar(1);\nnext");
+
+        assert_eq!(
+            buffer.next_word_end(Position::new(0, 3)),
+            Position::new(0, 9)
+        );
+        assert_eq!(
+            buffer.next_word_end(Position::new(0, 11)),
+            Position::new(0, 19)
+        );
+        assert_eq!(
+            buffer.previous_word_start(Position::new(0, 19)),
+            Position::new(0, 12)
+        );
+        assert_eq!(
+            buffer.previous_word_start(Position::new(1, 0)),
+            Position::new(0, 23)
+        );
+        assert_eq!(
+            buffer.word_at(Position::new(0, 14)),
+            (Position::new(0, 12), Position::new(0, 19))
+        );
+    }
+}
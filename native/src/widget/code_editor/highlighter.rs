@@ -0,0 +1,256 @@
+use crate::widget::code_editor::buffer::Buffer;
+use crate::Color;
+
+use syntect::highlighting::{self, HighlightIterator, HighlightState};
+use syntect::parsing::{ParseState, ScopeStack, SyntaxSet};
+
+use std::fmt;
+use std::ops::Range;
+
+thread_local! {
+    static SYNTAXES: SyntaxSet = SyntaxSet::load_defaults_newlines();
+    static THEMES: highlighting::ThemeSet = highlighting::ThemeSet::load_defaults();
+}
+
+/// A color theme used to highlight the code of a [`CodeEditor`].
+///
+/// [`CodeEditor`]: crate::widget::CodeEditor
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Theme {
+    /// The _InspiredGitHub_ theme.
+    InspiredGitHub,
+
+    /// The dark _Solarized_ theme.
+    SolarizedDark,
+
+    /// The light _Solarized_ theme.
+    SolarizedLight,
+
+    /// The _Base16 Eighties_ theme.
+    Base16EightiesDark,
+
+    /// The _Base16 Mocha_ theme.
+    Base16MochaDark,
+
+    /// The dark _Base16 Ocean_ theme.
+    Base16OceanDark,
+
+    /// The light _Base16 Ocean_ theme.
+    Base16OceanLight,
+}
+
+impl Theme {
+    /// All the available themes.
+    pub const ALL: [Theme; 7] = [
+        Theme::InspiredGitHub,
+        Theme::SolarizedDark,
+        Theme::SolarizedLight,
+        Theme::Base16EightiesDark,
+        Theme::Base16MochaDark,
+        Theme::Base16OceanDark,
+        Theme::Base16OceanLight,
+    ];
+
+    fn key(self) -> &'static str {
+        match self {
+            Theme::InspiredGitHub => "InspiredGitHub",
+            Theme::SolarizedDark => "Solarized (dark)",
+            Theme::SolarizedLight => "Solarized (light)",
+            Theme::Base16EightiesDark => "base16-eighties.dark",
+            Theme::Base16MochaDark => "base16-mocha.dark",
+            Theme::Base16OceanDark => "base16-ocean.dark",
+            Theme::Base16OceanLight => "base16-ocean.light",
+        }
+    }
+}
+
+impl Default for Theme {
+    fn default() -> Theme {
+        Theme::InspiredGitHub
+    }
+}
+
+impl fmt::Display for Theme {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Theme::InspiredGitHub => "InspiredGitHub",
+            Theme::SolarizedDark => "Solarized Dark",
+            Theme::SolarizedLight => "Solarized Light",
+            Theme::Base16EightiesDark => "Base16 Eighties",
+            Theme::Base16MochaDark => "Base16 Mocha",
+            Theme::Base16OceanDark => "Base16 Ocean Dark",
+            Theme::Base16OceanLight => "Base16 Ocean Light",
+        })
+    }
+}
+
+/// A run of characters of a line sharing the same color.
+#[derive(Debug, Clone)]
+pub struct Span {
+    /// The byte range of the run in its line.
+    pub range: Range<usize>,
+    pub color: Color,
+}
+
+/// The colors of the parts of a code editor defined by its [`Theme`].
+#[derive(Debug, Clone, Copy)]
+pub struct Palette {
+    pub text: Color,
+    pub background: Color,
+    pub gutter: Color,
+    pub line_number: Color,
+    pub current_line: Color,
+    pub selection: Color,
+    pub cursor: Color,
+    pub bracket: Color,
+}
+
+/// Highlights the lines of a [`Buffer`] lazily, caching the parser state at
+/// the start of every line so an edit only needs to highlight again the
+/// lines after it.
+#[derive(Debug)]
+pub struct Highlighter {
+    theme: highlighting::Theme,
+    palette: Palette,
+    states: Vec<(ParseState, HighlightState)>,
+    lines: Vec<Vec<Span>>,
+}
+
+impl Highlighter {
+    /// Creates a new [`Highlighter`] for the language with the given name or
+    /// file extension.
+    ///
+    /// Unknown languages are displayed as plain text.
+    pub fn new(language: &str, theme: Theme) -> Self {
+        let syntax = SYNTAXES.with(|syntaxes| {
+            let syntax = syntaxes
+                .find_syntax_by_token(language)
+                .unwrap_or_else(|| syntaxes.find_syntax_plain_text());
+
+            ParseState::new(syntax)
+        });
+
+        let theme = THEMES.with(|themes| themes.themes[theme.key()].clone());
+        let palette = palette(&theme.settings);
+
+        let highlight = HighlightState::new(
+            &highlighting::Highlighter::new(&theme),
+            ScopeStack::new(),
+        );
+
+        Highlighter {
+            theme,
+            palette,
+            states: vec![(syntax, highlight)],
+            lines: Vec::new(),
+        }
+    }
+
+    pub fn palette(&self) -> Palette {
+        self.palette
+    }
+
+    /// Discards the highlighting of the given line and every line after it.
+    pub fn invalidate(&mut self, line: usize) {
+        self.states.truncate(line + 1);
+        self.lines.truncate(line);
+    }
+
+    /// Highlights the lines of the [`Buffer`] up to the given one, if they
+    /// are not highlighted already.
+    pub fn highlight(&mut self, buffer: &Buffer, last: usize) {
+        let last = last.min(buffer.line_count() - 1);
+
+        if self.lines.len() > last {
+            return;
+        }
+
+        let highlighter = highlighting::Highlighter::new(&self.theme);
+        let states = &mut self.states;
+        let lines = &mut self.lines;
+        let mut line = String::new();
+
+        SYNTAXES.with(|syntaxes| {
+            while lines.len() <= last {
+                let index = lines.len();
+                let (mut parse, mut highlight) = states[index].clone();
+
+                line.clear();
+                line.push_str(buffer.line(index));
+                line.push('\n');
+
+                let operations =
+                    parse.parse_line(&line, syntaxes).unwrap_or_default();
+
+                let mut spans: Vec<Span> = Vec::new();
+                let mut start = 0;
+
+                for (style, text) in HighlightIterator::new(
+                    &mut highlight,
+                    &operations,
+                    &line,
+                    &highlighter,
+                ) {
+                    let end = (start + text.len()).min(line.len() - 1);
+                    let color = color(style.foreground);
+
+                    // Adjacent runs with the same color are drawn together
+                    match spans.last_mut() {
+                        Some(span) if span.color == color => {
+                            span.range.end = end;
+                        }
+                        _ if start < end => spans.push(Span {
+                            range: start..end,
+                            color,
+                        }),
+                        _ => {}
+                    }
+
+                    start += text.len();
+                }
+
+                lines.push(spans);
+                states.push((parse, highlight));
+            }
+        });
+    }
+
+    /// Returns the [`Span`] of the given line, which must be highlighted
+    /// already.
+    pub fn spans(&self, line: usize) -> &[Span] {
+        &self.lines[line]
+    }
+}
+
+fn color(color: highlighting::Color) -> Color {
+    Color::from_rgba8(color.r, color.g, color.b, f32::from(color.a) / 255.0)
+}
+
+fn palette(settings: &highlighting::ThemeSettings) -> Palette {
+    let text = settings.foreground.map(color).unwrap_or(Color::BLACK);
+    let background = settings.background.map(color).unwrap_or(Color::WHITE);
+    let gutter = settings.gutter.map(color).unwrap_or(background);
+
+    Palette {
+        text,
+        background,
+        gutter,
+        line_number: settings
+            .gutter_foreground
+            .map(color)
+            .unwrap_or(Color { a: 0.5, ..text }),
+        current_line: settings
+            .line_highlight
+            .map(color)
+            .unwrap_or(Color { a: 0.05, ..text }),
+        selection: settings
+            .selection
+            .map(color)
+            .unwrap_or(Color::from_rgba(0.3, 0.5, 0.9, 0.3)),
+        cursor: settings.caret.map(color).unwrap_or(text),
+        bracket: settings
+            .brackets_foreground
+            .map(color)
+            .unwrap_or(Color { a: 0.6, ..text }),
+    }
+}
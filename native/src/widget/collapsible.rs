@@ -0,0 +1,895 @@
+//! Show sections of content that can be expanded and collapsed.
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::window;
+use crate::{
+    Clipboard, Color, Element, Hasher, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+use std::hash::Hash;
+use std::time::{Duration, Instant};
+
+pub use iced_style::collapsible::{Style, StyleSheet};
+
+/// A section with a clickable header that shows or hides its content.
+///
+/// The content of a closed [`Collapsible`] is not laid out. Its height is
+/// animated when it is expanded or collapsed.
+///
+/// Several sections can be grouped in an [`Accordion`] to keep only one of
+/// them open at a time.
+///
+/// # Example
+/// ```
+/// # type Collapsible<'a, Message> =
+/// #     iced_native::widget::Collapsible<'a, Message, iced_native::renderer::Null>;
+/// # type Text = iced_native::widget::Text<iced_native::renderer::Null>;
+/// #
+/// use iced_native::widget::collapsible;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     AdvancedToggled(bool),
+/// }
+///
+/// let mut state = collapsible::State::new();
+///
+/// let collapsible = Collapsible::new(
+///     &mut state,
+///     "Advanced",
+///     Text::new("Some advanced settings"),
+/// )
+/// .on_toggle(Message::AdvancedToggled);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Collapsible<'a, Message, Renderer: text::Renderer> {
+    state: &'a mut State,
+    title: String,
+    content: Element<'a, Message, Renderer>,
+    on_toggle: Option<Box<dyn Fn(bool) -> Message + 'a>>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    duration: Duration,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> Collapsible<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default padding of a [`Collapsible`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(8);
+
+    /// The default duration of the animation of a [`Collapsible`].
+    pub const DEFAULT_DURATION: Duration = Duration::from_millis(200);
+
+    /// Creates a new [`Collapsible`] with the given [`State`], title, and
+    /// content.
+    pub fn new<E>(
+        state: &'a mut State,
+        title: impl Into<String>,
+        content: E,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        Collapsible {
+            state,
+            title: title.into(),
+            content: content.into(),
+            on_toggle: None,
+            width: Length::Fill,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            duration: Self::DEFAULT_DURATION,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the message that should be produced when the [`Collapsible`] is
+    /// expanded or collapsed by the user.
+    ///
+    /// The function receives whether the [`Collapsible`] is now open.
+    pub fn on_toggle<F>(mut self, f: F) -> Self
+    where
+        F: 'a + Fn(bool) -> Message,
+    {
+        self.on_toggle = Some(Box::new(f));
+        self
+    }
+
+    /// Sets the width of the [`Collapsible`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the header and the content of the
+    /// [`Collapsible`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the title of the [`Collapsible`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the title of the [`Collapsible`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.font = font.into();
+        self
+    }
+
+    /// Sets the duration of the animation of the [`Collapsible`].
+    ///
+    /// A zero duration disables the animation.
+    pub fn duration(mut self, duration: Duration) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Sets the style of the [`Collapsible`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Returns the fraction of the content that is currently visible, from
+    /// 0 to 1.
+    fn openness(&self) -> f32 {
+        if self.duration == Duration::ZERO {
+            if self.state.is_open {
+                1.0
+            } else {
+                0.0
+            }
+        } else {
+            ease(self.state.progress)
+        }
+    }
+}
+
+/// The local state of a [`Collapsible`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_open: bool,
+    progress: f32,
+    last_frame: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new closed [`State`].
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Creates a new open [`State`].
+    pub fn opened() -> Self {
+        State {
+            is_open: true,
+            progress: 1.0,
+            last_frame: None,
+        }
+    }
+
+    /// Returns whether the [`Collapsible`] is open.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Expands the [`Collapsible`].
+    pub fn open(&mut self) {
+        self.is_open = true;
+    }
+
+    /// Collapses the [`Collapsible`].
+    pub fn close(&mut self) {
+        self.is_open = false;
+    }
+
+    /// Expands the [`Collapsible`] if it is closed, or collapses it
+    /// otherwise.
+    pub fn toggle(&mut self) {
+        self.is_open = !self.is_open;
+    }
+
+    fn target(&self) -> f32 {
+        if self.is_open {
+            1.0
+        } else {
+            0.0
+        }
+    }
+
+    fn is_animating(&self) -> bool {
+        (self.progress - self.target()).abs() > f32::EPSILON
+    }
+
+    /// Advances the animation to the given frame and returns whether it is
+    /// still running.
+    fn advance(&mut self, now: Instant, duration: Duration) -> bool {
+        let step = match self.last_frame {
+            _ if duration == Duration::ZERO => 1.0,
+            Some(last_frame) => {
+                now.saturating_duration_since(last_frame).as_secs_f32()
+                    / duration.as_secs_f32()
+            }
+            None => 0.0,
+        };
+
+        let target = self.target();
+
+        self.progress = if self.progress < target {
+            (self.progress + step).min(target)
+        } else {
+            (self.progress - step).max(target)
+        };
+
+        if self.is_animating() {
+            self.last_frame = Some(now);
+
+            true
+        } else {
+            self.last_frame = None;
+
+            false
+        }
+    }
+}
+
+/// Eases the progress of an animation in and out.
+fn ease(t: f32) -> f32 {
+    t * t * (3.0 - 2.0 * t)
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Collapsible<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        let header_width = f32::from(self.padding.horizontal())
+            + f32::from(text_size) * 1.5
+            + renderer.measure_width(&self.title, text_size, self.font.clone());
+
+        let header_height =
+            f32::from(text_size) + f32::from(self.padding.vertical());
+
+        let openness = self.openness();
+
+        let content = if openness > 0.0 {
+            let mut content =
+                self.content.layout(renderer, &limits.pad(self.padding));
+
+            content.move_to(Point::new(
+                f32::from(self.padding.left),
+                header_height + f32::from(self.padding.top),
+            ));
+
+            Some(content)
+        } else {
+            None
+        };
+
+        let (content_width, body_height) = content
+            .as_ref()
+            .map(|content| {
+                (
+                    content.size().width + f32::from(self.padding.horizontal()),
+                    content.size().height + f32::from(self.padding.vertical()),
+                )
+            })
+            .unwrap_or((0.0, 0.0));
+
+        let size = limits.resolve(Size::new(
+            header_width.max(content_width),
+            header_height + body_height * openness,
+        ));
+
+        let header = layout::Node::new(Size::new(size.width, header_height));
+
+        layout::Node::with_children(
+            size,
+            std::iter::once(header).chain(content).collect(),
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+
+        match event {
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                if self.state.is_animating() {
+                    if self.state.advance(now, self.duration) {
+                        shell.request_redraw(window::RedrawRequest::NextFrame);
+                    }
+
+                    shell.invalidate_layout();
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if header.bounds().contains(cursor_position) =>
+            {
+                self.state.toggle();
+
+                if let Some(on_toggle) = &self.on_toggle {
+                    shell.publish(on_toggle(self.state.is_open));
+                }
+
+                shell.invalidate_layout();
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+            _ => {
+                // The state may have been changed by the application
+                if self.state.is_animating() {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+        }
+
+        match children.next() {
+            Some(content) if self.state.is_open => self.content.on_event(
+                event,
+                content,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ),
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let header = children.next().unwrap();
+
+        if header.bounds().contains(cursor_position) {
+            return mouse::Interaction::Pointer;
+        }
+
+        match children.next() {
+            Some(content) if bounds.contains(cursor_position) => {
+                self.content.mouse_interaction(
+                    content,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            }
+            _ => mouse::Interaction::default(),
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let header = children.next().unwrap().bounds();
+
+        let is_open = self.state.is_open;
+
+        let appearance = if header.contains(cursor_position) {
+            self.style_sheet.hovered(is_open)
+        } else {
+            self.style_sheet.active(is_open)
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: appearance.border_radius,
+                border_width: appearance.border_width,
+                border_color: appearance.border_color,
+            },
+            appearance
+                .body_background
+                .unwrap_or_else(|| Color::TRANSPARENT.into()),
+        );
+
+        if let Some(background) = appearance.header_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: header,
+                    border_radius: appearance.border_radius,
+                    border_width: appearance.border_width,
+                    border_color: appearance.border_color,
+                },
+                background,
+            );
+        }
+
+        let text_size =
+            f32::from(self.text_size.unwrap_or(renderer.default_size()));
+
+        renderer.fill_text(Text {
+            content: &if is_open {
+                Renderer::ARROW_DOWN_ICON
+            } else {
+                Renderer::ARROW_RIGHT_ICON
+            }
+            .to_string(),
+            font: Renderer::ICON_FONT,
+            size: text_size,
+            bounds: Rectangle {
+                x: header.x + f32::from(self.padding.left),
+                y: header.center_y(),
+                ..header
+            },
+            color: appearance.icon_color,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            format: text::Format::default(),
+            decorations: &[],
+        });
+
+        renderer.fill_text(Text {
+            content: &self.title,
+            font: self.font.clone(),
+            size: text_size,
+            bounds: Rectangle {
+                x: header.x + f32::from(self.padding.left) + text_size * 1.5,
+                y: header.center_y(),
+                ..header
+            },
+            color: appearance.header_text_color,
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            format: text::Format::default(),
+            decorations: &[],
+        });
+
+        if let Some(content) = children.next() {
+            let body = Rectangle {
+                y: header.y + header.height,
+                height: bounds.height - header.height,
+                ..bounds
+            };
+
+            renderer.with_layer(body, |renderer| {
+                self.content.draw(
+                    renderer,
+                    style,
+                    content,
+                    cursor_position,
+                    viewport,
+                );
+            });
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.font.hash(state);
+        self.state.is_open.hash(state);
+        self.state.progress.to_bits().hash(state);
+
+        if self.state.is_open || self.state.is_animating() {
+            self.content.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        let content = layout.children().nth(1)?;
+
+        if self.state.is_open {
+            self.content.overlay(content, renderer)
+        } else {
+            None
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Collapsible<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        collapsible: Collapsible<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(collapsible)
+    }
+}
+
+/// A group of [`Collapsible`] sections where only one section can be open at
+/// a time.
+///
+/// Opening a section collapses the one that was open before.
+///
+/// # Example
+/// ```
+/// # type Collapsible<'a, Message> =
+/// #     iced_native::widget::Collapsible<'a, Message, iced_native::renderer::Null>;
+/// # type Accordion<'a, Message> =
+/// #     iced_native::widget::Accordion<'a, Message, iced_native::renderer::Null>;
+/// # type Text = iced_native::widget::Text<iced_native::renderer::Null>;
+/// #
+/// use iced_native::widget::collapsible;
+///
+/// # #[derive(Debug, Clone)]
+/// # enum Message {}
+/// #
+/// let mut general = collapsible::State::opened();
+/// let mut network = collapsible::State::new();
+///
+/// let accordion: Accordion<'_, Message> = Accordion::new()
+///     .push(Collapsible::new(&mut general, "General", Text::new("...")))
+///     .push(Collapsible::new(&mut network, "Network", Text::new("...")));
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct Accordion<'a, Message, Renderer: text::Renderer> {
+    sections: Vec<Collapsible<'a, Message, Renderer>>,
+    width: Length,
+    spacing: u16,
+}
+
+impl<'a, Message, Renderer> Accordion<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Creates an empty [`Accordion`].
+    pub fn new() -> Self {
+        Accordion {
+            sections: Vec::new(),
+            width: Length::Fill,
+            spacing: 0,
+        }
+    }
+
+    /// Sets the width of the [`Accordion`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the sections of the
+    /// [`Accordion`].
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Adds a [`Collapsible`] section to the [`Accordion`].
+    pub fn push(mut self, section: Collapsible<'a, Message, Renderer>) -> Self {
+        self.sections.push(section);
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Default for Accordion<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Accordion<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let spacing = f32::from(self.spacing);
+
+        let mut height = 0.0;
+        let mut width: f32 = 0.0;
+
+        let children = self
+            .sections
+            .iter()
+            .enumerate()
+            .map(|(i, section)| {
+                if i > 0 {
+                    height += spacing;
+                }
+
+                let mut node = section.layout(renderer, &limits);
+                node.move_to(Point::new(0.0, height));
+
+                height += node.size().height;
+                width = width.max(node.size().width);
+
+                node
+            })
+            .collect();
+
+        layout::Node::with_children(
+            limits.resolve(Size::new(width, height)),
+            children,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut status = event::Status::Ignored;
+        let mut opened = None;
+
+        for (i, (section, layout)) in
+            self.sections.iter_mut().zip(layout.children()).enumerate()
+        {
+            let was_open = section.state.is_open;
+
+            status = status.merge(section.on_event(
+                event.clone(),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ));
+
+            if !was_open && section.state.is_open {
+                opened = Some(i);
+            }
+        }
+
+        if let Some(opened) = opened {
+            for (i, section) in self.sections.iter_mut().enumerate() {
+                if i != opened && section.state.is_open {
+                    section.state.close();
+
+                    if let Some(on_toggle) = &section.on_toggle {
+                        shell.publish(on_toggle(false));
+                    }
+                }
+            }
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.sections
+            .iter()
+            .zip(layout.children())
+            .map(|(section, layout)| {
+                section.mouse_interaction(
+                    layout,
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        for (section, layout) in self.sections.iter().zip(layout.children()) {
+            section.draw(renderer, style, layout, cursor_position, viewport);
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.spacing.hash(state);
+
+        for section in &self.sections {
+            section.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.sections
+            .iter_mut()
+            .zip(layout.children())
+            .filter_map(|(section, layout)| section.overlay(layout, renderer))
+            .next()
+    }
+}
+
+impl<'a, Message, Renderer> From<Accordion<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        accordion: Accordion<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(accordion)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard;
+    use crate::renderer::Null;
+    use crate::widget::Space;
+
+    #[test]
+    fn animates_opening_and_closing() {
+        let duration = Duration::from_millis(200);
+        let start = Instant::now();
+
+        let mut state = State::new();
+        state.open();
+
+        assert!(state.is_animating());
+        assert!(state.advance(start, duration));
+        assert_eq!(state.progress, 0.0);
+
+        assert!(state.advance(start + Duration::from_millis(100), duration));
+        assert_eq!(state.progress, 0.5);
+        assert_eq!(ease(state.progress), 0.5);
+
+        assert!(!state.advance(start + Duration::from_millis(300), duration));
+        assert_eq!(state.progress, 1.0);
+
+        state.close();
+
+        assert!(state.advance(start, duration));
+        assert!(state.advance(start + Duration::from_millis(50), duration));
+        assert_eq!(state.progress, 0.75);
+        assert!(ease(state.progress) > 0.75);
+    }
+
+    #[test]
+    fn does_not_animate_without_duration() {
+        let mut state = State::new();
+        state.open();
+
+        assert!(!state.advance(Instant::now(), Duration::ZERO));
+        assert_eq!(state.progress, 1.0);
+    }
+
+    #[test]
+    fn keeps_one_section_of_an_accordion_open() {
+        let mut first = State::opened();
+        let mut second = State::new();
+        let mut messages = Vec::new();
+
+        {
+            let section = |state, i| {
+                Collapsible::new(
+                    state,
+                    "Section",
+                    Space::new(Length::Fill, Length::Units(50)),
+                )
+                .on_toggle(move |is_open| (i, is_open))
+            };
+
+            let mut accordion = Accordion::<(usize, bool), Null>::new()
+                .push(section(&mut first, 0))
+                .push(section(&mut second, 1));
+
+            let renderer = Null::new();
+            let node = Widget::layout(
+                &accordion,
+                &renderer,
+                &layout::Limits::new(Size::ZERO, Size::new(200.0, 1000.0)),
+            );
+
+            let header = Layout::new(&node)
+                .children()
+                .nth(1)
+                .and_then(|section| section.children().next())
+                .unwrap()
+                .bounds();
+
+            let _ = accordion.on_event(
+                Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+                Layout::new(&node),
+                header.center(),
+                &renderer,
+                &mut clipboard::Null,
+                &mut Shell::new(&mut messages),
+            );
+        }
+
+        assert!(!first.is_open());
+        assert!(second.is_open());
+        assert_eq!(messages, vec![(1, true), (0, false)]);
+    }
+}
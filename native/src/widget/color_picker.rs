@@ -0,0 +1,935 @@
+//! Let your users pick a color.
+//!
+//! A [`ColorPicker`] has some local [`State`].
+mod hsva;
+mod notation;
+
+pub use iced_style::color_picker::{Style, StyleSheet};
+pub use notation::Notation;
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::text_input::{self, TextInput};
+use crate::{
+    Background, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
+    Point, Rectangle, Shell, Size, Widget,
+};
+
+use hsva::Hsva;
+use std::hash::Hash;
+
+/// A color picker with a saturation and value area, hue and alpha strips, a
+/// text entry, and a history of picked colors.
+///
+/// The text entry accepts colors written in any [`Notation`]. The notation
+/// used to display the current color can be changed with the button next to
+/// it.
+///
+/// A color is added to the history of a [`ColorPicker`] whenever the user
+/// finishes picking it, by releasing the mouse button or by submitting the
+/// text entry. Clicking a swatch of the history picks its color again.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::color_picker;
+/// # use iced_native::Color;
+/// #
+/// # type ColorPicker<'a, Message> =
+/// #     iced_native::widget::ColorPicker<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     ColorPicked(Color),
+/// }
+///
+/// let mut state = color_picker::State::new();
+/// let color = Color::from_rgb(0.1, 0.5, 0.9);
+///
+/// let color_picker = ColorPicker::new(&mut state, color, Message::ColorPicked);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ColorPicker<'a, Message, Renderer: text::Renderer> {
+    text_input: TextInput<'a, Edit, Renderer>,
+    text: &'a mut Option<String>,
+    notation: &'a mut Notation,
+    hsva: &'a mut Option<Hsva>,
+    drag: &'a mut Option<Component>,
+    history: &'a mut Vec<Color>,
+    color: Color,
+    on_change: Box<dyn Fn(Color) -> Message>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+/// A change in the text entry of a [`ColorPicker`].
+#[derive(Debug, Clone)]
+enum Edit {
+    Changed(String),
+    Submitted,
+}
+
+/// A part of a [`ColorPicker`] that can be dragged.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Component {
+    Area,
+    Hue,
+    Alpha,
+}
+
+impl<'a, Message, Renderer> ColorPicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The height of the hue and alpha strips of a [`ColorPicker`].
+    const STRIP_HEIGHT: f32 = 16.0;
+
+    /// The size of a swatch of the history of a [`ColorPicker`].
+    const SWATCH_SIZE: f32 = 20.0;
+
+    /// The space between the parts of a [`ColorPicker`].
+    const SPACING: f32 = 8.0;
+
+    /// The maximum amount of colors kept in the history of a
+    /// [`ColorPicker`].
+    pub const HISTORY_SIZE: usize = 10;
+
+    /// Creates a new [`ColorPicker`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`ColorPicker`]
+    ///   * the current [`Color`] of the [`ColorPicker`]
+    ///   * a function that will be called when the color changes. It
+    ///     receives the new [`Color`] and must produce a `Message`.
+    pub fn new(
+        state: &'a mut State,
+        color: Color,
+        on_change: impl Fn(Color) -> Message + 'static,
+    ) -> Self {
+        let State {
+            text_input,
+            text,
+            notation,
+            hsva,
+            drag,
+            history,
+        } = state;
+
+        // The hue and saturation are kept while they still describe the
+        // color, since they are lost for grays and blacks
+        if !matches!(hsva, Some(hsva) if hsva::is_same(hsva.to_color(), color))
+        {
+            *hsva = Some(Hsva::from_color(color));
+        }
+
+        let content = text.clone().unwrap_or_else(|| notation.format(color));
+
+        let text_input =
+            TextInput::new(text_input, "", &content, Edit::Changed)
+                .on_submit(Edit::Submitted);
+
+        ColorPicker {
+            text_input,
+            text,
+            notation,
+            hsva,
+            drag,
+            history,
+            color,
+            on_change: Box::new(on_change),
+            width: Length::Units(240),
+            padding: Padding::ZERO,
+            text_size: None,
+            font: Default::default(),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`ColorPicker`].
+    ///
+    /// By default, it is 240 units wide.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`ColorPicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`ColorPicker`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the font of the [`ColorPicker`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font.clone();
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the style of the text entry of the [`ColorPicker`].
+    pub fn input_style(
+        mut self,
+        style_sheet: impl Into<Box<dyn text_input::StyleSheet + 'a>>,
+    ) -> Self {
+        self.text_input = self.text_input.style(style_sheet);
+        self
+    }
+
+    /// Sets the style of the [`ColorPicker`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn hsva(&self) -> Hsva {
+        self.hsva.unwrap_or_else(|| Hsva::from_color(self.color))
+    }
+
+    fn change(&mut self, hsva: Hsva, shell: &mut Shell<'_, Message>) {
+        *self.hsva = Some(hsva);
+
+        let color = hsva.to_color();
+
+        if !hsva::is_same(color, self.color) {
+            self.color = color;
+
+            shell.publish((self.on_change)(color));
+        }
+    }
+
+    /// Picks a color from the given [`Component`] at the cursor position.
+    fn pick(
+        &mut self,
+        component: Component,
+        bounds: Rectangle,
+        cursor_position: Point,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let x = ((cursor_position.x - bounds.x) / bounds.width).clamp(0.0, 1.0);
+        let y =
+            ((cursor_position.y - bounds.y) / bounds.height).clamp(0.0, 1.0);
+
+        let hsva = self.hsva();
+
+        self.change(
+            match component {
+                Component::Area => Hsva {
+                    saturation: x,
+                    value: 1.0 - y,
+                    ..hsva
+                },
+                Component::Hue => Hsva {
+                    hue: x * 360.0,
+                    ..hsva
+                },
+                Component::Alpha => Hsva { alpha: x, ..hsva },
+            },
+            shell,
+        );
+    }
+
+    /// Adds the current color to the front of the history.
+    fn remember(&mut self) {
+        let color = self.color;
+
+        self.history.retain(|swatch| !hsva::is_same(*swatch, color));
+        self.history.insert(0, color);
+        self.history.truncate(Self::HISTORY_SIZE);
+    }
+
+    /// Parses the typed text, if any, and adds its color to the history.
+    ///
+    /// Invalid text is discarded.
+    fn commit(&mut self, shell: &mut Shell<'_, Message>) {
+        if let Some(text) = self.text.take() {
+            if let Some(color) = notation::parse(&text) {
+                self.change(Hsva::from_color(color), shell);
+                self.remember();
+            }
+
+            shell.invalidate_widgets();
+        }
+    }
+}
+
+/// The local state of a [`ColorPicker`].
+#[derive(Debug, Clone)]
+pub struct State {
+    text_input: text_input::State,
+    text: Option<String>,
+    notation: Notation,
+    hsva: Option<Hsva>,
+    drag: Option<Component>,
+    history: Vec<Color>,
+}
+
+impl Default for State {
+    fn default() -> Self {
+        Self {
+            text_input: text_input::State::default(),
+            text: None,
+            notation: Notation::Hex,
+            hsva: None,
+            drag: None,
+            history: Vec::new(),
+        }
+    }
+}
+
+impl State {
+    /// Creates a new [`State`] with an empty history.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the [`Notation`] of the text entry of the [`ColorPicker`].
+    pub fn notation(&self) -> Notation {
+        self.notation
+    }
+
+    /// Sets the [`Notation`] of the text entry of the [`ColorPicker`],
+    /// discarding any typed text.
+    pub fn set_notation(&mut self, notation: Notation) {
+        self.notation = notation;
+        self.text = None;
+    }
+
+    /// Returns the history of the [`ColorPicker`], from the most recent
+    /// color to the oldest one.
+    pub fn history(&self) -> &[Color] {
+        &self.history
+    }
+
+    /// Clears the history of the [`ColorPicker`].
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ColorPicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.width)
+            .height(Length::Shrink)
+            .pad(self.padding);
+
+        let direction = limits.direction();
+        let width = limits.resolve(Size::ZERO).width;
+
+        let left = f32::from(self.padding.left);
+        let mut y = f32::from(self.padding.top);
+
+        let mut part = |size: Size, spacing: f32| {
+            let mut node = layout::Node::new(size);
+            node.move_to(Point::new(left, y));
+
+            y += size.height + spacing;
+
+            node
+        };
+
+        let area = part(Size::new(width, width * 0.75), Self::SPACING);
+        let hue = part(Size::new(width, Self::STRIP_HEIGHT), Self::SPACING);
+        let alpha = part(Size::new(width, Self::STRIP_HEIGHT), Self::SPACING);
+
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        let label_width = [Notation::Hex, Notation::Rgb, Notation::Hsl]
+            .iter()
+            .map(|notation| {
+                renderer.measure_width(
+                    notation.label(),
+                    text_size,
+                    self.font.clone(),
+                )
+            })
+            .fold(0.0, f32::max);
+
+        let mut field = self.text_input.layout(
+            renderer,
+            &layout::Limits::new(
+                Size::ZERO,
+                Size::new(width - label_width - Self::SPACING * 3.0, f32::MAX),
+            ),
+        );
+
+        let entry_height = field.size().height;
+        let button_width = label_width + Self::SPACING * 2.0;
+        let field_width = width - button_width - Self::SPACING;
+
+        let mut button =
+            layout::Node::new(Size::new(button_width, entry_height));
+
+        if direction.is_right_to_left() {
+            field.move_to(Point::new(left, y));
+            button.move_to(Point::new(left + field_width + Self::SPACING, y));
+        } else {
+            button.move_to(Point::new(left, y));
+            field.move_to(Point::new(left + button_width + Self::SPACING, y));
+        }
+
+        y += entry_height;
+
+        let columns = ((width + Self::SPACING / 2.0)
+            / (Self::SWATCH_SIZE + Self::SPACING / 2.0))
+            .floor() as usize;
+
+        let swatches: Vec<_> = (0..self.history.len().min(columns))
+            .map(|index| {
+                let offset =
+                    index as f32 * (Self::SWATCH_SIZE + Self::SPACING / 2.0);
+
+                let mut swatch = layout::Node::new(Size::new(
+                    Self::SWATCH_SIZE,
+                    Self::SWATCH_SIZE,
+                ));
+
+                swatch.move_to(Point::new(
+                    if direction.is_right_to_left() {
+                        width - offset - Self::SWATCH_SIZE
+                    } else {
+                        offset
+                    },
+                    0.0,
+                ));
+
+                swatch
+            })
+            .collect();
+
+        let mut history = layout::Node::with_children(
+            Size::new(
+                width,
+                if swatches.is_empty() {
+                    0.0
+                } else {
+                    Self::SWATCH_SIZE
+                },
+            ),
+            swatches,
+        );
+
+        if history.size().height > 0.0 {
+            y += Self::SPACING;
+        }
+
+        history.move_to(Point::new(left, y));
+        y += history.size().height;
+
+        layout::Node::with_children(
+            Size::new(
+                width + f32::from(self.padding.horizontal()),
+                y + f32::from(self.padding.bottom),
+            ),
+            vec![area, hue, alpha, button, field, history],
+        )
+        .with_direction(direction)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let area = children.next().unwrap();
+        let hue = children.next().unwrap();
+        let alpha = children.next().unwrap();
+        let button = children.next().unwrap();
+        let field = children.next().unwrap();
+        let history = children.next().unwrap();
+
+        // The text entry handles the event first, so it loses focus and
+        // commits any typed text when the rest of the picker is clicked
+        let is_focused = self.text_input.state().is_focused();
+
+        let mut edits = Vec::new();
+        let mut text_shell = Shell::new(&mut edits);
+
+        let status = self.text_input.on_event(
+            event.clone(),
+            field,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut text_shell,
+        );
+
+        // The edits of the text entry are handled here, while the rest of its
+        // requests are forwarded
+        shell.merge_with(text_shell, |edit, shell| match edit {
+            Edit::Changed(text) => {
+                // Valid colors are picked while typing
+                if let Some(color) = notation::parse(&text) {
+                    self.change(Hsva::from_color(color), shell);
+                }
+
+                *self.text = Some(text);
+            }
+            Edit::Submitted => {
+                self.commit(shell);
+            }
+        });
+
+        if is_focused && !self.text_input.state().is_focused() {
+            self.commit(shell);
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let component = [
+                    (Component::Area, area),
+                    (Component::Hue, hue),
+                    (Component::Alpha, alpha),
+                ]
+                .iter()
+                .find(|(_, layout)| layout.bounds().contains(cursor_position))
+                .copied();
+
+                if let Some((component, layout)) = component {
+                    *self.drag = Some(component);
+
+                    self.pick(
+                        component,
+                        layout.bounds(),
+                        cursor_position,
+                        shell,
+                    );
+
+                    return event::Status::Captured;
+                }
+
+                if button.bounds().contains(cursor_position) {
+                    *self.notation = self.notation.next();
+                    *self.text = None;
+
+                    shell.invalidate_widgets();
+
+                    return event::Status::Captured;
+                }
+
+                let swatch = history.children().position(|swatch| {
+                    swatch.bounds().contains(cursor_position)
+                });
+
+                if let Some(index) = swatch {
+                    let color = self.history[index];
+
+                    self.change(Hsva::from_color(color), shell);
+                    self.remember();
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(component) = *self.drag {
+                    let bounds = match component {
+                        Component::Area => area.bounds(),
+                        Component::Hue => hue.bounds(),
+                        Component::Alpha => alpha.bounds(),
+                    };
+
+                    self.pick(component, bounds, cursor_position, shell);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if self.drag.is_some() =>
+            {
+                *self.drag = None;
+
+                self.remember();
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let area = children.next().unwrap();
+        let hue = children.next().unwrap();
+        let alpha = children.next().unwrap();
+        let button = children.next().unwrap();
+        let field = children.next().unwrap();
+        let history = children.next().unwrap();
+
+        if self.drag.is_some() {
+            mouse::Interaction::Grabbing
+        } else if [area, hue, alpha]
+            .iter()
+            .any(|part| part.bounds().contains(cursor_position))
+        {
+            mouse::Interaction::Crosshair
+        } else if field.bounds().contains(cursor_position) {
+            self.text_input.mouse_interaction(
+                field,
+                cursor_position,
+                viewport,
+                renderer,
+            )
+        } else if button.bounds().contains(cursor_position)
+            || history
+                .children()
+                .any(|swatch| swatch.bounds().contains(cursor_position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let area = children.next().unwrap();
+        let hue = children.next().unwrap();
+        let alpha = children.next().unwrap();
+        let button = children.next().unwrap();
+        let field = children.next().unwrap();
+        let history = children.next().unwrap();
+
+        let style = self.style_sheet.active();
+        let hsva = self.hsva();
+
+        if style.background.is_some() || style.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: layout.bounds(),
+                    border_radius: style.border_radius,
+                    border_width: style.border_width,
+                    border_color: style.border_color,
+                },
+                style
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        // The renderer does not support gradients yet, so they are
+        // approximated with cells of solid colors. The saturation and value
+        // area is made of columns of saturated colors, darkened by rows of
+        // translucent black, which needs a quad per column and per row
+        // instead of one per cell.
+        let area_bounds = area.bounds();
+        let columns = (area_bounds.width / CELL_SIZE).ceil() as usize;
+        let rows = (area_bounds.height / CELL_SIZE).ceil() as usize;
+
+        for column in 0..columns {
+            let color = Hsva {
+                saturation: (column as f32 + 0.5) / columns as f32,
+                value: 1.0,
+                alpha: 1.0,
+                ..hsva
+            }
+            .to_color();
+
+            fill(renderer, cell(area_bounds, column, columns, 0, 1), color);
+        }
+
+        for row in 0..rows {
+            let value = 1.0 - (row as f32 + 0.5) / rows as f32;
+
+            // Colors are blended in linear space, so the opacity of the black
+            // needs to darken linear colors by the value
+            let [linear_value, ..] =
+                Color::from_rgb(value, value, value).into_linear();
+
+            fill(
+                renderer,
+                cell(area_bounds, 0, 1, row, rows),
+                Color {
+                    a: 1.0 - linear_value,
+                    ..Color::BLACK
+                },
+            );
+        }
+
+        let hue_bounds = hue.bounds();
+        let hue_cells = (hue_bounds.width / CELL_SIZE).ceil() as usize;
+
+        for column in 0..hue_cells {
+            let color = Hsva {
+                hue: (column as f32 + 0.5) / hue_cells as f32 * 360.0,
+                saturation: 1.0,
+                value: 1.0,
+                alpha: 1.0,
+            }
+            .to_color();
+
+            fill(renderer, cell(hue_bounds, column, hue_cells, 0, 1), color);
+        }
+
+        let alpha_bounds = alpha.bounds();
+        checkerboard(renderer, alpha_bounds);
+
+        let alpha_cells = (alpha_bounds.width / CELL_SIZE).ceil() as usize;
+        let opaque = Hsva { alpha: 1.0, ..hsva }.to_color();
+
+        for column in 0..alpha_cells {
+            let color = Color {
+                a: (column as f32 + 0.5) / alpha_cells as f32,
+                ..opaque
+            };
+
+            fill(
+                renderer,
+                cell(alpha_bounds, column, alpha_cells, 0, 1),
+                color,
+            );
+        }
+
+        let handle = |renderer: &mut Renderer, center: Point, color: Color| {
+            let size = Self::STRIP_HEIGHT;
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: center.x - size / 2.0,
+                        y: center.y - size / 2.0,
+                        width: size,
+                        height: size,
+                    },
+                    border_radius: size / 2.0,
+                    border_width: 2.0,
+                    border_color: style.handle_color,
+                },
+                Background::Color(color),
+            );
+        };
+
+        handle(
+            renderer,
+            Point::new(
+                area_bounds.x + hsva.saturation * area_bounds.width,
+                area_bounds.y + (1.0 - hsva.value) * area_bounds.height,
+            ),
+            opaque,
+        );
+
+        handle(
+            renderer,
+            Point::new(
+                hue_bounds.x + hsva.hue / 360.0 * hue_bounds.width,
+                hue_bounds.center_y(),
+            ),
+            Hsva {
+                saturation: 1.0,
+                value: 1.0,
+                alpha: 1.0,
+                ..hsva
+            }
+            .to_color(),
+        );
+
+        handle(
+            renderer,
+            Point::new(
+                alpha_bounds.x + hsva.alpha * alpha_bounds.width,
+                alpha_bounds.center_y(),
+            ),
+            self.color,
+        );
+
+        let button_bounds = button.bounds();
+
+        let button_style = if button_bounds.contains(cursor_position) {
+            self.style_sheet.hovered()
+        } else {
+            style
+        };
+
+        if let Some(background) = button_style.button_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: button_bounds,
+                    border_radius: button_style.button_border_radius,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        renderer.fill_text(Text {
+            content: self.notation.label(),
+            bounds: Rectangle {
+                x: button_bounds.center_x(),
+                y: button_bounds.center_y(),
+                ..button_bounds
+            },
+            size: f32::from(self.text_size.unwrap_or(renderer.default_size())),
+            color: button_style.button_text_color,
+            font: self.font.clone(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            format: text::Format::default(),
+            decorations: &[],
+        });
+
+        self.text_input.draw(renderer, field, cursor_position, None);
+
+        for (swatch, color) in history.children().zip(self.history.iter()) {
+            let bounds = swatch.bounds();
+
+            let swatch_style = if bounds.contains(cursor_position) {
+                self.style_sheet.hovered()
+            } else {
+                style
+            };
+
+            checkerboard(renderer, bounds);
+
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: swatch_style.swatch_border_radius,
+                    border_width: 1.0,
+                    border_color: swatch_style.swatch_border_color,
+                },
+                Background::Color(*color),
+            );
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.history.len().hash(state);
+        self.text_input.hash_layout(state);
+    }
+}
+
+/// The size of the cells approximating gradients.
+const CELL_SIZE: f32 = 2.0;
+
+/// Returns the bounds of a cell of a grid covering the given bounds.
+fn cell(
+    bounds: Rectangle,
+    column: usize,
+    columns: usize,
+    row: usize,
+    rows: usize,
+) -> Rectangle {
+    let x = bounds.x + bounds.width * column as f32 / columns as f32;
+    let y = bounds.y + bounds.height * row as f32 / rows as f32;
+
+    Rectangle {
+        x,
+        y,
+        width: bounds.x + bounds.width * (column + 1) as f32 / columns as f32
+            - x,
+        height: bounds.y + bounds.height * (row + 1) as f32 / rows as f32 - y,
+    }
+}
+
+fn fill<Renderer: crate::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        Background::Color(color),
+    );
+}
+
+/// Draws a checkerboard, which reveals the transparency of the colors drawn
+/// over it.
+fn checkerboard<Renderer: crate::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+) {
+    let columns = (bounds.width / (bounds.height / 2.0)).ceil() as usize;
+
+    fill(renderer, bounds, Color::WHITE);
+
+    for row in 0..2 {
+        for column in (row..columns).step_by(2) {
+            fill(
+                renderer,
+                cell(bounds, column, columns, row, 2),
+                Color::from_rgb(0.8, 0.8, 0.8),
+            );
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<ColorPicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        color_picker: ColorPicker<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(color_picker)
+    }
+}
@@ -0,0 +1,153 @@
+use crate::Color;
+
+/// A color in the HSV color space, with an alpha channel.
+///
+/// The hue is kept even when it cannot be derived from the color, like for
+/// grays, so it does not jump around while picking.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) struct Hsva {
+    /// The hue, in degrees from 0 to 360.
+    pub hue: f32,
+    pub saturation: f32,
+    pub value: f32,
+    pub alpha: f32,
+}
+
+impl Hsva {
+    /// Converts a [`Color`] to the HSV color space.
+    pub fn from_color(color: Color) -> Hsva {
+        let max = color.r.max(color.g).max(color.b);
+        let min = color.r.min(color.g).min(color.b);
+
+        Hsva {
+            hue: hue(color, max, min),
+            saturation: if max > 0.0 { (max - min) / max } else { 0.0 },
+            value: max,
+            alpha: color.a,
+        }
+    }
+
+    /// Converts the [`Hsva`] color to a [`Color`].
+    pub fn to_color(self) -> Color {
+        let chroma = self.value * self.saturation;
+
+        from_hue_chroma(self.hue, chroma, self.value - chroma, self.alpha)
+    }
+}
+
+/// Converts a [`Color`] to the HSL color space.
+///
+/// It returns the hue in degrees and the saturation and lightness from 0 to
+/// 1.
+pub(super) fn to_hsl(color: Color) -> (f32, f32, f32) {
+    let max = color.r.max(color.g).max(color.b);
+    let min = color.r.min(color.g).min(color.b);
+
+    let lightness = (max + min) / 2.0;
+
+    let saturation = if max - min > 0.0 {
+        (max - min) / (1.0 - (2.0 * lightness - 1.0).abs())
+    } else {
+        0.0
+    };
+
+    (hue(color, max, min), saturation, lightness)
+}
+
+/// Converts a color in the HSL color space to a [`Color`].
+pub(super) fn from_hsl(
+    hue: f32,
+    saturation: f32,
+    lightness: f32,
+    alpha: f32,
+) -> Color {
+    let chroma = (1.0 - (2.0 * lightness - 1.0).abs()) * saturation;
+
+    from_hue_chroma(hue, chroma, lightness - chroma / 2.0, alpha)
+}
+
+fn hue(color: Color, max: f32, min: f32) -> f32 {
+    let delta = max - min;
+
+    if delta <= 0.0 {
+        0.0
+    } else if max == color.r {
+        60.0 * ((color.g - color.b) / delta).rem_euclid(6.0)
+    } else if max == color.g {
+        60.0 * ((color.b - color.r) / delta + 2.0)
+    } else {
+        60.0 * ((color.r - color.g) / delta + 4.0)
+    }
+}
+
+fn from_hue_chroma(hue: f32, chroma: f32, offset: f32, alpha: f32) -> Color {
+    let sector = hue.rem_euclid(360.0) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+
+    let (r, g, b) = match sector as u32 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+
+    Color::new(r + offset, g + offset, b + offset, alpha)
+}
+
+/// Returns whether two colors look the same once quantized to 8 bits per
+/// channel.
+pub(super) fn is_same(a: Color, b: Color) -> bool {
+    let quantize = |channel: f32| (channel * 255.0).round() as u8;
+
+    quantize(a.r) == quantize(b.r)
+        && quantize(a.g) == quantize(b.g)
+        && quantize(a.b) == quantize(b.b)
+        && quantize(a.a) == quantize(b.a)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hsva_round_trip() {
+        for &color in &[
+            Color::BLACK,
+            Color::WHITE,
+            Color::from_rgb(1.0, 0.0, 0.0),
+            Color::from_rgb(0.2, 0.6, 0.4),
+            Color::from_rgba(0.9, 0.1, 0.7, 0.5),
+            Color::from_rgb(0.1, 0.2, 0.95),
+        ] {
+            assert!(is_same(Hsva::from_color(color).to_color(), color));
+        }
+    }
+
+    #[test]
+    fn hsva_of_primaries() {
+        let green = Hsva::from_color(Color::from_rgb(0.0, 1.0, 0.0));
+
+        assert_eq!(green.hue, 120.0);
+        assert_eq!(green.saturation, 1.0);
+        assert_eq!(green.value, 1.0);
+
+        let gray = Hsva::from_color(Color::from_rgb(0.5, 0.5, 0.5));
+
+        assert_eq!(gray.saturation, 0.0);
+        assert_eq!(gray.value, 0.5);
+    }
+
+    #[test]
+    fn hsl_round_trip() {
+        let color = Color::from_rgb(0.2, 0.6, 0.4);
+        let (hue, saturation, lightness) = to_hsl(color);
+
+        assert!((hue - 150.0).abs() < 0.01);
+        assert!((saturation - 0.5).abs() < 0.01);
+        assert!((lightness - 0.4).abs() < 0.01);
+
+        assert!(is_same(from_hsl(hue, saturation, lightness, 1.0), color));
+    }
+}
@@ -0,0 +1,263 @@
+use crate::widget::color_picker::hsva;
+use crate::Color;
+
+/// The notation of the text entry of a [`ColorPicker`].
+///
+/// Any notation is accepted when typing a color, regardless of the one used
+/// to display it.
+///
+/// [`ColorPicker`]: super::ColorPicker
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Notation {
+    /// A hexadecimal notation, like `#1e90ff` or `#1e90ff80`.
+    Hex,
+
+    /// A functional RGB notation, like `rgb(30, 144, 255)` or
+    /// `rgba(30, 144, 255, 0.5)`.
+    Rgb,
+
+    /// A functional HSL notation, like `hsl(210, 100%, 56%)` or
+    /// `hsla(210, 100%, 56%, 0.5)`.
+    Hsl,
+}
+
+impl Notation {
+    /// Returns the [`Notation`] that follows this one, wrapping around.
+    pub fn next(self) -> Notation {
+        match self {
+            Notation::Hex => Notation::Rgb,
+            Notation::Rgb => Notation::Hsl,
+            Notation::Hsl => Notation::Hex,
+        }
+    }
+
+    /// Returns the short label of the [`Notation`].
+    pub fn label(self) -> &'static str {
+        match self {
+            Notation::Hex => "HEX",
+            Notation::Rgb => "RGB",
+            Notation::Hsl => "HSL",
+        }
+    }
+
+    /// Writes the given [`Color`] in the [`Notation`].
+    ///
+    /// The alpha channel is only written when the color is translucent.
+    pub fn format(self, color: Color) -> String {
+        let (r, g, b, a) = (
+            to_u8(color.r),
+            to_u8(color.g),
+            to_u8(color.b),
+            to_u8(color.a),
+        );
+        let is_opaque = a == u8::MAX;
+
+        match self {
+            Notation::Hex if is_opaque => {
+                format!("#{:02x}{:02x}{:02x}", r, g, b)
+            }
+            Notation::Hex => format!("#{:02x}{:02x}{:02x}{:02x}", r, g, b, a),
+            Notation::Rgb if is_opaque => format!("rgb({}, {}, {})", r, g, b),
+            Notation::Rgb => {
+                format!("rgba({}, {}, {}, {:.2})", r, g, b, color.a)
+            }
+            Notation::Hsl => {
+                let (hue, saturation, lightness) = hsva::to_hsl(color);
+
+                let (hue, saturation, lightness) = (
+                    hue.round(),
+                    (saturation * 100.0).round(),
+                    (lightness * 100.0).round(),
+                );
+
+                if is_opaque {
+                    format!("hsl({}, {}%, {}%)", hue, saturation, lightness)
+                } else {
+                    format!(
+                        "hsla({}, {}%, {}%, {:.2})",
+                        hue, saturation, lightness, color.a
+                    )
+                }
+            }
+        }
+    }
+}
+
+/// Parses a [`Color`] written in any [`Notation`].
+pub(super) fn parse(text: &str) -> Option<Color> {
+    let text = text.trim().to_lowercase();
+
+    if let Some(arguments) =
+        function(&text, "rgba").or_else(|| function(&text, "rgb"))
+    {
+        let [r, g, b, a] = components(arguments)?;
+
+        let channel = |value: f32| Some(within(value, 255.0)? / 255.0);
+
+        Some(Color::new(
+            channel(r)?,
+            channel(g)?,
+            channel(b)?,
+            within(a, 1.0)?,
+        ))
+    } else if let Some(arguments) =
+        function(&text, "hsla").or_else(|| function(&text, "hsl"))
+    {
+        let [hue, saturation, lightness, a] = components(arguments)?;
+
+        let percentage = |value: f32| Some(within(value, 100.0)? / 100.0);
+
+        Some(hsva::from_hsl(
+            hue,
+            percentage(saturation)?,
+            percentage(lightness)?,
+            within(a, 1.0)?,
+        ))
+    } else {
+        hex(text.strip_prefix('#').unwrap_or(&text))
+    }
+}
+
+fn to_u8(channel: f32) -> u8 {
+    (channel.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Returns the arguments of a functional notation with the given name.
+fn function<'a>(text: &'a str, name: &str) -> Option<&'a str> {
+    text.strip_prefix(name)?
+        .trim_start()
+        .strip_prefix('(')?
+        .strip_suffix(')')
+}
+
+/// Parses three numeric components and an optional alpha, which defaults to
+/// 1.
+///
+/// Percent signs are ignored.
+fn components(arguments: &str) -> Option<[f32; 4]> {
+    let mut values = arguments
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|value| !value.is_empty())
+        .map(|value| value.trim_end_matches('%').parse::<f32>());
+
+    let mut next = || values.next().transpose().ok();
+
+    let components = [next()??, next()??, next()??, next()?.unwrap_or(1.0)];
+
+    match next() {
+        Some(None) => Some(components),
+        _ => None,
+    }
+}
+
+/// Returns the given value if it is between 0 and the given maximum.
+fn within(value: f32, max: f32) -> Option<f32> {
+    if (0.0..=max).contains(&value) {
+        Some(value)
+    } else {
+        None
+    }
+}
+
+/// Parses the digits of a hexadecimal notation, which can be 3, 4, 6 or 8
+/// digits long.
+fn hex(digits: &str) -> Option<Color> {
+    if !digits.chars().all(|c| c.is_ascii_hexdigit()) {
+        return None;
+    }
+
+    let digit = |i: usize| u8::from_str_radix(&digits[i..=i], 16).ok();
+    let pair = |i: usize| u8::from_str_radix(&digits[i..i + 2], 16).ok();
+
+    let [r, g, b, a] = match digits.len() {
+        3 | 4 => {
+            let short = |i: usize| {
+                if i < digits.len() {
+                    digit(i).map(|value| value * 17)
+                } else {
+                    Some(u8::MAX)
+                }
+            };
+
+            [short(0)?, short(1)?, short(2)?, short(3)?]
+        }
+        6 | 8 => {
+            let alpha = if digits.len() == 8 { pair(6)? } else { u8::MAX };
+
+            [pair(0)?, pair(2)?, pair(4)?, alpha]
+        }
+        _ => return None,
+    };
+
+    Some(Color::from_rgba8(r, g, b, f32::from(a) / 255.0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn same(a: Option<Color>, b: Color) -> bool {
+        a.map(|a| hsva::is_same(a, b)).unwrap_or(false)
+    }
+
+    #[test]
+    fn parse_hex() {
+        let blue = Color::from_rgb8(0x1e, 0x90, 0xff);
+
+        assert!(same(parse("#1e90ff"), blue));
+        assert!(same(parse("1E90FF"), blue));
+        assert!(same(
+            parse(" #1e90ff80 "),
+            Color {
+                a: 128.0 / 255.0,
+                ..blue
+            }
+        ));
+        assert!(same(parse("#fff"), Color::WHITE));
+        assert!(same(
+            parse("#0008"),
+            Color::from_rgba8(0, 0, 0, 136.0 / 255.0)
+        ));
+        assert_eq!(parse("#12345"), None);
+        assert_eq!(parse("#ggg"), None);
+    }
+
+    #[test]
+    fn parse_functions() {
+        let blue = Color::from_rgb8(30, 144, 255);
+
+        assert!(same(parse("rgb(30, 144, 255)"), blue));
+        assert!(same(parse("RGB(30 144 255)"), blue));
+        assert!(same(
+            parse("rgba(30, 144, 255, 0.5)"),
+            Color { a: 0.5, ..blue }
+        ));
+        assert!(same(
+            parse("hsl(0, 100%, 50%)"),
+            Color::from_rgb(1.0, 0.0, 0.0)
+        ));
+        assert!(same(parse("hsla(0, 0%, 100%, 1)"), Color::WHITE));
+        assert_eq!(parse("rgb(30, 144)"), None);
+        assert_eq!(parse("rgb(30, 144, 256)"), None);
+        assert_eq!(parse("rgb(30, 144, 255, 0.5, 1)"), None);
+    }
+
+    #[test]
+    fn format_round_trip() {
+        for &color in &[
+            Color::from_rgb8(30, 144, 255),
+            Color::from_rgba8(12, 200, 99, 0.5),
+        ] {
+            for &notation in &[Notation::Hex, Notation::Rgb] {
+                assert!(same(parse(&notation.format(color)), color));
+            }
+        }
+
+        assert_eq!(Notation::Hex.format(Color::WHITE), "#ffffff");
+        assert_eq!(Notation::Rgb.format(Color::BLACK), "rgb(0, 0, 0)");
+        assert_eq!(
+            Notation::Hsl.format(Color::from_rgb(1.0, 0.0, 0.0)),
+            "hsl(0, 100%, 50%)"
+        );
+    }
+}
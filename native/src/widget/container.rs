@@ -8,8 +8,8 @@ use crate::mouse;
 use crate::overlay;
 use crate::renderer;
 use crate::{
-    Background, Clipboard, Color, Element, Hasher, Layout, Length, Padding,
-    Point, Rectangle, Shell, Widget,
+    Background, Clipboard, Color, Direction, Element, Hasher, Layout, Length,
+    Padding, Point, Rectangle, Shell, Widget,
 };
 
 use std::u32;
@@ -28,6 +28,7 @@ pub struct Container<'a, Message, Renderer> {
     max_height: u32,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    direction: Option<Direction>,
     style_sheet: Box<dyn StyleSheet + 'a>,
     content: Element<'a, Message, Renderer>,
 }
@@ -49,6 +50,7 @@ where
             max_height: u32::MAX,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            direction: None,
             style_sheet: Default::default(),
             content: content.into(),
         }
@@ -108,6 +110,16 @@ where
         self
     }
 
+    /// Sets the [`Direction`] of the contents of the [`Container`].
+    ///
+    /// By default, a [`Container`] inherits the [`Direction`] of its parent.
+    /// When the [`Direction`] is right-to-left, the horizontal alignment and
+    /// padding of the [`Container`] are mirrored.
+    pub fn direction(mut self, direction: Direction) -> Self {
+        self.direction = Some(direction);
+        self
+    }
+
     /// Sets the style of the [`Container`].
     pub fn style(
         mut self,
@@ -136,28 +148,36 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
+        let direction = self.direction.unwrap_or_else(|| limits.direction());
+
         let limits = limits
             .loose()
             .max_width(self.max_width)
             .max_height(self.max_height)
             .width(self.width)
             .height(self.height)
-            .pad(self.padding);
+            .pad(self.padding)
+            .with_direction(direction);
 
         let mut content = self.content.layout(renderer, &limits.loose());
         let size = limits.resolve(content.size());
 
         content.move_to(Point::new(
-            self.padding.left.into(),
+            if direction.is_right_to_left() {
+                self.padding.right.into()
+            } else {
+                self.padding.left.into()
+            },
             self.padding.top.into(),
         ));
         content.align(
-            Alignment::from(self.horizontal_alignment),
+            Alignment::from(direction.align_x(self.horizontal_alignment)),
             Alignment::from(self.vertical_alignment),
             size,
         );
 
         layout::Node::with_children(size.pad(self.padding), vec![content])
+            .with_direction(direction)
     }
 
     fn on_event(
@@ -230,6 +250,7 @@ where
         self.max_height.hash(state);
         self.horizontal_alignment.hash(state);
         self.vertical_alignment.hash(state);
+        self.direction.hash(state);
 
         self.content.hash_layout(state);
     }
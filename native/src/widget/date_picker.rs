@@ -0,0 +1,492 @@
+//! Let your users pick dates from a calendar.
+//!
+//! A [`DatePicker`] has some local [`State`].
+mod calendar;
+mod date;
+
+pub use date::{Date, Weekday};
+pub use iced_style::date_picker::{Calendar, Style, StyleSheet};
+
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Padding, Point, Rectangle,
+    Shell, Size, Widget,
+};
+
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+
+/// A compact field that opens a calendar to pick a date, or a range of
+/// dates.
+///
+/// The calendar can be navigated with the mouse or the keyboard: the arrow
+/// keys move between days and weeks, the page keys move between months,
+/// `Enter` picks the highlighted day, and `Escape` closes the calendar.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::date_picker::{self, Date, Weekday};
+/// #
+/// # type DatePicker<'a, Message> =
+/// #     iced_native::widget::DatePicker<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     DeadlinePicked(Date),
+/// }
+///
+/// let mut state = date_picker::State::new();
+/// let deadline = Date::from_ymd(2024, 3, 15);
+///
+/// let date_picker = DatePicker::new(&mut state, deadline, Message::DeadlinePicked)
+///     .week_start(Weekday::Sunday)
+///     .min(Date::from_ymd(2024, 1, 1).unwrap())
+///     .disabled(|date| date.weekday() == Weekday::Sunday);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct DatePicker<'a, Message, Renderer: text::Renderer> {
+    state: &'a mut State,
+    selection: Selection<Message>,
+    week_start: Weekday,
+    min: Option<Date>,
+    max: Option<Date>,
+    is_disabled: Box<dyn Fn(Date) -> bool>,
+    placeholder: Option<String>,
+    width: Length,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+/// The current value of a [`DatePicker`] and how to report a new one.
+enum Selection<Message> {
+    Single {
+        value: Option<Date>,
+        on_select: Box<dyn Fn(Date) -> Message>,
+    },
+    Range {
+        value: Option<RangeInclusive<Date>>,
+        on_select: Box<dyn Fn(RangeInclusive<Date>) -> Message>,
+    },
+}
+
+impl<'a, Message, Renderer> DatePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default padding of a [`DatePicker`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    /// Creates a new [`DatePicker`] that picks a single date.
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`DatePicker`]
+    ///   * the current value of the [`DatePicker`], if any
+    ///   * a function that will be called when a date is picked. It
+    ///     receives the picked [`Date`] and must produce a `Message`.
+    pub fn new(
+        state: &'a mut State,
+        value: Option<Date>,
+        on_select: impl Fn(Date) -> Message + 'static,
+    ) -> Self {
+        Self::with_selection(
+            state,
+            Selection::Single {
+                value,
+                on_select: Box::new(on_select),
+            },
+        )
+    }
+
+    /// Creates a new [`DatePicker`] that picks a range of dates.
+    ///
+    /// The first day picked in the calendar starts the range and the second
+    /// one ends it, in any order. The range may contain disabled dates.
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`DatePicker`]
+    ///   * the current range of the [`DatePicker`], if any
+    ///   * a function that will be called when a range is picked. It
+    ///     receives the picked range and must produce a `Message`.
+    pub fn range(
+        state: &'a mut State,
+        value: Option<RangeInclusive<Date>>,
+        on_select: impl Fn(RangeInclusive<Date>) -> Message + 'static,
+    ) -> Self {
+        Self::with_selection(
+            state,
+            Selection::Range {
+                value,
+                on_select: Box::new(on_select),
+            },
+        )
+    }
+
+    fn with_selection(
+        state: &'a mut State,
+        selection: Selection<Message>,
+    ) -> Self {
+        DatePicker {
+            state,
+            selection,
+            week_start: Weekday::Monday,
+            min: None,
+            max: None,
+            is_disabled: Box::new(|_| false),
+            placeholder: None,
+            width: Length::Shrink,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the first day of the week shown in the calendar of the
+    /// [`DatePicker`].
+    ///
+    /// By default, weeks start on Monday.
+    pub fn week_start(mut self, week_start: Weekday) -> Self {
+        self.week_start = week_start;
+        self
+    }
+
+    /// Sets the earliest [`Date`] that can be picked.
+    pub fn min(mut self, min: Date) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// Sets the latest [`Date`] that can be picked.
+    pub fn max(mut self, max: Date) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// Sets the function that decides which dates cannot be picked.
+    pub fn disabled(
+        mut self,
+        is_disabled: impl Fn(Date) -> bool + 'static,
+    ) -> Self {
+        self.is_disabled = Box::new(is_disabled);
+        self
+    }
+
+    /// Sets the placeholder of the [`DatePicker`], shown when it has no
+    /// value.
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// Sets the width of the field of the [`DatePicker`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`DatePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`DatePicker`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`DatePicker`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`DatePicker`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Returns the text shown in the field of the [`DatePicker`], if any.
+    fn label(&self) -> Option<String> {
+        match &self.selection {
+            Selection::Single { value, .. } => {
+                value.map(|date| date.to_string())
+            }
+            Selection::Range { value, .. } => value
+                .as_ref()
+                .map(|range| format!("{} – {}", range.start(), range.end())),
+        }
+    }
+
+    /// Returns the [`Date`] highlighted when the calendar opens.
+    fn initial_date(&self) -> Date {
+        let value = match &self.selection {
+            Selection::Single { value, .. } => *value,
+            Selection::Range { value, .. } => {
+                value.as_ref().map(|range| *range.start())
+            }
+        };
+
+        let today = Date::today();
+
+        value.unwrap_or_else(|| match (self.min, self.max) {
+            (Some(min), _) if today < min => min,
+            (_, Some(max)) if today > max => max,
+            _ => today,
+        })
+    }
+}
+
+/// The local state of a [`DatePicker`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_open: bool,
+    month: Option<Date>,
+    cursor: Option<Date>,
+    hovered: Option<Date>,
+    range_start: Option<Date>,
+}
+
+impl State {
+    /// Creates a new [`State`], representing a closed [`DatePicker`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the calendar of the [`DatePicker`] is open or not.
+    pub fn is_open(&self) -> bool {
+        self.is_open
+    }
+
+    /// Opens the calendar of the [`DatePicker`] at the given [`Date`].
+    pub fn open(&mut self, date: Date) {
+        self.is_open = true;
+        self.month = Some(date.first_of_month());
+        self.cursor = Some(date);
+        self.hovered = None;
+        self.range_start = None;
+    }
+
+    /// Closes the calendar of the [`DatePicker`], discarding any range
+    /// being picked.
+    pub fn close(&mut self) {
+        self.is_open = false;
+        self.range_start = None;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for DatePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(self.width)
+            .height(Length::Shrink)
+            .pad(self.padding);
+
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        let measure = |content: &str| {
+            renderer.measure_width(content, text_size, self.font.clone())
+        };
+
+        let sample = match self.selection {
+            Selection::Single { .. } => "0000-00-00",
+            Selection::Range { .. } => "0000-00-00 – 0000-00-00",
+        };
+
+        let width = self
+            .placeholder
+            .as_deref()
+            .map(measure)
+            .unwrap_or(0.0)
+            .max(measure(sample));
+
+        let size = limits
+            .resolve(Size::new(width.ceil(), f32::from(text_size)))
+            .pad(self.padding);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        _shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if self.state.is_open {
+                    // TODO: Encode cursor availability in the type system
+                    let is_over_calendar =
+                        cursor_position.x < 0.0 || cursor_position.y < 0.0;
+
+                    if !is_over_calendar {
+                        self.state.close();
+                    }
+
+                    event::Status::Captured
+                } else if layout.bounds().contains(cursor_position) {
+                    let date = self.initial_date();
+
+                    self.state.open(date);
+
+                    event::Status::Captured
+                } else {
+                    event::Status::Ignored
+                }
+            }
+            _ => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let style = if self.state.is_open || bounds.contains(cursor_position) {
+            self.style_sheet.hovered()
+        } else {
+            self.style_sheet.active()
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_color: style.border_color,
+                border_width: style.border_width,
+                border_radius: style.border_radius,
+            },
+            style.background,
+        );
+
+        let label = self.label();
+
+        let (content, color) = match (&label, &self.placeholder) {
+            (Some(label), _) => (label, style.text_color),
+            (None, Some(placeholder)) => (placeholder, style.placeholder_color),
+            (None, None) => return,
+        };
+
+        renderer.fill_text(Text {
+            content,
+            size: f32::from(self.text_size.unwrap_or(renderer.default_size())),
+            font: self.font.clone(),
+            color,
+            bounds: Rectangle {
+                x: bounds.x + f32::from(self.padding.left),
+                y: bounds.center_y(),
+                ..bounds
+            },
+            horizontal_alignment: alignment::Horizontal::Left,
+            vertical_alignment: alignment::Vertical::Center,
+            format: text::Format::default(),
+            decorations: &[],
+        });
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.font.hash(state);
+        self.placeholder.hash(state);
+        matches!(self.selection, Selection::Range { .. }).hash(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        _renderer: &Renderer,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        if !self.state.is_open {
+            return None;
+        }
+
+        let bounds = layout.bounds();
+
+        let calendar = calendar::Calendar {
+            state: self.state,
+            selection: &self.selection,
+            week_start: self.week_start,
+            min: self.min,
+            max: self.max,
+            is_disabled: &*self.is_disabled,
+            text_size: self.text_size,
+            font: self.font.clone(),
+            style: self.style_sheet.calendar(),
+            target_height: bounds.height,
+        };
+
+        Some(overlay::Element::new(layout.position(), Box::new(calendar)))
+    }
+}
+
+impl<'a, Message, Renderer> From<DatePicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        date_picker: DatePicker<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(date_picker)
+    }
+}
@@ -0,0 +1,492 @@
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::date_picker::date::{self, Date, Weekday};
+use crate::widget::date_picker::{Selection, State};
+use crate::{
+    Background, Clipboard, Color, Hasher, Layout, Point, Rectangle, Shell, Size,
+};
+
+use iced_style::date_picker::Calendar as Style;
+
+use std::hash::Hash;
+
+/// The amount of weeks shown in a [`Calendar`], enough for any month.
+const WEEKS: usize = 6;
+
+/// The calendar overlay of a [`DatePicker`].
+///
+/// Its layout has a child for the previous month button, another one for
+/// the next month button, and then one for every day of the grid.
+///
+/// [`DatePicker`]: super::DatePicker
+pub(super) struct Calendar<'a, Message, Renderer: text::Renderer> {
+    pub state: &'a mut State,
+    pub selection: &'a Selection<Message>,
+    pub week_start: Weekday,
+    pub min: Option<Date>,
+    pub max: Option<Date>,
+    pub is_disabled: &'a dyn Fn(Date) -> bool,
+    pub text_size: Option<u16>,
+    pub font: Renderer::Font,
+    pub style: Style,
+    pub target_height: f32,
+}
+
+impl<'a, Message, Renderer> Calendar<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn month(&self) -> Date {
+        self.state
+            .month
+            .unwrap_or_else(|| Date::today().first_of_month())
+    }
+
+    /// Returns the first [`Date`] of the grid, which may belong to the
+    /// previous month.
+    fn first_day(&self) -> Date {
+        let month = self.month();
+
+        let offset =
+            (month.weekday().index() + 7 - self.week_start.index()) % 7;
+
+        month.add_days(-(offset as i64))
+    }
+
+    fn is_selectable(&self, date: Date) -> bool {
+        !matches!(self.min, Some(min) if date < min)
+            && !matches!(self.max, Some(max) if date > max)
+            && !(self.is_disabled)(date)
+    }
+
+    fn clamp(&self, date: Date) -> Date {
+        match (self.min, self.max) {
+            (Some(min), _) if date < min => min,
+            (_, Some(max)) if date > max => max,
+            _ => date,
+        }
+    }
+
+    /// Returns the [`Date`] of the day under the cursor, if any.
+    fn day_at(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<Date> {
+        let first_day = self.first_day();
+
+        layout
+            .children()
+            .skip(2)
+            .position(|day| day.bounds().contains(cursor_position))
+            .map(|index| first_day.add_days(index as i64))
+    }
+
+    fn show_month(&mut self, months: i32) {
+        let month = self.month().add_months(months);
+
+        self.state.month = Some(month);
+    }
+
+    fn move_cursor(&mut self, date: Date) {
+        let date = self.clamp(date);
+
+        self.state.cursor = Some(date);
+        self.state.month = Some(date.first_of_month());
+    }
+
+    fn select(&mut self, date: Date, shell: &mut Shell<'_, Message>) {
+        if !self.is_selectable(date) {
+            return;
+        }
+
+        match self.selection {
+            Selection::Single { on_select, .. } => {
+                shell.publish(on_select(date));
+
+                self.state.close();
+            }
+            Selection::Range { on_select, .. } => {
+                match self.state.range_start.take() {
+                    Some(start) => {
+                        shell.publish(on_select(if start <= date {
+                            start..=date
+                        } else {
+                            date..=start
+                        }));
+
+                        self.state.close();
+                    }
+                    None => {
+                        self.state.range_start = Some(date);
+                        self.state.cursor = Some(date);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Returns whether the given [`Date`] is an end of the selection and
+    /// whether it is inside of it.
+    fn selection_of(&self, date: Date) -> (bool, bool) {
+        let range = match (self.selection, self.state.range_start) {
+            (Selection::Range { .. }, Some(start)) => {
+                let end =
+                    self.state.hovered.or(self.state.cursor).unwrap_or(start);
+
+                Some(if start <= end {
+                    (start, end)
+                } else {
+                    (end, start)
+                })
+            }
+            (Selection::Range { value, .. }, None) => {
+                value.as_ref().map(|range| (*range.start(), *range.end()))
+            }
+            (Selection::Single { value, .. }, _) => {
+                value.map(|value| (value, value))
+            }
+        };
+
+        match range {
+            Some((start, end)) => {
+                (date == start || date == end, date > start && date < end)
+            }
+            None => (false, false),
+        }
+    }
+}
+
+impl<'a, Message, Renderer> crate::Overlay<Message, Renderer>
+    for Calendar<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        let cell = f32::from(text_size) * 2.0;
+        let padding = cell / 4.0;
+
+        let size = Size::new(
+            cell * 7.0 + padding * 2.0,
+            cell * (WEEKS + 2) as f32 + padding * 2.0,
+        );
+
+        let mut previous = layout::Node::new(Size::new(cell, cell));
+        previous.move_to(Point::new(padding, padding));
+
+        let mut next = layout::Node::new(Size::new(cell, cell));
+        next.move_to(Point::new(padding + cell * 6.0, padding));
+
+        let days = (0..WEEKS * 7).map(|index| {
+            let mut day = layout::Node::new(Size::new(cell, cell));
+
+            day.move_to(Point::new(
+                padding + (index % 7) as f32 * cell,
+                padding + (index / 7 + 2) as f32 * cell,
+            ));
+
+            day
+        });
+
+        let mut children = vec![previous, next];
+        children.extend(days);
+
+        let mut node = layout::Node::with_children(size, children);
+
+        let space_below = bounds.height - (position.y + self.target_height);
+        let space_above = position.y;
+
+        node.move_to(Point::new(
+            position.x.min(bounds.width - size.width).max(0.0),
+            if space_below > space_above {
+                position.y + self.target_height
+            } else {
+                position.y - size.height
+            },
+        ));
+
+        node
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        self.text_size.hash(state);
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let previous = children.next().unwrap();
+        let next = children.next().unwrap();
+
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                self.state.hovered = self.day_at(layout, cursor_position);
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if bounds.contains(cursor_position) =>
+            {
+                if previous.bounds().contains(cursor_position) {
+                    self.show_month(-1);
+                } else if next.bounds().contains(cursor_position) {
+                    self.show_month(1);
+                } else if let Some(date) = self.day_at(layout, cursor_position)
+                {
+                    self.select(date, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if bounds.contains(cursor_position) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y > 0.0 {
+                    self.show_month(-1);
+                } else if y < 0.0 {
+                    self.show_month(1);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) => {
+                let cursor = self.state.cursor.unwrap_or_else(|| self.month());
+
+                let months = if modifiers.shift() { 12 } else { 1 };
+
+                match key_code {
+                    keyboard::KeyCode::Left => {
+                        self.move_cursor(cursor.add_days(-1))
+                    }
+                    keyboard::KeyCode::Right => {
+                        self.move_cursor(cursor.add_days(1))
+                    }
+                    keyboard::KeyCode::Up => {
+                        self.move_cursor(cursor.add_days(-7))
+                    }
+                    keyboard::KeyCode::Down => {
+                        self.move_cursor(cursor.add_days(7))
+                    }
+                    keyboard::KeyCode::PageUp => {
+                        self.move_cursor(cursor.add_months(-months))
+                    }
+                    keyboard::KeyCode::PageDown => {
+                        self.move_cursor(cursor.add_months(months))
+                    }
+                    keyboard::KeyCode::Enter
+                    | keyboard::KeyCode::NumpadEnter
+                    | keyboard::KeyCode::Space => self.select(cursor, shell),
+                    keyboard::KeyCode::Escape => self.state.close(),
+                    _ => return event::Status::Ignored,
+                }
+
+                self.state.hovered = None;
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_over_button = layout
+            .children()
+            .take(2)
+            .any(|button| button.bounds().contains(cursor_position));
+
+        let is_over_day = matches!(
+            self.day_at(layout, cursor_position),
+            Some(date) if self.is_selectable(date)
+        );
+
+        if is_over_button || is_over_day {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let previous = children.next().unwrap();
+        let next = children.next().unwrap();
+
+        let style = &self.style;
+        let size = f32::from(self.text_size.unwrap_or(renderer.default_size()));
+        let month = self.month();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: style.border_radius,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            },
+            style.background,
+        );
+
+        let label = |renderer: &mut Renderer,
+                     content: &str,
+                     bounds: Rectangle,
+                     color: Color| {
+            renderer.fill_text(Text {
+                content,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size,
+                color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                format: text::Format::default(),
+                decorations: &[],
+            });
+        };
+
+        for (button, icon) in &[(previous, "<"), (next, ">")] {
+            let bounds = button.bounds();
+
+            if bounds.contains(cursor_position) {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: style.day_border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    style.hovered_background,
+                );
+            }
+
+            label(renderer, icon, bounds, style.text_color);
+        }
+
+        let title =
+            format!("{} {}", date::month_name(month.month()), month.year());
+
+        label(
+            renderer,
+            &title,
+            Rectangle {
+                x: previous.bounds().x + previous.bounds().width,
+                width: next.bounds().x
+                    - (previous.bounds().x + previous.bounds().width),
+                ..previous.bounds()
+            },
+            style.text_color,
+        );
+
+        let first_day = self.first_day();
+        let today = Date::today();
+
+        for (index, day) in children.enumerate() {
+            let bounds = day.bounds();
+            let date = first_day.add_days(index as i64);
+
+            if index < 7 {
+                label(
+                    renderer,
+                    self.week_start.add(index).abbreviation(),
+                    Rectangle {
+                        y: bounds.y - bounds.height,
+                        ..bounds
+                    },
+                    style.weekday_color,
+                );
+            }
+
+            let is_selectable = self.is_selectable(date);
+            let is_highlighted =
+                self.state.hovered.or(self.state.cursor) == Some(date);
+            let (is_selected, is_in_range) = self.selection_of(date);
+
+            let background = if is_selected {
+                Some(style.selected_background)
+            } else if is_in_range {
+                Some(style.range_background)
+            } else if is_highlighted && is_selectable {
+                Some(style.hovered_background)
+            } else {
+                None
+            };
+
+            let is_today = date == today;
+
+            if background.is_some() || is_today {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: style.day_border_radius,
+                        border_width: if is_today { 1.0 } else { 0.0 },
+                        border_color: style.today_border_color,
+                    },
+                    background.unwrap_or(Background::Color(Color::TRANSPARENT)),
+                );
+            }
+
+            let color = if is_selected {
+                style.selected_text_color
+            } else if !is_selectable {
+                style.disabled_color
+            } else if date.month() != month.month() {
+                style.outside_color
+            } else {
+                style.text_color
+            };
+
+            label(renderer, &date.day().to_string(), bounds, color);
+        }
+    }
+}
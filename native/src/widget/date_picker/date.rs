@@ -0,0 +1,285 @@
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// A day of the proleptic Gregorian calendar.
+///
+/// Dates are ordered chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Date {
+    year: i32,
+    month: u32,
+    day: u32,
+}
+
+impl Date {
+    /// Creates a new [`Date`] from a year, a month from 1 to 12, and a day
+    /// of the month starting at 1.
+    ///
+    /// Returns `None` if the date does not exist.
+    pub fn from_ymd(year: i32, month: u32, day: u32) -> Option<Date> {
+        if (1..=12).contains(&month)
+            && day >= 1
+            && day <= days_in_month(year, month)
+        {
+            Some(Date { year, month, day })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the current [`Date`] in UTC.
+    pub fn today() -> Date {
+        let seconds = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|duration| duration.as_secs())
+            .unwrap_or(0);
+
+        Date::from_days(seconds as i64 / 86_400)
+    }
+
+    /// Returns the year of the [`Date`].
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    /// Returns the month of the [`Date`], from 1 to 12.
+    pub fn month(&self) -> u32 {
+        self.month
+    }
+
+    /// Returns the day of the month of the [`Date`], starting at 1.
+    pub fn day(&self) -> u32 {
+        self.day
+    }
+
+    /// Returns the [`Weekday`] of the [`Date`].
+    pub fn weekday(&self) -> Weekday {
+        // 1970-01-01 was a Thursday
+        Weekday::from_index((self.days() + 3).rem_euclid(7) as usize)
+    }
+
+    /// Returns the amount of days in the month of the [`Date`].
+    pub fn days_in_month(&self) -> u32 {
+        days_in_month(self.year, self.month)
+    }
+
+    /// Returns the first day of the month of the [`Date`].
+    pub fn first_of_month(&self) -> Date {
+        Date { day: 1, ..*self }
+    }
+
+    /// Returns the [`Date`] the given amount of days later, or earlier if
+    /// negative.
+    pub fn add_days(&self, days: i64) -> Date {
+        Date::from_days(self.days() + days)
+    }
+
+    /// Returns the [`Date`] the given amount of months later, or earlier if
+    /// negative.
+    ///
+    /// The day is clamped to the length of the resulting month.
+    pub fn add_months(&self, months: i32) -> Date {
+        let index = self.year * 12 + self.month as i32 - 1 + months;
+
+        let year = index.div_euclid(12);
+        let month = index.rem_euclid(12) as u32 + 1;
+
+        Date {
+            year,
+            month,
+            day: self.day.min(days_in_month(year, month)),
+        }
+    }
+
+    /// Returns the amount of days since 1970-01-01.
+    fn days(&self) -> i64 {
+        let year = i64::from(self.year) - i64::from(self.month <= 2);
+        let month = i64::from(self.month);
+
+        let era = year.div_euclid(400);
+        let year_of_era = year - era * 400;
+        let day_of_year =
+            (153 * ((month + 9) % 12) + 2) / 5 + i64::from(self.day) - 1;
+        let day_of_era = year_of_era * 365 + year_of_era / 4
+            - year_of_era / 100
+            + day_of_year;
+
+        era * 146_097 + day_of_era - 719_468
+    }
+
+    /// Returns the [`Date`] the given amount of days after 1970-01-01.
+    fn from_days(days: i64) -> Date {
+        let days = days + 719_468;
+
+        let era = days.div_euclid(146_097);
+        let day_of_era = days - era * 146_097;
+        let year_of_era = (day_of_era - day_of_era / 1460
+            + day_of_era / 36_524
+            - day_of_era / 146_096)
+            / 365;
+        let day_of_year = day_of_era
+            - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+        let shifted_month = (5 * day_of_year + 2) / 153;
+
+        let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+        let month = (shifted_month + 2) % 12 + 1;
+        let year = year_of_era + era * 400 + i64::from(month <= 2);
+
+        Date {
+            year: year as i32,
+            month: month as u32,
+            day: day as u32,
+        }
+    }
+}
+
+impl fmt::Display for Date {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
+    }
+}
+
+/// A day of the week.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[allow(missing_docs)]
+pub enum Weekday {
+    Monday,
+    Tuesday,
+    Wednesday,
+    Thursday,
+    Friday,
+    Saturday,
+    Sunday,
+}
+
+impl Weekday {
+    const ALL: [Weekday; 7] = [
+        Weekday::Monday,
+        Weekday::Tuesday,
+        Weekday::Wednesday,
+        Weekday::Thursday,
+        Weekday::Friday,
+        Weekday::Saturday,
+        Weekday::Sunday,
+    ];
+
+    /// Returns the [`Weekday`] the given amount of days after Monday.
+    fn from_index(index: usize) -> Weekday {
+        Self::ALL[index % 7]
+    }
+
+    /// Returns the amount of days between Monday and the [`Weekday`].
+    pub(super) fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the [`Weekday`] the given amount of days later.
+    pub(super) fn add(self, days: usize) -> Weekday {
+        Weekday::from_index(self.index() + days)
+    }
+
+    /// Returns the two-letter abbreviation of the [`Weekday`].
+    pub(super) fn abbreviation(self) -> &'static str {
+        match self {
+            Weekday::Monday => "Mo",
+            Weekday::Tuesday => "Tu",
+            Weekday::Wednesday => "We",
+            Weekday::Thursday => "Th",
+            Weekday::Friday => "Fr",
+            Weekday::Saturday => "Sa",
+            Weekday::Sunday => "Su",
+        }
+    }
+}
+
+/// Returns the name of the given month, from 1 to 12.
+pub(super) fn month_name(month: u32) -> &'static str {
+    const NAMES: [&str; 12] = [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ];
+
+    NAMES[(month as usize + 11) % 12]
+}
+
+fn is_leap_year(year: i32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(year: i32, month: u32, day: u32) -> Date {
+        Date::from_ymd(year, month, day).unwrap()
+    }
+
+    #[test]
+    fn from_ymd_rejects_invalid_dates() {
+        assert_eq!(Date::from_ymd(2023, 2, 29), None);
+        assert_eq!(Date::from_ymd(2023, 13, 1), None);
+        assert_eq!(Date::from_ymd(2023, 4, 31), None);
+        assert_eq!(Date::from_ymd(2023, 1, 0), None);
+        assert!(Date::from_ymd(2024, 2, 29).is_some());
+        assert!(Date::from_ymd(2000, 2, 29).is_some());
+        assert_eq!(Date::from_ymd(1900, 2, 29), None);
+    }
+
+    #[test]
+    fn days_round_trip() {
+        assert_eq!(date(1970, 1, 1).days(), 0);
+        assert_eq!(date(2000, 3, 1).days(), 11_017);
+        assert_eq!(date(1969, 12, 31).days(), -1);
+
+        for days in -800_000..800_000 {
+            assert_eq!(Date::from_days(days).days(), days);
+        }
+    }
+
+    #[test]
+    fn weekday() {
+        assert_eq!(date(1970, 1, 1).weekday(), Weekday::Thursday);
+        assert_eq!(date(2024, 2, 29).weekday(), Weekday::Thursday);
+        assert_eq!(date(2023, 1, 1).weekday(), Weekday::Sunday);
+        assert_eq!(date(1969, 12, 29).weekday(), Weekday::Monday);
+    }
+
+    #[test]
+    fn add_days() {
+        assert_eq!(date(2023, 12, 31).add_days(1), date(2024, 1, 1));
+        assert_eq!(date(2024, 3, 1).add_days(-1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 1).add_days(366), date(2025, 1, 1));
+    }
+
+    #[test]
+    fn add_months_clamps_day() {
+        assert_eq!(date(2024, 1, 31).add_months(1), date(2024, 2, 29));
+        assert_eq!(date(2024, 3, 31).add_months(-1), date(2024, 2, 29));
+        assert_eq!(date(2024, 1, 15).add_months(-1), date(2023, 12, 15));
+        assert_eq!(date(2024, 11, 30).add_months(14), date(2026, 1, 30));
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(date(2024, 3, 7).to_string(), "2024-03-07");
+    }
+}
@@ -0,0 +1,44 @@
+//! Let your users drag content around and drop it somewhere else.
+//!
+//! A [`DragSource`] carries a payload of some type `T`. While it is dragged,
+//! every [`DropTarget`] accepting that payload is highlighted when hovered,
+//! and dropping it on one of them produces a message with the payload.
+//!
+//! Both widgets share the drag and drop [`State`] of your application, which
+//! must be kept up to date with the [`Event`] messages of every
+//! [`DragSource`].
+//!
+//! # Example
+//! ```
+//! # use iced_native::widget::drag_and_drop::{self, Grab};
+//! # use iced_native::widget::{Column, Text};
+//! # use iced_native::Point;
+//! #
+//! # type DragSource<'a, T, Message> = drag_and_drop::DragSource<
+//! #     'a, T, Message, iced_native::renderer::Null,
+//! # >;
+//! # type DropTarget<'a, T, Message> = drag_and_drop::DropTarget<
+//! #     'a, T, Message, iced_native::renderer::Null,
+//! # >;
+//! #
+//! #[derive(Debug, Clone)]
+//! enum Message {
+//!     Dragged(drag_and_drop::Event<usize>),
+//!     Dropped(usize, Point),
+//! }
+//!
+//! let state = drag_and_drop::State::new();
+//! let mut grab = Grab::new();
+//!
+//! let card = DragSource::new(&mut grab, 0, Text::new("Card"), Message::Dragged);
+//!
+//! let target = DropTarget::new(&state, Column::new().push(card), Message::Dropped);
+//! ```
+mod drag_source;
+mod drop_target;
+mod preview;
+mod state;
+
+pub use drag_source::{DragSource, Grab};
+pub use drop_target::{DropTarget, Style, StyleSheet};
+pub use state::{Event, State};
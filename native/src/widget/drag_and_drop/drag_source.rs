@@ -0,0 +1,274 @@
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::drag_and_drop::preview::Preview;
+use crate::widget::drag_and_drop::state;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use std::hash::Hash;
+
+/// Some content that can be dragged around, carrying a payload.
+///
+/// While it is dragged, a copy of its content follows the mouse cursor.
+/// Dropping it over a [`DropTarget`] accepting its payload produces the drop
+/// message of the [`DropTarget`].
+///
+/// A [`DragSource`] has some local [`Grab`] state.
+///
+/// [`DropTarget`]: super::DropTarget
+#[allow(missing_debug_implementations)]
+pub struct DragSource<'a, T, Message, Renderer> {
+    grab: &'a mut Grab,
+    payload: T,
+    content: Element<'a, Message, Renderer>,
+    on_drag: Box<dyn Fn(state::Event<T>) -> Message>,
+}
+
+impl<'a, T, Message, Renderer> DragSource<'a, T, Message, Renderer>
+where
+    T: Clone,
+    Renderer: crate::Renderer,
+{
+    /// The distance that the mouse cursor needs to travel while pressed
+    /// before a [`DragSource`] is picked.
+    ///
+    /// It allows the content of a [`DragSource`] to be clicked as usual.
+    const DRAG_THRESHOLD: f32 = 5.0;
+
+    /// Creates a new [`DragSource`].
+    ///
+    /// It expects:
+    ///   * the local [`Grab`] state of the [`DragSource`]
+    ///   * the payload carried by the [`DragSource`]
+    ///   * the content of the [`DragSource`]
+    ///   * a function that will be called when the [`DragSource`] is picked,
+    ///     released, or its drag is canceled. The [`Event`] it receives must
+    ///     be fed to [`State::update`].
+    ///
+    /// [`Event`]: state::Event
+    /// [`State::update`]: state::State::update
+    pub fn new<E>(
+        grab: &'a mut Grab,
+        payload: T,
+        content: E,
+        on_drag: impl Fn(state::Event<T>) -> Message + 'static,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        DragSource {
+            grab,
+            payload,
+            content: content.into(),
+            on_drag: Box::new(on_drag),
+        }
+    }
+}
+
+/// The local state of a [`DragSource`], which tracks whether it is grabbed
+/// by the mouse cursor.
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub struct Grab {
+    action: Option<Action>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Action {
+    Pressed { origin: Point },
+    Dragging { position: Point },
+}
+
+impl Grab {
+    /// Creates a new [`Grab`] state.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`DragSource`] is being dragged or not.
+    pub fn is_dragging(&self) -> bool {
+        matches!(self.action, Some(Action::Dragging { .. }))
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for DragSource<'a, T, Message, Renderer>
+where
+    T: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if !self.grab.is_dragging() {
+            if let event::Status::Captured = self.content.on_event(
+                event.clone(),
+                layout,
+                cursor_position,
+                renderer,
+                clipboard,
+                shell,
+            ) {
+                return event::Status::Captured;
+            }
+        }
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if layout.bounds().contains(cursor_position) =>
+            {
+                self.grab.action = Some(Action::Pressed {
+                    origin: cursor_position,
+                });
+            }
+            Event::Mouse(mouse::Event::CursorMoved { position })
+            | Event::Touch(touch::Event::FingerMoved { position, .. }) => {
+                match self.grab.action {
+                    Some(Action::Pressed { origin })
+                        if origin.distance(position) > Self::DRAG_THRESHOLD =>
+                    {
+                        self.grab.action = Some(Action::Dragging { position });
+
+                        shell.publish((self.on_drag)(state::Event::Picked(
+                            self.payload.clone(),
+                        )));
+
+                        return event::Status::Captured;
+                    }
+                    Some(Action::Dragging { .. }) => {
+                        self.grab.action = Some(Action::Dragging { position });
+
+                        return event::Status::Captured;
+                    }
+                    _ => {}
+                }
+            }
+            // The release is not captured, so the drop target below the
+            // cursor can handle it
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if self.grab.is_dragging() {
+                    shell.publish((self.on_drag)(state::Event::Released));
+                }
+
+                self.grab.action = None;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) if self.grab.is_dragging() => {
+                self.grab.action = None;
+
+                shell.publish((self.on_drag)(state::Event::Canceled));
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.grab.is_dragging() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let content_interaction = self.content.mouse_interaction(
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        );
+
+        if content_interaction == mouse::Interaction::Idle
+            && layout.bounds().contains(cursor_position)
+        {
+            mouse::Interaction::Grab
+        } else {
+            content_interaction
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport);
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        match self.grab.action {
+            Some(Action::Dragging { position }) => Some(overlay::Element::new(
+                position,
+                Box::new(Preview::new(&self.content, layout.bounds().size())),
+            )),
+            _ => self.content.overlay(layout, renderer),
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<DragSource<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Clone,
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        drag_source: DragSource<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(drag_source)
+    }
+}
@@ -0,0 +1,260 @@
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::widget::drag_and_drop::State;
+use crate::window;
+use crate::{
+    Background, Clipboard, Color, Element, Hasher, Layout, Length, Point,
+    Rectangle, Shell, Widget,
+};
+
+use std::hash::Hash;
+use std::path::PathBuf;
+
+pub use iced_style::drag_and_drop::{Style, StyleSheet};
+
+/// Some content where the payload of a [`DragSource`] can be dropped.
+///
+/// A [`DropTarget`] is highlighted while a payload that it accepts is dragged
+/// over it. When nested, only the innermost [`DropTarget`] receives a drop.
+///
+/// [`DragSource`]: super::DragSource
+#[allow(missing_debug_implementations)]
+pub struct DropTarget<'a, T, Message, Renderer> {
+    state: &'a State<T>,
+    content: Element<'a, Message, Renderer>,
+    on_drop: Box<dyn Fn(T, Point) -> Message>,
+    on_file_drop: Option<Box<dyn Fn(PathBuf) -> Message>>,
+    accept: Box<dyn Fn(&T) -> bool>,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, T, Message, Renderer> DropTarget<'a, T, Message, Renderer>
+where
+    T: Clone,
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`DropTarget`].
+    ///
+    /// It expects:
+    ///   * the drag and drop [`State`] of the user interface
+    ///   * the content of the [`DropTarget`]
+    ///   * a function that will be called when a payload is dropped on the
+    ///     [`DropTarget`]. It receives the payload and the position of the
+    ///     drop, relative to the top-left corner of the [`DropTarget`].
+    pub fn new<E>(
+        state: &'a State<T>,
+        content: E,
+        on_drop: impl Fn(T, Point) -> Message + 'static,
+    ) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        DropTarget {
+            state,
+            content: content.into(),
+            on_drop: Box::new(on_drop),
+            on_file_drop: None,
+            accept: Box::new(|_| true),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the function that will be called when a file is dropped on the
+    /// [`DropTarget`] from outside of the application.
+    ///
+    /// Only the [`DropTarget`] under the mouse cursor receives the file.
+    pub fn on_file_drop(
+        mut self,
+        on_file_drop: impl Fn(PathBuf) -> Message + 'static,
+    ) -> Self {
+        self.on_file_drop = Some(Box::new(on_file_drop));
+        self
+    }
+
+    /// Sets the function that decides which payloads the [`DropTarget`]
+    /// accepts.
+    ///
+    /// By default, a [`DropTarget`] accepts any payload.
+    pub fn accept(mut self, accept: impl Fn(&T) -> bool + 'static) -> Self {
+        self.accept = Box::new(accept);
+        self
+    }
+
+    /// Sets the style of the [`DropTarget`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Returns the dragged payload, if the [`DropTarget`] accepts it.
+    fn accepted(&self) -> Option<&T> {
+        self.state
+            .dragged()
+            .filter(|payload| (self.accept)(payload))
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for DropTarget<'a, T, Message, Renderer>
+where
+    T: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        // A nested drop target captures the drop first
+        if let event::Status::Captured = self.content.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        ) {
+            return event::Status::Captured;
+        }
+
+        let bounds = layout.bounds();
+
+        if !bounds.contains(cursor_position) {
+            return event::Status::Ignored;
+        }
+
+        let position = Point::new(
+            cursor_position.x - bounds.x,
+            cursor_position.y - bounds.y,
+        );
+
+        let message = match event {
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => self
+                .accepted()
+                .map(|payload| (self.on_drop)(payload.clone(), position)),
+            Event::Window(window::Event::FileDropped(path)) => self
+                .on_file_drop
+                .as_ref()
+                .map(|on_file_drop| on_file_drop(path)),
+            _ => None,
+        };
+
+        match message {
+            Some(message) => {
+                shell.publish(message);
+
+                event::Status::Captured
+            }
+            None => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.mouse_interaction(
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        let is_hovered =
+            self.accepted().is_some() && bounds.contains(cursor_position);
+
+        let styling = if is_hovered {
+            self.style_sheet.hovered()
+        } else {
+            self.style_sheet.active()
+        };
+
+        if styling.background.is_some() || styling.border_width > 0.0 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: styling.border_radius,
+                    border_width: styling.border_width,
+                    border_color: styling.border_color,
+                },
+                styling
+                    .background
+                    .unwrap_or(Background::Color(Color::TRANSPARENT)),
+            );
+        }
+
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport);
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout, renderer)
+    }
+}
+
+impl<'a, T, Message, Renderer> From<DropTarget<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Clone,
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        drop_target: DropTarget<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(drop_target)
+    }
+}
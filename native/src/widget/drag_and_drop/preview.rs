@@ -0,0 +1,92 @@
+use crate::layout;
+use crate::renderer;
+use crate::{Element, Hasher, Layout, Overlay, Point, Size};
+
+/// The distance between the mouse cursor and a [`Preview`].
+///
+/// A [`Preview`] must never be under the mouse cursor. Otherwise, the widgets
+/// below it would not be able to know where the cursor is.
+const CURSOR_GAP: f32 = 8.0;
+
+/// A floating copy of a dragged [`DragSource`] that follows the mouse cursor.
+///
+/// [`DragSource`]: super::DragSource
+pub struct Preview<'a, 'b, Message, Renderer> {
+    content: &'b Element<'a, Message, Renderer>,
+    size: Size,
+}
+
+impl<'a, 'b, Message, Renderer> Preview<'a, 'b, Message, Renderer> {
+    /// Creates a new [`Preview`] of the given content, which will be laid out
+    /// with the given maximum size.
+    pub fn new(
+        content: &'b Element<'a, Message, Renderer>,
+        size: Size,
+    ) -> Self {
+        Preview { content, size }
+    }
+}
+
+impl<'a, 'b, Message, Renderer> Overlay<Message, Renderer>
+    for Preview<'a, 'b, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        bounds: Size,
+        position: Point,
+    ) -> layout::Node {
+        let limits = layout::Limits::new(Size::ZERO, self.size);
+        let mut node = self.content.layout(renderer, &limits);
+        let size = node.size();
+
+        // The preview is placed below and to the right of the cursor, unless
+        // it does not fit in the window
+        let x = if position.x + CURSOR_GAP + size.width <= bounds.width {
+            position.x + CURSOR_GAP
+        } else {
+            position.x - CURSOR_GAP - size.width
+        };
+
+        let y = if position.y + CURSOR_GAP + size.height <= bounds.height {
+            position.y + CURSOR_GAP
+        } else {
+            position.y - CURSOR_GAP - size.height
+        };
+
+        node.move_to(Point::new(x.max(0.0), y.max(0.0)));
+
+        node
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) {
+        self.content.draw(
+            renderer,
+            style,
+            layout,
+            cursor_position,
+            &layout.bounds(),
+        );
+    }
+
+    fn hash_layout(&self, state: &mut Hasher, position: Point) {
+        use std::hash::Hash;
+
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        (position.x as u32).hash(state);
+        (position.y as u32).hash(state);
+        (self.size.width as u32).hash(state);
+        (self.size.height as u32).hash(state);
+        self.content.hash_layout(state);
+    }
+}
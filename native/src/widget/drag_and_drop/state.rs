@@ -0,0 +1,70 @@
+/// The state of the drag and drop interactions of a user interface.
+///
+/// It keeps track of the payload of the [`DragSource`] being dragged, which
+/// every [`DropTarget`] needs to know about. Its [`Event`] messages must be
+/// fed to [`State::update`].
+///
+/// [`DragSource`]: super::DragSource
+/// [`DropTarget`]: super::DropTarget
+#[derive(Debug, Clone)]
+pub struct State<T> {
+    dragged: Option<T>,
+}
+
+/// An interaction with a [`DragSource`].
+///
+/// [`DragSource`]: super::DragSource
+#[derive(Debug, Clone, PartialEq)]
+pub enum Event<T> {
+    /// The [`DragSource`] with the given payload started being dragged.
+    ///
+    /// [`DragSource`]: super::DragSource
+    Picked(T),
+
+    /// The dragged [`DragSource`] was released.
+    ///
+    /// If it was released over a [`DropTarget`] accepting its payload, the
+    /// [`DropTarget`] has produced a drop message as well.
+    ///
+    /// [`DragSource`]: super::DragSource
+    /// [`DropTarget`]: super::DropTarget
+    Released,
+
+    /// The drag was canceled by pressing the `Escape` key.
+    Canceled,
+}
+
+impl<T> State<T> {
+    /// Creates a new [`State`] with nothing being dragged.
+    pub fn new() -> Self {
+        State { dragged: None }
+    }
+
+    /// Returns the payload of the [`DragSource`] being dragged, if any.
+    ///
+    /// [`DragSource`]: super::DragSource
+    pub fn dragged(&self) -> Option<&T> {
+        self.dragged.as_ref()
+    }
+
+    /// Returns whether something is being dragged or not.
+    pub fn is_dragging(&self) -> bool {
+        self.dragged.is_some()
+    }
+
+    /// Processes an [`Event`] produced by a [`DragSource`].
+    ///
+    /// [`DragSource`]: super::DragSource
+    pub fn update(&mut self, event: Event<T>) {
+        self.dragged = match event {
+            Event::Picked(payload) => Some(payload),
+            Event::Released | Event::Canceled => None,
+        };
+    }
+}
+
+impl<T> Default for State<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -2,15 +2,29 @@
 pub mod viewer;
 pub use viewer::Viewer;
 
+use crate::alignment;
+use crate::event;
 use crate::image;
 use crate::layout;
 use crate::renderer;
-use crate::{Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::window;
+use crate::{
+    Clipboard, ContentFit, Element, Event, Hasher, Layout, Length, Point,
+    Rectangle, Shell, Size, Widget,
+};
 
 use std::hash::Hash;
+use std::time::{Duration, Instant};
 
 /// A frame that displays an image while keeping aspect ratio.
 ///
+/// How the image fits its bounds can be changed with a [`ContentFit`], and
+/// how it is scaled with an [`image::FilterMethod`].
+///
+/// Only the first frame of an animated image is shown. It can be played in a
+/// loop by turning the [`Image`] into a [`Playback`] with some local
+/// [`State`].
+///
 /// # Example
 ///
 /// ```
@@ -26,6 +40,10 @@ pub struct Image<Handle> {
     handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+    filter_method: image::FilterMethod,
 }
 
 impl<Handle> Image<Handle> {
@@ -35,6 +53,10 @@ impl<Handle> Image<Handle> {
             handle: handle.into(),
             width: Length::Shrink,
             height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
+            filter_method: image::FilterMethod::default(),
         }
     }
 
@@ -49,6 +71,257 @@ impl<Handle> Image<Handle> {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Image`].
+    ///
+    /// Defaults to [`ContentFit::Contain`].
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the horizontal alignment of the image within the boundaries of
+    /// the [`Image`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the image within the boundaries of the
+    /// [`Image`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
+
+    /// Sets the [`image::FilterMethod`] used to scale the [`Image`].
+    ///
+    /// Defaults to [`image::FilterMethod::Linear`].
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
+    /// Plays the [`Image`] with the given [`State`], if it is animated.
+    ///
+    /// The [`State`] can be used to pause, resume, and seek the animation.
+    pub fn playback(self, state: &mut State) -> Playback<'_, Handle> {
+        Playback { image: self, state }
+    }
+
+    fn draw_frame<Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        layout: Layout<'_>,
+        frame: usize,
+    ) where
+        Renderer: image::Renderer<Handle = Handle>,
+        Handle: Clone,
+    {
+        let bounds = layout.bounds();
+
+        let content_bounds = content_bounds(
+            bounds,
+            renderer.dimensions(&self.handle),
+            self.content_fit,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+        );
+
+        let render = |renderer: &mut Renderer| {
+            renderer.draw_frame(
+                self.handle.clone(),
+                frame,
+                self.filter_method,
+                content_bounds,
+            );
+        };
+
+        if content_bounds.width > bounds.width
+            || content_bounds.height > bounds.height
+        {
+            renderer.with_layer(bounds, render);
+        } else {
+            render(renderer);
+        }
+    }
+}
+
+/// An animated [`Image`] played in a loop with some local [`State`].
+///
+/// A [`Playback`] can be created with [`Image::playback`].
+#[derive(Debug)]
+pub struct Playback<'a, Handle> {
+    image: Image<Handle>,
+    state: &'a mut State,
+}
+
+/// The playback state of an animated [`Image`].
+#[derive(Debug, Clone, Copy, Default, Hash)]
+pub struct State {
+    frame: usize,
+    elapsed: Duration,
+    is_paused: bool,
+    last_tick: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`], playing from the first frame.
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns the index of the frame currently shown.
+    pub fn frame(&self) -> usize {
+        self.frame
+    }
+
+    /// Returns whether the animation is paused.
+    pub fn is_paused(&self) -> bool {
+        self.is_paused
+    }
+
+    /// Pauses the animation on its current frame.
+    pub fn pause(&mut self) {
+        self.is_paused = true;
+        self.last_tick = None;
+    }
+
+    /// Resumes the animation from its current frame.
+    pub fn resume(&mut self) {
+        self.is_paused = false;
+    }
+
+    /// Shows the given frame of the animation.
+    ///
+    /// The index wraps around the amount of frames of the image.
+    pub fn seek(&mut self, frame: usize) {
+        self.frame = frame;
+        self.elapsed = Duration::ZERO;
+    }
+
+    /// Advances the animation with the given frame delays up to `now` and
+    /// returns when the next frame should be shown, if ever.
+    fn advance(
+        &mut self,
+        delays: &[Duration],
+        now: Instant,
+    ) -> Option<Instant> {
+        if self.is_paused || delays.len() < 2 {
+            self.last_tick = None;
+
+            return None;
+        }
+
+        if let Some(last_tick) = self.last_tick {
+            self.elapsed += now.saturating_duration_since(last_tick);
+        }
+
+        self.last_tick = Some(now);
+        self.frame %= delays.len();
+
+        let total: Duration = delays.iter().copied().map(frame_delay).sum();
+
+        if self.elapsed >= total {
+            self.elapsed = Duration::from_nanos(
+                (self.elapsed.as_nanos() % total.as_nanos()) as u64,
+            );
+        }
+
+        loop {
+            let delay = frame_delay(delays[self.frame]);
+
+            if self.elapsed < delay {
+                return Some(now + (delay - self.elapsed));
+            }
+
+            self.elapsed -= delay;
+            self.frame = (self.frame + 1) % delays.len();
+        }
+    }
+}
+
+/// Returns the effective delay of a frame.
+///
+/// Like web browsers do, very short delays are treated as 100 ms, since many
+/// animated images rely on it.
+fn frame_delay(delay: Duration) -> Duration {
+    if delay <= Duration::from_millis(10) {
+        Duration::from_millis(100)
+    } else {
+        delay
+    }
+}
+
+/// Computes the layout of some content with the given dimensions, resized
+/// with a [`ContentFit`] to fit the given [`Length`]s and [`layout::Limits`].
+pub(crate) fn layout(
+    limits: &layout::Limits,
+    (width, height): (u32, u32),
+    content_fit: ContentFit,
+    widget_width: Length,
+    widget_height: Length,
+) -> layout::Node {
+    let image_size = Size::new(width as f32, height as f32);
+
+    let raw_size = limits
+        .width(widget_width)
+        .height(widget_height)
+        .resolve(image_size);
+
+    let full_size = content_fit.fit(image_size, raw_size);
+
+    // A shrinking dimension wraps the fitted content
+    let final_size = Size {
+        width: match widget_width {
+            Length::Shrink => raw_size.width.min(full_size.width),
+            _ => raw_size.width,
+        },
+        height: match widget_height {
+            Length::Shrink => raw_size.height.min(full_size.height),
+            _ => raw_size.height,
+        },
+    };
+
+    layout::Node::new(final_size)
+}
+
+/// Returns the bounds where some content with the given dimensions is drawn
+/// in the given bounds, once resized with a [`ContentFit`] and aligned.
+///
+/// The content may overflow the bounds, in which case it needs to be clipped.
+pub(crate) fn content_bounds(
+    bounds: Rectangle,
+    (width, height): (u32, u32),
+    content_fit: ContentFit,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
+) -> Rectangle {
+    let size =
+        content_fit.fit(Size::new(width as f32, height as f32), bounds.size());
+
+    let x = match horizontal_alignment {
+        alignment::Horizontal::Left => bounds.x,
+        alignment::Horizontal::Center => {
+            bounds.x + (bounds.width - size.width) / 2.0
+        }
+        alignment::Horizontal::Right => bounds.x + bounds.width - size.width,
+    };
+
+    let y = match vertical_alignment {
+        alignment::Vertical::Top => bounds.y,
+        alignment::Vertical::Center => {
+            bounds.y + (bounds.height - size.height) / 2.0
+        }
+        alignment::Vertical::Bottom => bounds.y + bounds.height - size.height,
+    };
+
+    Rectangle {
+        x,
+        y,
+        width: size.width,
+        height: size.height,
+    }
 }
 
 impl<Message, Renderer, Handle> Widget<Message, Renderer> for Image<Handle>
@@ -69,24 +342,13 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let (width, height) = renderer.dimensions(&self.handle);
-
-        let aspect_ratio = width as f32 / height as f32;
-
-        let mut size = limits
-            .width(self.width)
-            .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
-
-        let viewport_aspect_ratio = size.width / size.height;
-
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
-        }
-
-        layout::Node::new(size)
+        layout(
+            limits,
+            renderer.dimensions(&self.handle),
+            self.content_fit,
+            self.width,
+            self.height,
+        )
     }
 
     fn draw(
@@ -97,7 +359,7 @@ where
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) {
-        renderer.draw(self.handle.clone(), layout.bounds());
+        self.draw_frame(renderer, layout, 0);
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -107,6 +369,7 @@ where
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.content_fit.hash(state);
     }
 }
 
@@ -120,3 +383,139 @@ where
         Element::new(image)
     }
 }
+
+impl<'a, Message, Renderer, Handle> Widget<Message, Renderer>
+    for Playback<'a, Handle>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + Hash,
+{
+    fn width(&self) -> Length {
+        self.image.width
+    }
+
+    fn height(&self) -> Length {
+        self.image.height
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        <Image<Handle> as Widget<Message, Renderer>>::layout(
+            &self.image,
+            renderer,
+            limits,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            let delays = renderer.frame_delays(&self.image.handle);
+
+            if let Some(at) = self.state.advance(&delays, now) {
+                shell.request_redraw(window::RedrawRequest::At(at));
+            }
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        self.image.draw_frame(renderer, layout, self.state.frame);
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        <Image<Handle> as Widget<Message, Renderer>>::hash_layout(
+            &self.image,
+            state,
+        )
+    }
+}
+
+impl<'a, Message, Renderer, Handle> From<Playback<'a, Handle>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: image::Renderer<Handle = Handle>,
+    Handle: Clone + Hash + 'a,
+{
+    fn from(playback: Playback<'a, Handle>) -> Element<'a, Message, Renderer> {
+        Element::new(playback)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn loops_through_frames() {
+        let delays = [
+            Duration::from_millis(50),
+            Duration::from_millis(0),
+            Duration::from_millis(30),
+        ];
+        let start = Instant::now();
+
+        let mut state = State::new();
+
+        assert_eq!(
+            state.advance(&delays, start),
+            Some(start + Duration::from_millis(50))
+        );
+        assert_eq!(state.frame(), 0);
+
+        let now = start + Duration::from_millis(60);
+
+        assert_eq!(
+            state.advance(&delays, now),
+            Some(now + Duration::from_millis(90))
+        );
+        assert_eq!(state.frame(), 1);
+
+        let now = start + Duration::from_millis(190);
+
+        assert_eq!(
+            state.advance(&delays, now),
+            Some(now + Duration::from_millis(40))
+        );
+        assert_eq!(state.frame(), 0);
+    }
+
+    #[test]
+    fn pauses_and_seeks() {
+        let delays = [Duration::from_millis(50); 4];
+        let start = Instant::now();
+
+        let mut state = State::new();
+        state.seek(6);
+        state.pause();
+
+        assert_eq!(state.advance(&delays, start), None);
+        assert_eq!(state.frame(), 6);
+
+        state.resume();
+
+        let _ = state.advance(&delays, start);
+        assert_eq!(state.frame(), 2);
+
+        let _ = state.advance(&delays, start + Duration::from_millis(100));
+        assert_eq!(state.frame(), 0);
+    }
+}
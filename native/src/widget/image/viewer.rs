@@ -21,6 +21,7 @@ pub struct Viewer<'a, Handle> {
     min_scale: f32,
     max_scale: f32,
     scale_step: f32,
+    filter_method: image::FilterMethod,
     handle: Handle,
 }
 
@@ -35,6 +36,7 @@ impl<'a, Handle> Viewer<'a, Handle> {
             min_scale: 0.25,
             max_scale: 10.0,
             scale_step: 0.10,
+            filter_method: image::FilterMethod::default(),
             handle,
         }
     }
@@ -82,6 +84,15 @@ impl<'a, Handle> Viewer<'a, Handle> {
         self
     }
 
+    /// Sets the [`image::FilterMethod`] used to scale the image of the
+    /// [`Viewer`].
+    ///
+    /// Default is [`image::FilterMethod::Linear`]
+    pub fn filter_method(mut self, filter_method: image::FilterMethod) -> Self {
+        self.filter_method = filter_method;
+        self
+    }
+
     /// Returns the bounds of the underlying image, given the bounds of
     /// the [`Viewer`]. Scaling will be applied and original aspect ratio
     /// will be respected.
@@ -326,6 +337,7 @@ where
                 image::Renderer::draw(
                     renderer,
                     self.handle.clone(),
+                    self.filter_method,
                     Rectangle {
                         x: bounds.x,
                         y: bounds.y,
@@ -0,0 +1,380 @@
+//! Display rich text written in Markdown.
+mod paragraph;
+mod parser;
+mod quote;
+
+use crate::image;
+use crate::text;
+use crate::widget::{container, rule};
+use crate::widget::{Column, Container, Image, Row, Rule, Text};
+use crate::{Color, Element, Length};
+
+use paragraph::Paragraph;
+use parser::Block;
+use quote::Quote;
+
+use std::path::PathBuf;
+use std::rc::Rc;
+
+pub use iced_style::markdown::{Style, StyleSheet};
+
+/// A document written in [CommonMark] Markdown, displaying its headings,
+/// emphasis, code, lists, block quotes, rules, links, and images.
+///
+/// Clicking a link produces a message with its destination. The text of the
+/// document wraps to its width, so it can be placed in a
+/// [`Scrollable`](crate::widget::Scrollable) to display long documents.
+///
+/// # Example
+///
+/// ```
+/// # type Markdown<'a, Message> =
+/// #     iced_native::widget::Markdown<'a, Message, iced_native::renderer::Null>;
+/// #
+/// use iced_native::widget::markdown;
+///
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     LinkClicked(String),
+/// }
+///
+/// let state = markdown::State::new("# Hello\n\nSome *emphasized* text.");
+///
+/// let markdown = Markdown::new(&state, Message::LinkClicked);
+/// ```
+///
+/// [CommonMark]: https://commonmark.org
+#[allow(missing_debug_implementations)]
+pub struct Markdown<'a, Message, Renderer: text::Renderer> {
+    state: &'a State,
+    on_link: Rc<dyn Fn(String) -> Message>,
+    width: Length,
+    spacing: u16,
+    text_size: Option<u16>,
+    fonts: Fonts<Renderer::Font>,
+    image_directory: Option<PathBuf>,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> Markdown<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// Creates a new [`Markdown`] displaying the document of the given
+    /// [`State`].
+    ///
+    /// It expects a function that produces a message when a link with the
+    /// given destination is clicked.
+    pub fn new<F>(state: &'a State, on_link: F) -> Self
+    where
+        F: 'static + Fn(String) -> Message,
+    {
+        Markdown {
+            state,
+            on_link: Rc::new(on_link),
+            width: Length::Fill,
+            spacing: 10,
+            text_size: None,
+            fonts: Fonts {
+                regular: Renderer::Font::default(),
+                bold: Renderer::bold_font(),
+                italic: Renderer::italic_font(),
+                monospace: Renderer::monospace_font(),
+            },
+            image_directory: None,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the width of the [`Markdown`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the blocks of the [`Markdown`].
+    pub fn spacing(mut self, units: u16) -> Self {
+        self.spacing = units;
+        self
+    }
+
+    /// Sets the text size of the [`Markdown`]. Headings are scaled up from
+    /// it.
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the regular text of the [`Markdown`].
+    pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.fonts.regular = font.into();
+        self
+    }
+
+    /// Sets the font used for strong text and headings.
+    ///
+    /// By default, the [`bold_font`] of the renderer is used.
+    ///
+    /// [`bold_font`]: text::Renderer::bold_font
+    pub fn bold_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.fonts.bold = font.into();
+        self
+    }
+
+    /// Sets the font used for emphasized text.
+    ///
+    /// By default, the [`italic_font`] of the renderer is used.
+    ///
+    /// [`italic_font`]: text::Renderer::italic_font
+    pub fn italic_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.fonts.italic = font.into();
+        self
+    }
+
+    /// Sets the font used for inline code and code blocks.
+    ///
+    /// By default, the [`monospace_font`] of the renderer is used.
+    ///
+    /// [`monospace_font`]: text::Renderer::monospace_font
+    pub fn monospace_font(mut self, font: impl Into<Renderer::Font>) -> Self {
+        self.fonts.monospace = font.into();
+        self
+    }
+
+    /// Sets the directory that the relative paths of images are resolved
+    /// against.
+    ///
+    /// By default, they are resolved against the current working directory.
+    pub fn image_directory(mut self, directory: impl Into<PathBuf>) -> Self {
+        self.image_directory = Some(directory.into());
+        self
+    }
+
+    /// Sets the style of the [`Markdown`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+}
+
+/// The parsed document of a [`Markdown`].
+#[derive(Debug, Clone)]
+pub struct State {
+    blocks: Vec<Block>,
+}
+
+impl State {
+    /// Creates a new [`State`] by parsing the given Markdown source.
+    pub fn new(source: &str) -> Self {
+        State {
+            blocks: parser::parse(source),
+        }
+    }
+}
+
+/// The fonts of the different kinds of text of a [`Markdown`].
+#[derive(Debug, Clone)]
+struct Fonts<Font> {
+    regular: Font,
+    bold: Font,
+    italic: Font,
+    monospace: Font,
+}
+
+/// The settings shared by all the blocks of a [`Markdown`].
+struct Context<Message, Renderer: text::Renderer> {
+    on_link: Rc<dyn Fn(String) -> Message>,
+    spacing: u16,
+    text_size: Option<u16>,
+    fonts: Fonts<Renderer::Font>,
+    image_directory: Option<PathBuf>,
+    style: Style,
+}
+
+impl<'a, Message, Renderer> Context<Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + image::Renderer<Handle = image::Handle> + 'a,
+{
+    fn column(
+        &self,
+        blocks: &'a [Block],
+        color: Option<Color>,
+    ) -> Column<'a, Message, Renderer> {
+        blocks.iter().fold(
+            Column::new().width(Length::Fill).spacing(self.spacing),
+            |column, block| column.push(self.block(block, color)),
+        )
+    }
+
+    fn paragraph(
+        &self,
+        inline: &'a parser::Inline,
+        color: Option<Color>,
+        scale: f32,
+        is_strong: bool,
+    ) -> Element<'a, Message, Renderer> {
+        Paragraph {
+            inline,
+            text_size: self.text_size,
+            scale,
+            is_strong,
+            color,
+            fonts: self.fonts.clone(),
+            style: self.style,
+            on_link: Rc::clone(&self.on_link),
+        }
+        .into()
+    }
+
+    fn block(
+        &self,
+        block: &'a Block,
+        color: Option<Color>,
+    ) -> Element<'a, Message, Renderer> {
+        match block {
+            Block::Paragraph(inline) => {
+                self.paragraph(inline, color, 1.0, false)
+            }
+            Block::Heading(level, inline) => {
+                let scale = match level {
+                    1 => 2.0,
+                    2 => 1.6,
+                    3 => 1.3,
+                    4 => 1.15,
+                    _ => 1.0,
+                };
+
+                self.paragraph(inline, color, scale, true)
+            }
+            Block::Code(code) => {
+                let mut text = Text::new(code.as_str())
+                    .font(self.fonts.monospace.clone())
+                    .width(Length::Fill);
+
+                if let Some(size) = self.text_size {
+                    text = text.size(size);
+                }
+
+                if let Some(color) = self.style.code_color.or(color) {
+                    text = text.color(color);
+                }
+
+                Container::new(text)
+                    .width(Length::Fill)
+                    .padding(10)
+                    .style(CodeBlock(self.style))
+                    .into()
+            }
+            Block::List { start, items } => {
+                let list = items.iter().enumerate().fold(
+                    Column::new().width(Length::Fill).spacing(self.spacing / 2),
+                    |list, (i, item)| {
+                        let marker = match start {
+                            Some(start) => format!("{}.", start + i as u64),
+                            None => String::from("•"),
+                        };
+
+                        let mut marker =
+                            Text::new(marker).font(self.fonts.regular.clone());
+
+                        if let Some(size) = self.text_size {
+                            marker = marker.size(size);
+                        }
+
+                        if let Some(color) = color {
+                            marker = marker.color(color);
+                        }
+
+                        list.push(
+                            Row::new()
+                                .width(Length::Fill)
+                                .spacing(self.spacing / 2)
+                                .push(marker)
+                                .push(self.column(item, color)),
+                        )
+                    },
+                );
+
+                list.into()
+            }
+            Block::Quote(blocks) => {
+                let color = self.style.quote_color.or(color);
+
+                Quote {
+                    content: self.column(blocks, color).into(),
+                    style: self.style,
+                }
+                .into()
+            }
+            Block::Rule => Rule::horizontal(self.spacing)
+                .style(Separator(self.style))
+                .into(),
+            Block::Image(path) => {
+                let path = match &self.image_directory {
+                    Some(directory) => directory.join(path),
+                    None => PathBuf::from(path),
+                };
+
+                Image::new(image::Handle::from_path(path)).into()
+            }
+        }
+    }
+}
+
+impl<'a, Message, Renderer> From<Markdown<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + image::Renderer<Handle = image::Handle> + 'a,
+{
+    fn from(
+        markdown: Markdown<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        let style = markdown.style_sheet.style();
+
+        let context = Context {
+            on_link: markdown.on_link,
+            spacing: markdown.spacing,
+            text_size: markdown.text_size,
+            fonts: markdown.fonts,
+            image_directory: markdown.image_directory,
+            style,
+        };
+
+        context
+            .column(&markdown.state.blocks, style.text_color)
+            .width(markdown.width)
+            .into()
+    }
+}
+
+/// The style of the code blocks of a [`Markdown`].
+struct CodeBlock(Style);
+
+impl container::StyleSheet for CodeBlock {
+    fn style(&self) -> container::Style {
+        container::Style {
+            background: Some(self.0.code_background.into()),
+            border_radius: self.0.code_border_radius,
+            ..container::Style::default()
+        }
+    }
+}
+
+/// The style of the rules of a [`Markdown`].
+struct Separator(Style);
+
+impl rule::StyleSheet for Separator {
+    fn style(&self) -> rule::Style {
+        rule::Style {
+            color: self.0.rule_color,
+            width: 1,
+            radius: 0.0,
+            fill_mode: rule::FillMode::Full,
+        }
+    }
+}
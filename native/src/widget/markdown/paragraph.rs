@@ -0,0 +1,366 @@
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Decoration};
+use crate::touch;
+use crate::widget::markdown::parser::{Emphasis, Fragment, Inline};
+use crate::widget::markdown::{Fonts, Style};
+use crate::{
+    alignment, Clipboard, Color, Element, Hasher, Layout, Length, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+use std::hash::Hash;
+use std::rc::Rc;
+
+/// A paragraph of [`Inline`] content, whose words are wrapped one by one so
+/// they can have different fonts and be links.
+pub(super) struct Paragraph<'a, Message, Renderer: text::Renderer> {
+    pub inline: &'a Inline,
+    pub text_size: Option<u16>,
+
+    /// The factor applied to the text size, used by headings.
+    pub scale: f32,
+
+    /// Whether every run is drawn with the bold font.
+    pub is_strong: bool,
+
+    pub color: Option<Color>,
+    pub fonts: Fonts<Renderer::Font>,
+    pub style: Style,
+    pub on_link: Rc<dyn Fn(String) -> Message>,
+}
+
+impl<'a, Message, Renderer> Paragraph<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn size(&self, renderer: &Renderer) -> u16 {
+        let size = self.text_size.unwrap_or(renderer.default_size());
+
+        (f32::from(size) * self.scale).round() as u16
+    }
+
+    fn font(&self, emphasis: Emphasis) -> Renderer::Font {
+        let font = if emphasis.code {
+            &self.fonts.monospace
+        } else if emphasis.strong || self.is_strong {
+            &self.fonts.bold
+        } else if emphasis.italic {
+            &self.fonts.italic
+        } else {
+            &self.fonts.regular
+        };
+
+        font.clone()
+    }
+
+    /// Returns the index of the link under the cursor, if any.
+    fn hovered_link(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+    ) -> Option<usize> {
+        self.inline
+            .runs
+            .iter()
+            .zip(layout.children())
+            .find(|(run, layout)| {
+                run.link.is_some() && layout.bounds().contains(cursor_position)
+            })
+            .and_then(|(run, _)| run.link)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Paragraph<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(Length::Fill).height(Length::Shrink);
+        let max_width = limits.max().width;
+        let text_size = self.size(renderer);
+
+        let measure = |content: &str, font, bounds| {
+            renderer.measure(
+                content,
+                text_size,
+                font,
+                bounds,
+                text::Format::default(),
+            )
+        };
+
+        let (_, space_height) =
+            measure(" ", self.fonts.regular.clone(), Size::INFINITY);
+
+        let mut children: Vec<layout::Node> =
+            Vec::with_capacity(self.inline.runs.len());
+
+        let mut line_start = 0;
+        let mut line_height: f32 = 0.0;
+        let mut position = Point::ORIGIN;
+        let mut width: f32 = 0.0;
+
+        for run in &self.inline.runs {
+            let font = self.font(run.emphasis);
+
+            let size = match &run.fragment {
+                Fragment::Break => {
+                    let height = line_height.max(space_height);
+
+                    finish_line(
+                        &mut children[line_start..],
+                        position.y,
+                        height,
+                    );
+
+                    position = Point::new(0.0, position.y + height);
+                    line_height = 0.0;
+                    line_start = children.len() + 1;
+
+                    Size::ZERO
+                }
+                // Spaces do not take room at the start of a line
+                Fragment::Space if position.x == 0.0 => Size::ZERO,
+                Fragment::Space => {
+                    let (width, _) = measure(" ", font, Size::INFINITY);
+
+                    Size::new(width, space_height)
+                }
+                Fragment::Word(word) => {
+                    let (word_width, _) =
+                        measure(word, font.clone(), Size::INFINITY);
+
+                    if position.x > 0.0 && position.x + word_width > max_width {
+                        // Nor do they at its end
+                        for (node, _) in children[line_start..]
+                            .iter_mut()
+                            .zip(&self.inline.runs[line_start..])
+                            .rev()
+                            .take_while(|(_, run)| {
+                                run.fragment == Fragment::Space
+                            })
+                        {
+                            let bounds = node.bounds();
+
+                            *node = layout::Node::new(Size::ZERO);
+                            node.move_to(bounds.position());
+                        }
+
+                        finish_line(
+                            &mut children[line_start..],
+                            position.y,
+                            line_height,
+                        );
+
+                        position = Point::new(0.0, position.y + line_height);
+                        line_height = 0.0;
+                        line_start = children.len();
+                    }
+
+                    // Words wider than a whole line wrap at any character
+                    let bounds = if word_width > max_width {
+                        Size::new(max_width, f32::INFINITY)
+                    } else {
+                        Size::INFINITY
+                    };
+
+                    let (word_width, word_height) = renderer.measure(
+                        word,
+                        text_size,
+                        font,
+                        bounds,
+                        text::Format {
+                            wrapping: text::Wrapping::Glyph,
+                            ..text::Format::default()
+                        },
+                    );
+
+                    line_height = line_height.max(word_height);
+
+                    Size::new(word_width, word_height)
+                }
+            };
+
+            let mut node = layout::Node::new(size);
+            node.move_to(position);
+
+            position.x += size.width;
+            width = width.max(position.x);
+
+            children.push(node);
+        }
+
+        let line_start = line_start.min(children.len());
+        finish_line(&mut children[line_start..], position.y, line_height);
+
+        let size = limits.resolve(Size::new(width, position.y + line_height));
+
+        layout::Node::with_children(size, children)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let link = self
+                    .hovered_link(layout, cursor_position)
+                    .and_then(|link| self.inline.links.get(link));
+
+                if let Some(link) = link {
+                    shell.publish((self.on_link)(link.clone()));
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.hovered_link(layout, cursor_position).is_some() {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let size = f32::from(self.size(renderer));
+        let max_width = layout.bounds().width;
+        let hovered_link = self.hovered_link(layout, cursor_position);
+
+        for (run, layout) in self.inline.runs.iter().zip(layout.children()) {
+            let bounds = layout.bounds();
+
+            let content = match &run.fragment {
+                _ if bounds.width <= 0.0 => continue,
+                Fragment::Word(word) => word.as_str(),
+                Fragment::Space => " ",
+                Fragment::Break => continue,
+            };
+
+            let color = match run.link {
+                Some(_) if run.link == hovered_link => {
+                    self.style.hovered_link_color
+                }
+                Some(_) => self.style.link_color,
+                None if run.emphasis.code => self
+                    .style
+                    .code_color
+                    .or(self.color)
+                    .unwrap_or(style.text_color),
+                None => self.color.unwrap_or(style.text_color),
+            };
+
+            let length = content.chars().count();
+            let mut decorations = Vec::new();
+
+            if run.link.is_some() {
+                decorations.push(Decoration::underline(0..length));
+            }
+
+            if run.emphasis.strikethrough {
+                decorations.push(Decoration::strikethrough(0..length));
+            }
+
+            if run.emphasis.code {
+                decorations.push(Decoration::highlight(
+                    0..length,
+                    self.style.code_background,
+                ));
+            }
+
+            let wrapping = if bounds.width >= max_width {
+                text::Wrapping::Glyph
+            } else {
+                text::Wrapping::None
+            };
+
+            renderer.fill_text(text::Text {
+                content,
+                bounds,
+                size,
+                color,
+                font: self.font(run.emphasis),
+                horizontal_alignment: alignment::Horizontal::Left,
+                vertical_alignment: alignment::Vertical::Top,
+                format: text::Format {
+                    wrapping,
+                    ..text::Format::default()
+                },
+                decorations: &decorations,
+            });
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.inline.runs.hash(state);
+        self.text_size.hash(state);
+        self.scale.to_bits().hash(state);
+        self.is_strong.hash(state);
+    }
+}
+
+/// Aligns the nodes of a line to its bottom.
+fn finish_line(nodes: &mut [layout::Node], top: f32, height: f32) {
+    for node in nodes {
+        let bounds = node.bounds();
+
+        node.move_to(Point::new(bounds.x, top + height - bounds.height));
+    }
+}
+
+impl<'a, Message, Renderer> From<Paragraph<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        paragraph: Paragraph<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(paragraph)
+    }
+}
@@ -0,0 +1,478 @@
+use pulldown_cmark::{Event, Options, Tag};
+
+/// A block of a markdown document.
+#[derive(Debug, Clone, PartialEq)]
+pub(super) enum Block {
+    Paragraph(Inline),
+    Heading(u32, Inline),
+    Code(String),
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<Block>>,
+    },
+    Quote(Vec<Block>),
+    Rule,
+    Image(String),
+}
+
+/// The inline content of a paragraph or a heading, split in runs that can
+/// be wrapped independently.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(super) struct Inline {
+    pub runs: Vec<Run>,
+
+    /// The destinations of the links of the content, referenced by index in
+    /// its runs.
+    pub links: Vec<String>,
+}
+
+/// A word, a space or a line break of some [`Inline`] content.
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub(super) struct Run {
+    pub fragment: Fragment,
+    pub emphasis: Emphasis,
+    pub link: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Hash)]
+pub(super) enum Fragment {
+    Word(String),
+    Space,
+    Break,
+}
+
+/// The inline styles applied to a [`Run`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub(super) struct Emphasis {
+    pub strong: bool,
+    pub italic: bool,
+    pub strikethrough: bool,
+    pub code: bool,
+}
+
+/// Parses CommonMark, with strikethrough support, into a list of blocks.
+pub(super) fn parse(source: &str) -> Vec<Block> {
+    let mut parser = Parser::default();
+
+    for event in
+        pulldown_cmark::Parser::new_ext(source, Options::ENABLE_STRIKETHROUGH)
+    {
+        parser.event(event);
+    }
+
+    parser.flush();
+    parser.root
+}
+
+#[derive(Default)]
+struct Parser {
+    root: Vec<Block>,
+
+    /// The open containers, from the outermost to the innermost.
+    containers: Vec<Container>,
+
+    /// The inline content being parsed, if any.
+    ///
+    /// The items of tight lists contain inline content directly, so a
+    /// paragraph is started implicitly when needed.
+    inline: Option<(Leaf, Inline)>,
+
+    code: Option<String>,
+    image: Option<String>,
+    strong: usize,
+    italic: usize,
+    strikethrough: usize,
+    link: Option<usize>,
+}
+
+enum Container {
+    Quote(Vec<Block>),
+    List {
+        start: Option<u64>,
+        items: Vec<Vec<Block>>,
+    },
+    Item(Vec<Block>),
+}
+
+#[derive(Clone, Copy)]
+enum Leaf {
+    Paragraph,
+    Heading(u32),
+}
+
+impl Parser {
+    fn event(&mut self, event: Event<'_>) {
+        match event {
+            Event::Start(tag) => self.start(tag),
+            Event::End(tag) => self.end(tag),
+            Event::Text(text) => {
+                if let Some(code) = &mut self.code {
+                    code.push_str(&text);
+                } else if self.image.is_none() {
+                    self.text(&text, false);
+                }
+            }
+            Event::Code(text) => {
+                if self.image.is_none() {
+                    self.text(&text, true);
+                }
+            }
+            Event::SoftBreak => self.run(Fragment::Space),
+            Event::HardBreak => self.run(Fragment::Break),
+            Event::Rule => self.push(Block::Rule),
+            Event::Html(_)
+            | Event::FootnoteReference(_)
+            | Event::TaskListMarker(_) => {}
+        }
+    }
+
+    fn start(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph => {
+                self.flush();
+                self.inline = Some((Leaf::Paragraph, Inline::default()));
+            }
+            Tag::Heading(level) => {
+                self.flush();
+                self.inline = Some((Leaf::Heading(level), Inline::default()));
+            }
+            Tag::BlockQuote => {
+                self.flush();
+                self.containers.push(Container::Quote(Vec::new()));
+            }
+            Tag::CodeBlock(_) => {
+                self.flush();
+                self.code = Some(String::new());
+            }
+            Tag::List(start) => {
+                self.flush();
+                self.containers.push(Container::List {
+                    start,
+                    items: Vec::new(),
+                });
+            }
+            Tag::Item => {
+                self.flush();
+                self.containers.push(Container::Item(Vec::new()));
+            }
+            Tag::Emphasis => self.italic += 1,
+            Tag::Strong => self.strong += 1,
+            Tag::Strikethrough => self.strikethrough += 1,
+            Tag::Link(_, destination, _) => {
+                let inline = self.inline();
+                inline.links.push(destination.to_string());
+
+                self.link = Some(inline.links.len() - 1);
+            }
+            Tag::Image(_, destination, _) => {
+                self.image = Some(destination.to_string());
+            }
+            Tag::FootnoteDefinition(_)
+            | Tag::Table(_)
+            | Tag::TableHead
+            | Tag::TableRow
+            | Tag::TableCell => {}
+        }
+    }
+
+    fn end(&mut self, tag: Tag<'_>) {
+        match tag {
+            Tag::Paragraph | Tag::Heading(_) => self.flush(),
+            Tag::BlockQuote => {
+                self.flush();
+
+                if let Some(Container::Quote(blocks)) = self.containers.pop() {
+                    self.blocks().push(Block::Quote(blocks));
+                }
+            }
+            Tag::CodeBlock(_) => {
+                if let Some(mut code) = self.code.take() {
+                    if code.ends_with('\n') {
+                        let _ = code.pop();
+                    }
+
+                    self.blocks().push(Block::Code(code));
+                }
+            }
+            Tag::List(_) => {
+                self.flush();
+
+                if let Some(Container::List { start, items }) =
+                    self.containers.pop()
+                {
+                    self.blocks().push(Block::List { start, items });
+                }
+            }
+            Tag::Item => {
+                self.flush();
+
+                if let Some(Container::Item(blocks)) = self.containers.pop() {
+                    if let Some(Container::List { items, .. }) =
+                        self.containers.last_mut()
+                    {
+                        items.push(blocks);
+                    }
+                }
+            }
+            Tag::Emphasis => self.italic = self.italic.saturating_sub(1),
+            Tag::Strong => self.strong = self.strong.saturating_sub(1),
+            Tag::Strikethrough => {
+                self.strikethrough = self.strikethrough.saturating_sub(1)
+            }
+            Tag::Link(..) => self.link = None,
+            Tag::Image(..) => {
+                if let Some(path) = self.image.take() {
+                    self.split(Block::Image(path));
+                }
+            }
+            Tag::FootnoteDefinition(_)
+            | Tag::Table(_)
+            | Tag::TableHead
+            | Tag::TableRow
+            | Tag::TableCell => {}
+        }
+    }
+
+    /// Returns the blocks of the innermost container.
+    fn blocks(&mut self) -> &mut Vec<Block> {
+        match self.containers.last_mut() {
+            Some(Container::Quote(blocks)) | Some(Container::Item(blocks)) => {
+                blocks
+            }
+            // Lists only contain items
+            Some(Container::List { .. }) | None => &mut self.root,
+        }
+    }
+
+    fn inline(&mut self) -> &mut Inline {
+        &mut self
+            .inline
+            .get_or_insert_with(|| (Leaf::Paragraph, Inline::default()))
+            .1
+    }
+
+    /// Finishes the current inline content, if any.
+    fn flush(&mut self) {
+        if let Some((leaf, inline)) = self.inline.take() {
+            if inline.runs.is_empty() {
+                return;
+            }
+
+            let block = match leaf {
+                Leaf::Paragraph => Block::Paragraph(inline),
+                Leaf::Heading(level) => Block::Heading(level, inline),
+            };
+
+            self.blocks().push(block);
+        }
+    }
+
+    fn push(&mut self, block: Block) {
+        self.flush();
+        self.blocks().push(block);
+    }
+
+    /// Pushes a block in the middle of the current inline content, which
+    /// continues in a new block of the same kind.
+    fn split(&mut self, block: Block) {
+        let leaf = self.inline.as_ref().map(|(leaf, _)| *leaf);
+
+        // The link being parsed, if any, continues after the block
+        let link = self.inline.as_ref().and_then(|(_, inline)| {
+            self.link.and_then(|link| inline.links.get(link).cloned())
+        });
+
+        self.push(block);
+
+        if let Some(leaf) = leaf {
+            self.link = link.as_ref().map(|_| 0);
+            self.inline = Some((
+                leaf,
+                Inline {
+                    runs: Vec::new(),
+                    links: link.into_iter().collect(),
+                },
+            ));
+        }
+    }
+
+    fn run(&mut self, fragment: Fragment) {
+        let emphasis = Emphasis {
+            strong: self.strong > 0,
+            italic: self.italic > 0,
+            strikethrough: self.strikethrough > 0,
+            code: false,
+        };
+
+        let link = self.link;
+
+        self.inline().runs.push(Run {
+            fragment,
+            emphasis,
+            link,
+        });
+    }
+
+    /// Splits the text in words and spaces.
+    ///
+    /// Consecutive whitespace collapses into a single space, except in code.
+    fn text(&mut self, text: &str, code: bool) {
+        let emphasis = Emphasis {
+            strong: self.strong > 0,
+            italic: self.italic > 0,
+            strikethrough: self.strikethrough > 0,
+            code,
+        };
+
+        let link = self.link;
+        let runs = &mut self.inline().runs;
+
+        let mut push = |fragment| {
+            runs.push(Run {
+                fragment,
+                emphasis,
+                link,
+            })
+        };
+
+        let mut word = String::new();
+        let mut is_space = false;
+
+        for c in text.chars() {
+            if c.is_whitespace() {
+                if !word.is_empty() {
+                    push(Fragment::Word(std::mem::take(&mut word)));
+                }
+
+                if code || !is_space {
+                    push(Fragment::Space);
+                }
+
+                is_space = true;
+            } else {
+                word.push(c);
+                is_space = false;
+            }
+        }
+
+        if !word.is_empty() {
+            push(Fragment::Word(word));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn words(inline: &Inline) -> Vec<&str> {
+        inline
+            .runs
+            .iter()
+            .filter_map(|run| match &run.fragment {
+                Fragment::Word(word) => Some(word.as_str()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn parses_blocks() {
+        let blocks = parse(
+            "# Title\n\nSome text.\n\n---\n\n```rust\nfn main() {}\n```\n\n> Quoted",
+        );
+
+        assert_eq!(blocks.len(), 5);
+
+        match &blocks[0] {
+            Block::Heading(1, inline) => assert_eq!(words(inline), ["Title"]),
+            block => panic!("Unexpected block: {:?}", block),
+        }
+
+        assert!(matches!(&blocks[1], Block::Paragraph(_)));
+        assert_eq!(blocks[2], Block::Rule);
+        assert_eq!(blocks[3], Block::Code(String::from("fn main() {}")));
+
+        match &blocks[4] {
+            Block::Quote(blocks) => assert_eq!(blocks.len(), 1),
+            block => panic!("Unexpected block: {:?}", block),
+        }
+    }
+
+    #[test]
+    fn parses_emphasis_and_links() {
+        let blocks =
+            parse("A **bold** _word_ with `some  code` and [a link](url).");
+
+        let inline = match &blocks[..] {
+            [Block::Paragraph(inline)] => inline,
+            blocks => panic!("Unexpected blocks: {:?}", blocks),
+        };
+
+        assert_eq!(
+            words(inline),
+            [
+                "A", "bold", "word", "with", "some", "code", "and", "a",
+                "link", "."
+            ]
+        );
+
+        let emphasis = |word: &str| {
+            inline
+                .runs
+                .iter()
+                .find(|run| run.fragment == Fragment::Word(String::from(word)))
+                .map(|run| (run.emphasis, run.link))
+                .unwrap()
+        };
+
+        assert!(emphasis("bold").0.strong);
+        assert!(emphasis("word").0.italic);
+        assert!(emphasis("code").0.code);
+        assert_eq!(emphasis("link").1, Some(0));
+        assert_eq!(emphasis(".").1, None);
+        assert_eq!(inline.links, ["url"]);
+
+        // Spaces in code are preserved
+        let code_spaces = inline
+            .runs
+            .iter()
+            .filter(|run| run.emphasis.code && run.fragment == Fragment::Space)
+            .count();
+
+        assert_eq!(code_spaces, 2);
+    }
+
+    #[test]
+    fn parses_lists() {
+        let blocks = parse("3. First\n4. Second\n   - Nested\n");
+
+        let items = match &blocks[..] {
+            [Block::List {
+                start: Some(3),
+                items,
+            }] => items,
+            blocks => panic!("Unexpected blocks: {:?}", blocks),
+        };
+
+        assert_eq!(items.len(), 2);
+        assert!(matches!(&items[0][..], [Block::Paragraph(_)]));
+        assert!(matches!(
+            &items[1][..],
+            [Block::Paragraph(_), Block::List { start: None, .. }]
+        ));
+    }
+
+    #[test]
+    fn splits_paragraphs_around_images() {
+        let blocks = parse("Before ![alt *text*](image.png) after");
+
+        assert_eq!(blocks.len(), 3);
+        assert_eq!(blocks[1], Block::Image(String::from("image.png")));
+
+        match &blocks[2] {
+            Block::Paragraph(inline) => assert_eq!(words(inline), ["after"]),
+            block => panic!("Unexpected block: {:?}", block),
+        }
+    }
+}
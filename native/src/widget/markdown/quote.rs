@@ -0,0 +1,153 @@
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::widget::markdown::Style;
+use crate::{
+    Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+use std::hash::Hash;
+
+/// The space between the border of a [`Quote`] and its content.
+const SPACING: f32 = 12.0;
+
+/// A block quote, which draws a border along the left side of its content.
+pub(super) struct Quote<'a, Message, Renderer> {
+    pub content: Element<'a, Message, Renderer>,
+    pub style: Style,
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for Quote<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Fill
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let inset = self.style.quote_border_width + SPACING;
+
+        let limits = limits.width(Length::Fill).height(Length::Shrink);
+
+        let mut content = self
+            .content
+            .layout(renderer, &limits.shrink(Size::new(inset, 0.0)));
+
+        content.move_to(Point::new(inset, 0.0));
+
+        let size = limits.resolve(Size::new(
+            content.size().width + inset,
+            content.size().height,
+        ));
+
+        layout::Node::with_children(size, vec![content])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.content.on_event(
+            event,
+            layout.children().next().unwrap(),
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        )
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        self.content.mouse_interaction(
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+            renderer,
+        )
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds: Rectangle {
+                    width: self.style.quote_border_width,
+                    ..bounds
+                },
+                border_radius: 0.0,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            },
+            self.style.quote_border_color,
+        );
+
+        self.content.draw(
+            renderer,
+            style,
+            layout.children().next().unwrap(),
+            cursor_position,
+            viewport,
+        );
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content
+            .overlay(layout.children().next().unwrap(), renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<Quote<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: crate::Renderer + 'a,
+{
+    fn from(
+        quote: Quote<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(quote)
+    }
+}
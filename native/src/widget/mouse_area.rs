@@ -0,0 +1,522 @@
+//! Produce messages from the mouse interactions with some content.
+//!
+//! A [`MouseArea`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse::{self, click};
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use std::hash::Hash;
+
+/// A container that produces messages when the mouse interacts with its
+/// content.
+///
+/// Every message of a [`MouseArea`] is optional. The events it handles are
+/// only captured when it produces a message for them, so its content keeps
+/// working as usual.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{mouse_area, Text};
+/// #
+/// # type MouseArea<'a, Message> =
+/// #     iced_native::widget::MouseArea<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Clone)]
+/// enum Message {
+///     Selected,
+///     ContextMenuOpened,
+/// }
+///
+/// let mut state = mouse_area::State::new();
+/// let mouse_area = MouseArea::new(&mut state, Text::new("Right-click me!"))
+///     .on_press(Message::Selected)
+///     .on_right_press(Message::ContextMenuOpened);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct MouseArea<'a, Message, Renderer> {
+    state: &'a mut State,
+    content: Element<'a, Message, Renderer>,
+    on_press: Option<Message>,
+    on_release: Option<Message>,
+    on_right_press: Option<Message>,
+    on_right_release: Option<Message>,
+    on_middle_press: Option<Message>,
+    on_middle_release: Option<Message>,
+    on_double_click: Option<Message>,
+    on_enter: Option<Message>,
+    on_exit: Option<Message>,
+    on_move: Option<Box<dyn Fn(Point) -> Message>>,
+    on_scroll: Option<Box<dyn Fn(mouse::ScrollDelta) -> Message>>,
+    interaction: Option<mouse::Interaction>,
+}
+
+impl<'a, Message, Renderer> MouseArea<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    /// Creates a new [`MouseArea`] with some local [`State`] and the given
+    /// content.
+    pub fn new<E>(state: &'a mut State, content: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        MouseArea {
+            state,
+            content: content.into(),
+            on_press: None,
+            on_release: None,
+            on_right_press: None,
+            on_right_release: None,
+            on_middle_press: None,
+            on_middle_release: None,
+            on_double_click: None,
+            on_enter: None,
+            on_exit: None,
+            on_move: None,
+            on_scroll: None,
+            interaction: None,
+        }
+    }
+
+    /// Sets the message that will be produced when the left mouse button is
+    /// pressed over the [`MouseArea`].
+    pub fn on_press(mut self, message: Message) -> Self {
+        self.on_press = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the left mouse button is
+    /// released over the [`MouseArea`].
+    pub fn on_release(mut self, message: Message) -> Self {
+        self.on_release = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the right mouse button is
+    /// pressed over the [`MouseArea`].
+    pub fn on_right_press(mut self, message: Message) -> Self {
+        self.on_right_press = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the right mouse button is
+    /// released over the [`MouseArea`].
+    pub fn on_right_release(mut self, message: Message) -> Self {
+        self.on_right_release = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the middle mouse button
+    /// is pressed over the [`MouseArea`].
+    pub fn on_middle_press(mut self, message: Message) -> Self {
+        self.on_middle_press = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the middle mouse button
+    /// is released over the [`MouseArea`].
+    pub fn on_middle_release(mut self, message: Message) -> Self {
+        self.on_middle_release = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the [`MouseArea`] is
+    /// double-clicked with the left mouse button.
+    ///
+    /// The message set with [`MouseArea::on_press`] is still produced for
+    /// both clicks.
+    pub fn on_double_click(mut self, message: Message) -> Self {
+        self.on_double_click = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the mouse cursor enters
+    /// the [`MouseArea`].
+    pub fn on_enter(mut self, message: Message) -> Self {
+        self.on_enter = Some(message);
+        self
+    }
+
+    /// Sets the message that will be produced when the mouse cursor exits
+    /// the [`MouseArea`].
+    pub fn on_exit(mut self, message: Message) -> Self {
+        self.on_exit = Some(message);
+        self
+    }
+
+    /// Sets the function that will be called when the mouse cursor moves
+    /// over the [`MouseArea`].
+    ///
+    /// It receives the position of the cursor relative to the top-left
+    /// corner of the [`MouseArea`].
+    pub fn on_move(
+        mut self,
+        on_move: impl Fn(Point) -> Message + 'static,
+    ) -> Self {
+        self.on_move = Some(Box::new(on_move));
+        self
+    }
+
+    /// Sets the function that will be called when the mouse wheel is
+    /// scrolled over the [`MouseArea`].
+    pub fn on_scroll(
+        mut self,
+        on_scroll: impl Fn(mouse::ScrollDelta) -> Message + 'static,
+    ) -> Self {
+        self.on_scroll = Some(Box::new(on_scroll));
+        self
+    }
+
+    /// Sets the [`mouse::Interaction`] shown when the mouse cursor is over
+    /// the [`MouseArea`].
+    ///
+    /// The interaction of the content takes precedence, unless it is idle.
+    pub fn interaction(mut self, interaction: mouse::Interaction) -> Self {
+        self.interaction = Some(interaction);
+        self
+    }
+}
+
+/// The local state of a [`MouseArea`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_hovered: bool,
+    last_click: Option<mouse::Click>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether the mouse cursor is over the [`MouseArea`] or not.
+    pub fn is_hovered(&self) -> bool {
+        self.is_hovered
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for MouseArea<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.content.width()
+    }
+
+    fn height(&self) -> Length {
+        self.content.height()
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        self.content.layout(renderer, limits)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let bounds = layout.bounds();
+
+        // Hovering is tracked even when the content captures the movement,
+        // so enter and exit messages always come in pairs.
+        //
+        // The cursor position is used instead of the position of the event,
+        // as it is translated by scrollables and hidden by overlays.
+        match event {
+            Event::Mouse(mouse::Event::CursorMoved { .. }) => {
+                let is_hovered = bounds.contains(cursor_position);
+
+                if is_hovered != self.state.is_hovered {
+                    self.state.is_hovered = is_hovered;
+
+                    let message = if is_hovered {
+                        self.on_enter.clone()
+                    } else {
+                        self.on_exit.clone()
+                    };
+
+                    if let Some(message) = message {
+                        shell.publish(message);
+                    }
+                }
+
+                if let (true, Some(on_move)) = (is_hovered, &self.on_move) {
+                    shell.publish(on_move(Point::new(
+                        cursor_position.x - bounds.x,
+                        cursor_position.y - bounds.y,
+                    )));
+                }
+            }
+            Event::Mouse(mouse::Event::CursorLeft) if self.state.is_hovered => {
+                self.state.is_hovered = false;
+
+                if let Some(on_exit) = self.on_exit.clone() {
+                    shell.publish(on_exit);
+                }
+            }
+            _ => {}
+        }
+
+        if let event::Status::Captured = self.content.on_event(
+            event.clone(),
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        ) {
+            return event::Status::Captured;
+        }
+
+        if !bounds.contains(cursor_position) {
+            return event::Status::Ignored;
+        }
+
+        let message = match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let click =
+                    mouse::Click::new(cursor_position, self.state.last_click);
+
+                self.state.last_click = Some(click);
+
+                if let (click::Kind::Double, Some(on_double_click)) =
+                    (click.kind(), self.on_double_click.clone())
+                {
+                    shell.publish(on_double_click);
+
+                    if self.on_press.is_none() {
+                        return event::Status::Captured;
+                    }
+                }
+
+                self.on_press.clone()
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. }) => {
+                self.on_release.clone()
+            }
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Right)) => {
+                self.on_right_press.clone()
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Right,
+            )) => self.on_right_release.clone(),
+            Event::Mouse(mouse::Event::ButtonPressed(
+                mouse::Button::Middle,
+            )) => self.on_middle_press.clone(),
+            Event::Mouse(mouse::Event::ButtonReleased(
+                mouse::Button::Middle,
+            )) => self.on_middle_release.clone(),
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                self.on_scroll.as_ref().map(|on_scroll| on_scroll(delta))
+            }
+            _ => None,
+        };
+
+        match message {
+            Some(message) => {
+                shell.publish(message);
+
+                event::Status::Captured
+            }
+            None => event::Status::Ignored,
+        }
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let content_interaction = self.content.mouse_interaction(
+            layout,
+            cursor_position,
+            viewport,
+            renderer,
+        );
+
+        match self.interaction {
+            Some(interaction)
+                if content_interaction == mouse::Interaction::Idle
+                    && layout.bounds().contains(cursor_position) =>
+            {
+                interaction
+            }
+            _ => content_interaction,
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        self.content
+            .draw(renderer, style, layout, cursor_position, viewport);
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.content.hash_layout(state);
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.content.overlay(layout, renderer)
+    }
+}
+
+impl<'a, Message, Renderer> From<MouseArea<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a + Clone,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        mouse_area: MouseArea<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(mouse_area)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard;
+    use crate::renderer::Null;
+    use crate::widget::Space;
+    use crate::{Size, Vector};
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Message {
+        Entered,
+        Exited,
+        Moved(Point),
+    }
+
+    fn move_cursor(
+        state: &mut State,
+        position: Point,
+        cursor_position: Point,
+    ) -> Vec<Message> {
+        let mut mouse_area = MouseArea::new(
+            state,
+            Space::new(Length::Units(100), Length::Units(50)),
+        )
+        .on_enter(Message::Entered)
+        .on_exit(Message::Exited)
+        .on_move(Message::Moved);
+
+        let node = layout::Node::new(Size::new(100.0, 50.0))
+            .translate(Vector::new(20.0, 10.0));
+
+        let mut messages = Vec::new();
+
+        let _ = Widget::<Message, Null>::on_event(
+            &mut mouse_area,
+            Event::Mouse(mouse::Event::CursorMoved { position }),
+            Layout::new(&node),
+            cursor_position,
+            &Null::new(),
+            &mut clipboard::Null,
+            &mut Shell::new(&mut messages),
+        );
+
+        messages
+    }
+
+    #[test]
+    fn enters_and_exits() {
+        let mut state = State::new();
+
+        let inside = Point::new(30.0, 20.0);
+        let outside = Point::new(5.0, 5.0);
+
+        assert_eq!(
+            move_cursor(&mut state, inside, inside),
+            vec![Message::Entered, Message::Moved(Point::new(10.0, 10.0))]
+        );
+
+        assert_eq!(
+            move_cursor(&mut state, outside, outside),
+            vec![Message::Exited]
+        );
+
+        assert_eq!(move_cursor(&mut state, outside, outside), vec![]);
+    }
+
+    #[test]
+    fn produces_local_coordinates() {
+        let mut state = State::new();
+
+        let _ = move_cursor(
+            &mut state,
+            Point::new(21.0, 11.0),
+            Point::new(21.0, 11.0),
+        );
+
+        assert_eq!(
+            move_cursor(
+                &mut state,
+                Point::new(70.0, 45.0),
+                Point::new(70.0, 45.0)
+            ),
+            vec![Message::Moved(Point::new(50.0, 35.0))]
+        );
+    }
+
+    #[test]
+    fn follows_translated_cursor() {
+        let mut state = State::new();
+
+        // Scrolled down by 200 units, the window position is outside of the
+        // bounds while the translated cursor is inside
+        assert_eq!(
+            move_cursor(
+                &mut state,
+                Point::new(30.0, 20.0),
+                Point::new(30.0, 220.0),
+            ),
+            vec![]
+        );
+
+        assert_eq!(
+            move_cursor(
+                &mut state,
+                Point::new(30.0, -180.0),
+                Point::new(30.0, 20.0),
+            ),
+            vec![Message::Entered, Message::Moved(Point::new(10.0, 10.0))]
+        );
+    }
+}
@@ -0,0 +1,652 @@
+//! Display fields that can be filled with numbers.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::widget::text_input::{self, filter, TextInput};
+use crate::{
+    Clipboard, Color, Element, Hasher, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+
+pub use iced_style::number_input::{Style, StyleSheet};
+
+/// A field that can be filled with a number from a range of values.
+///
+/// A [`NumberInput`] has buttons to decrease and increase its value, which
+/// can also be stepped with the arrow keys while focused or with the mouse
+/// wheel while hovered. Typed text is parsed when submitted or when the
+/// [`NumberInput`] loses focus.
+///
+/// Like a [`Slider`], its range of numeric values is generic and its step
+/// size defaults to 1 unit.
+///
+/// [`Slider`]: crate::widget::Slider
+///
+/// # Example
+/// ```
+/// # use iced_native::renderer::Null;
+/// # use iced_native::widget::number_input;
+/// #
+/// # pub type NumberInput<'a, T, Message> =
+/// #     iced_native::widget::NumberInput<'a, T, Message, Null>;
+/// #[derive(Debug, Clone)]
+/// pub enum Message {
+///     QuantityChanged(u32),
+/// }
+///
+/// let state = &mut number_input::State::new();
+///
+/// NumberInput::new(state, 1..=99, 3, Message::QuantityChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct NumberInput<'a, T, Message, Renderer: text::Renderer> {
+    text_input: TextInput<'a, Edit, Renderer>,
+    text: &'a mut Option<String>,
+    drag: &'a mut Option<Drag>,
+    range: RangeInclusive<T>,
+    step: T,
+    value: T,
+    precision: Option<usize>,
+    is_scrubbable: bool,
+    on_change: Box<dyn Fn(T) -> Message>,
+    width: Length,
+    size: Option<u16>,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+/// A change in the text of the [`TextInput`] of a [`NumberInput`].
+#[derive(Debug, Clone)]
+enum Edit {
+    Changed(String),
+    Submitted,
+}
+
+impl<'a, T, Message, Renderer> NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + Into<f64> + num_traits::FromPrimitive,
+    Renderer: text::Renderer,
+{
+    /// The amount of pixels the mouse needs to travel to change the value
+    /// of a [`NumberInput`] by one step when scrubbing.
+    const PIXELS_PER_STEP: f32 = 5.0;
+
+    /// Creates a new [`NumberInput`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`NumberInput`]
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`NumberInput`]
+    ///   * a function that will be called when the value changes. It
+    ///     receives the new value of the [`NumberInput`] and must produce a
+    ///     `Message`.
+    pub fn new<F>(
+        state: &'a mut State,
+        range: RangeInclusive<T>,
+        value: T,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(T) -> Message,
+    {
+        let value = if value.into() < (*range.start()).into() {
+            *range.start()
+        } else if value.into() > (*range.end()).into() {
+            *range.end()
+        } else {
+            value
+        };
+
+        let State {
+            text_input,
+            text,
+            drag,
+        } = state;
+
+        let content = text.clone().unwrap_or_else(|| format(value, None));
+
+        let number = filter::Pattern::new(r"[-+]?\d*\.?\d*")
+            .expect("Parse number pattern");
+
+        let text_input =
+            TextInput::new(text_input, "", &content, Edit::Changed)
+                .filter(text_input::Filter::Pattern(number))
+                .on_submit(Edit::Submitted);
+
+        NumberInput {
+            text_input,
+            text,
+            drag,
+            range,
+            step: T::from(1),
+            value,
+            precision: None,
+            is_scrubbable: false,
+            on_change: Box::new(on_change),
+            width: Length::Fill,
+            size: None,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the step size of the [`NumberInput`].
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+
+    /// Sets the amount of decimal places shown in the [`NumberInput`].
+    ///
+    /// By default, the value is shown with as many decimal places as needed.
+    pub fn precision(mut self, precision: usize) -> Self {
+        self.precision = Some(precision);
+
+        if self.text.is_none() {
+            self.text_input
+                .replace_value(&format(self.value, self.precision));
+        }
+
+        self
+    }
+
+    /// Sets whether the value of the [`NumberInput`] can be changed by
+    /// dragging the mouse horizontally over it.
+    ///
+    /// A click without dragging still focuses the [`NumberInput`].
+    pub fn scrubbable(mut self, is_scrubbable: bool) -> Self {
+        self.is_scrubbable = is_scrubbable;
+        self
+    }
+
+    /// Sets the width of the [`NumberInput`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the [`Padding`] of the [`NumberInput`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.text_input = self.text_input.padding(padding);
+        self
+    }
+
+    /// Sets the text size of the [`NumberInput`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = Some(size);
+        self.text_input = self.text_input.size(size);
+        self
+    }
+
+    /// Sets the [`Font`] of the [`NumberInput`].
+    ///
+    /// [`Font`]: text::Renderer::Font
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.text_input = self.text_input.font(font);
+        self
+    }
+
+    /// Sets the style of the field of the [`NumberInput`].
+    pub fn input_style(
+        mut self,
+        style_sheet: impl Into<Box<dyn text_input::StyleSheet + 'a>>,
+    ) -> Self {
+        self.text_input = self.text_input.style(style_sheet);
+        self
+    }
+
+    /// Sets the style of the buttons of the [`NumberInput`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Snaps the given value to the range and the steps of the
+    /// [`NumberInput`].
+    fn snap(&self, value: f64) -> f64 {
+        let step = self.step.into();
+        let start = (*self.range.start()).into();
+        let end = (*self.range.end()).into();
+
+        let value = if step > 0.0 {
+            ((value - start) / step).round() * step + start
+        } else {
+            value
+        };
+
+        value.max(start).min(end)
+    }
+
+    fn change(&mut self, value: f64, shell: &mut Shell<'_, Message>) {
+        let value = self.snap(value);
+
+        if (self.value.into() - value).abs() > f64::EPSILON {
+            if let Some(value) = T::from_f64(value) {
+                self.value = value;
+
+                shell.publish((self.on_change)(value));
+            }
+        }
+    }
+
+    fn step_by(&mut self, steps: f64, shell: &mut Shell<'_, Message>) {
+        if self.text.take().is_some() {
+            shell.invalidate_widgets();
+        }
+
+        self.change(self.value.into() + steps * self.step.into(), shell);
+    }
+
+    /// Parses the text typed in the [`NumberInput`], if any.
+    ///
+    /// Invalid text is discarded.
+    fn commit(&mut self, shell: &mut Shell<'_, Message>) {
+        if let Some(text) = self.text.take() {
+            if let Ok(value) = text.trim().parse::<f64>() {
+                self.change(value, shell);
+            }
+
+            shell.invalidate_widgets();
+        }
+    }
+}
+
+/// The local state of a [`NumberInput`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    text_input: text_input::State,
+    text: Option<String>,
+    drag: Option<Drag>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Drag {
+    origin: Point,
+    value: f64,
+    is_scrubbing: bool,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`NumberInput`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`NumberInput`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.text_input.is_focused()
+    }
+
+    /// Focuses the [`NumberInput`].
+    pub fn focus(&mut self) {
+        self.text_input.focus();
+    }
+
+    /// Unfocuses the [`NumberInput`], discarding any typed text.
+    pub fn unfocus(&mut self) {
+        self.text_input.unfocus();
+        self.text = None;
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for NumberInput<'a, T, Message, Renderer>
+where
+    T: Copy + From<u8> + Into<f64> + num_traits::FromPrimitive,
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let direction = limits.direction();
+
+        let field_limits = limits.width(Length::Fill);
+
+        // The buttons are square, so their width depends on the height of
+        // the field
+        let field = self.text_input.layout(renderer, &field_limits);
+        let button_size = Size::new(field.size().height, field.size().height);
+
+        let mut field = self.text_input.layout(
+            renderer,
+            &field_limits.shrink(Size::new(button_size.width * 2.0, 0.0)),
+        );
+
+        let mut decrement = layout::Node::new(button_size);
+        let mut increment = layout::Node::new(button_size);
+
+        let field_width = field.size().width;
+
+        if direction.is_right_to_left() {
+            increment.move_to(Point::ORIGIN);
+            decrement.move_to(Point::new(button_size.width, 0.0));
+            field.move_to(Point::new(button_size.width * 2.0, 0.0));
+        } else {
+            decrement.move_to(Point::new(field_width, 0.0));
+            increment.move_to(Point::new(field_width + button_size.width, 0.0));
+        }
+
+        layout::Node::with_children(
+            Size::new(
+                field_width + button_size.width * 2.0,
+                button_size.height,
+            ),
+            vec![field, decrement, increment],
+        )
+        .with_direction(direction)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let field_layout = children.next().unwrap();
+        let decrement_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+
+        let is_focused = self.text_input.state().is_focused();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if decrement_layout.bounds().contains(cursor_position) {
+                    self.step_by(-1.0, shell);
+
+                    return event::Status::Captured;
+                }
+
+                if increment_layout.bounds().contains(cursor_position) {
+                    self.step_by(1.0, shell);
+
+                    return event::Status::Captured;
+                }
+
+                if self.is_scrubbable
+                    && !is_focused
+                    && field_layout.bounds().contains(cursor_position)
+                {
+                    *self.drag = Some(Drag {
+                        origin: cursor_position,
+                        value: self.value.into(),
+                        is_scrubbing: false,
+                    });
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(mut drag) = *self.drag {
+                    let distance = cursor_position.x - drag.origin.x;
+
+                    if drag.is_scrubbing
+                        || distance.abs() >= Self::PIXELS_PER_STEP
+                    {
+                        drag.is_scrubbing = true;
+                        *self.drag = Some(drag);
+
+                        let steps = (distance / Self::PIXELS_PER_STEP).trunc();
+
+                        self.change(
+                            drag.value + f64::from(steps) * self.step.into(),
+                            shell,
+                        );
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if let Some(drag) = self.drag.take() {
+                    if !drag.is_scrubbing {
+                        // A click without dragging focuses the field
+                        let _ = self.text_input.on_event(
+                            Event::Mouse(mouse::Event::ButtonPressed(
+                                mouse::Button::Left,
+                            )),
+                            field_layout,
+                            drag.origin,
+                            renderer,
+                            clipboard,
+                            &mut Shell::new(&mut Vec::new()),
+                        );
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta })
+                if layout.bounds().contains(cursor_position) =>
+            {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if y != 0.0 {
+                    self.step_by(f64::from(y.signum()), shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Up,
+                ..
+            }) if is_focused => {
+                self.step_by(1.0, shell);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Down,
+                ..
+            }) if is_focused => {
+                self.step_by(-1.0, shell);
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        let mut edits = Vec::new();
+        let mut text_shell = Shell::new(&mut edits);
+
+        let status = self.text_input.on_event(
+            event,
+            field_layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            &mut text_shell,
+        );
+
+        if let Some(position) = text_shell.ime_position() {
+            shell.set_ime_position(position);
+        }
+
+        for edit in edits {
+            match edit {
+                Edit::Changed(text) => {
+                    *self.text = Some(text);
+                }
+                Edit::Submitted => {
+                    self.commit(shell);
+                }
+            }
+        }
+
+        if is_focused && !self.text_input.state().is_focused() {
+            self.commit(shell);
+        }
+
+        status
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let mut children = layout.children();
+        let field_layout = children.next().unwrap();
+
+        let is_over_field = field_layout.bounds().contains(cursor_position);
+        let is_scrubbing = matches!(
+            self.drag,
+            Some(Drag {
+                is_scrubbing: true,
+                ..
+            })
+        );
+
+        if is_scrubbing
+            || (self.is_scrubbable
+                && is_over_field
+                && !self.text_input.state().is_focused())
+        {
+            mouse::Interaction::ResizingHorizontally
+        } else if is_over_field {
+            self.text_input.mouse_interaction(
+                field_layout,
+                cursor_position,
+                viewport,
+                renderer,
+            )
+        } else if children
+            .any(|button| button.bounds().contains(cursor_position))
+        {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let mut children = layout.children();
+        let field_layout = children.next().unwrap();
+        let decrement_layout = children.next().unwrap();
+        let increment_layout = children.next().unwrap();
+
+        self.text_input
+            .draw(renderer, field_layout, cursor_position, None);
+
+        let value = self.value.into();
+        let size = f32::from(self.size.unwrap_or(renderer.default_size()));
+
+        let buttons = [
+            (decrement_layout, "-", value > (*self.range.start()).into()),
+            (increment_layout, "+", value < (*self.range.end()).into()),
+        ];
+
+        for (layout, icon, is_enabled) in buttons.iter() {
+            let bounds = layout.bounds();
+
+            let style = if !is_enabled {
+                self.style_sheet.disabled()
+            } else if bounds.contains(cursor_position) {
+                self.style_sheet.hovered()
+            } else {
+                self.style_sheet.active()
+            };
+
+            if let Some(background) = style.button_background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds,
+                        border_radius: style.button_border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            renderer.fill_text(Text {
+                content: icon,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size,
+                color: style.icon_color,
+                font: Default::default(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                format: text::Format::default(),
+                decorations: &[],
+            });
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.text_input.hash_layout(state);
+    }
+}
+
+/// Formats a value with the given amount of decimal places, if any.
+fn format<T: Into<f64>>(value: T, precision: Option<usize>) -> String {
+    let value = value.into();
+
+    match precision {
+        Some(precision) => format!("{:.*}", precision, value),
+        None => value.to_string(),
+    }
+}
+
+impl<'a, T, Message, Renderer> From<NumberInput<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + From<u8> + Into<f64> + num_traits::FromPrimitive,
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        number_input: NumberInput<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(number_input)
+    }
+}
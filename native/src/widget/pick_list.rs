@@ -184,6 +184,7 @@ where
                         text_size,
                         font.clone(),
                         Size::new(f32::INFINITY, f32::INFINITY),
+                        text::Format::default(),
                     );
 
                     width.round() as u32
@@ -384,6 +385,8 @@ where
             color: style.text_color,
             horizontal_alignment: alignment::Horizontal::Right,
             vertical_alignment: alignment::Vertical::Center,
+            format: text::Format::default(),
+            decorations: &[],
         });
 
         if let Some(label) = self
@@ -409,6 +412,8 @@ where
                 },
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Center,
+                format: text::Format::default(),
+                decorations: &[],
             })
         }
     }
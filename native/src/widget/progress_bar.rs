@@ -1,16 +1,24 @@
 //! Provide progress feedback to your users.
+use crate::event::{self, Event};
 use crate::layout;
 use crate::renderer;
+use crate::window;
 use crate::{
-    Color, Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget,
+    Background, Clipboard, Color, Element, Hasher, Layout, Length, Point,
+    Rectangle, Shell, Size, Widget,
 };
 
+use std::time::{Duration, Instant};
 use std::{hash::Hash, ops::RangeInclusive};
 
 pub use iced_style::progress_bar::{Style, StyleSheet};
 
 /// A bar that displays progress.
 ///
+/// When the progress of an operation is unknown, an
+/// [`indeterminate`](ProgressBar::indeterminate) [`ProgressBar`] can be used
+/// instead. It is animated and needs some local [`State`].
+///
 /// # Example
 /// ```
 /// # use iced_native::widget::ProgressBar;
@@ -22,9 +30,9 @@ pub use iced_style::progress_bar::{Style, StyleSheet};
 /// ![Progress bar drawn with `iced_wgpu`](https://user-images.githubusercontent.com/18618951/71662391-a316c200-2d51-11ea-9cef-52758cab85e3.png)
 #[allow(missing_debug_implementations)]
 pub struct ProgressBar<'a> {
-    range: RangeInclusive<f32>,
-    value: f32,
-    width: Length,
+    progress: Progress<'a>,
+    orientation: Orientation,
+    width: Option<Length>,
     height: Option<Length>,
     style_sheet: Box<dyn StyleSheet + 'a>,
 }
@@ -33,16 +41,33 @@ impl<'a> ProgressBar<'a> {
     /// The default height of a [`ProgressBar`].
     pub const DEFAULT_HEIGHT: u16 = 30;
 
+    /// The time it takes for an indeterminate [`ProgressBar`] to complete a
+    /// cycle of its animation.
+    const CYCLE: Duration = Duration::from_millis(1500);
+
+    /// The fraction of the length of an indeterminate [`ProgressBar`] that
+    /// its moving bar covers.
+    const INDETERMINATE_LENGTH: f32 = 0.3;
+
     /// Creates a new [`ProgressBar`].
     ///
     /// It expects:
     ///   * an inclusive range of possible values
     ///   * the current value of the [`ProgressBar`]
     pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Self::with_progress(Progress::determinate(range, value))
+    }
+
+    /// Creates a new indeterminate [`ProgressBar`] with the given [`State`].
+    pub fn indeterminate(state: &'a mut State) -> Self {
+        Self::with_progress(Progress::Indeterminate(state))
+    }
+
+    fn with_progress(progress: Progress<'a>) -> Self {
         ProgressBar {
-            value: value.max(*range.start()).min(*range.end()),
-            range,
-            width: Length::Fill,
+            progress,
+            orientation: Orientation::default(),
+            width: None,
             height: None,
             style_sheet: Default::default(),
         }
@@ -50,7 +75,7 @@ impl<'a> ProgressBar<'a> {
 
     /// Sets the width of the [`ProgressBar`].
     pub fn width(mut self, width: Length) -> Self {
-        self.width = width;
+        self.width = Some(width);
         self
     }
 
@@ -60,6 +85,15 @@ impl<'a> ProgressBar<'a> {
         self
     }
 
+    /// Sets the [`Orientation`] of the [`ProgressBar`].
+    ///
+    /// A vertical [`ProgressBar`] fills from bottom to top, and its default
+    /// width and height are swapped.
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
     /// Sets the style of the [`ProgressBar`].
     pub fn style(
         mut self,
@@ -70,16 +104,129 @@ impl<'a> ProgressBar<'a> {
     }
 }
 
+/// The direction in which a [`ProgressBar`] fills up.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// The bar fills from left to right.
+    Horizontal,
+
+    /// The bar fills from bottom to top.
+    Vertical,
+}
+
+impl Default for Orientation {
+    fn default() -> Self {
+        Orientation::Horizontal
+    }
+}
+
+/// The local state of an indeterminate progress indicator, which keeps
+/// track of its animation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    started_at: Option<Instant>,
+    now: Option<Instant>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns how far the animation is in its current cycle, from 0 to 1.
+    fn cycle(&self, duration: Duration) -> f32 {
+        match (self.started_at, self.now) {
+            (Some(started_at), Some(now)) => {
+                let elapsed = now.duration_since(started_at).as_secs_f32();
+
+                (elapsed / duration.as_secs_f32()).fract()
+            }
+            _ => 0.0,
+        }
+    }
+}
+
+/// The progress shown by a progress indicator.
+pub(crate) enum Progress<'a> {
+    Determinate {
+        range: RangeInclusive<f32>,
+        value: f32,
+    },
+    Indeterminate(&'a mut State),
+}
+
+impl<'a> Progress<'a> {
+    /// Creates a determinate [`Progress`], clamping the value to the range.
+    pub fn determinate(range: RangeInclusive<f32>, value: f32) -> Self {
+        Progress::Determinate {
+            value: value.max(*range.start()).min(*range.end()),
+            range,
+        }
+    }
+
+    /// Returns the completed fraction of a determinate [`Progress`], from 0
+    /// to 1.
+    pub fn fraction(&self) -> Option<f32> {
+        match self {
+            Progress::Determinate { range, value } => {
+                let (start, end) = range.clone().into_inner();
+
+                Some(if start >= end {
+                    0.0
+                } else {
+                    (value - start) / (end - start)
+                })
+            }
+            Progress::Indeterminate(_) => None,
+        }
+    }
+
+    /// Returns how far the animation of an indeterminate [`Progress`] is in
+    /// its current cycle, from 0 to 1.
+    pub fn cycle(&self, duration: Duration) -> Option<f32> {
+        match self {
+            Progress::Determinate { .. } => None,
+            Progress::Indeterminate(state) => Some(state.cycle(duration)),
+        }
+    }
+
+    /// Advances the animation of an indeterminate [`Progress`] and schedules
+    /// its next frame.
+    pub fn on_event<Message>(
+        &mut self,
+        event: &Event,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        if let (
+            Progress::Indeterminate(state),
+            Event::Window(window::Event::RedrawRequested(now)),
+        ) = (self, event)
+        {
+            let _ = state.started_at.get_or_insert(*now);
+            state.now = Some(*now);
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer> for ProgressBar<'a>
 where
     Renderer: crate::Renderer,
 {
     fn width(&self) -> Length {
-        self.width
+        self.width.unwrap_or(match self.orientation {
+            Orientation::Horizontal => Length::Fill,
+            Orientation::Vertical => Length::Units(Self::DEFAULT_HEIGHT),
+        })
     }
 
     fn height(&self) -> Length {
-        self.height.unwrap_or(Length::Units(Self::DEFAULT_HEIGHT))
+        self.height.unwrap_or(match self.orientation {
+            Orientation::Horizontal => Length::Units(Self::DEFAULT_HEIGHT),
+            Orientation::Vertical => Length::Fill,
+        })
     }
 
     fn layout(
@@ -88,14 +235,28 @@ where
         limits: &layout::Limits,
     ) -> layout::Node {
         let limits = limits
-            .width(self.width)
-            .height(self.height.unwrap_or(Length::Units(Self::DEFAULT_HEIGHT)));
+            .width(Widget::<Message, Renderer>::width(self))
+            .height(Widget::<Message, Renderer>::height(self));
 
         let size = limits.resolve(Size::ZERO);
 
         layout::Node::new(size)
     }
 
+    fn on_event(
+        &mut self,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.progress.on_event(&event, shell);
+
+        event::Status::Ignored
+    }
+
     fn draw(
         &self,
         renderer: &mut Renderer,
@@ -105,20 +266,23 @@ where
         _viewport: &Rectangle,
     ) {
         let bounds = layout.bounds();
-        let (range_start, range_end) = self.range.clone().into_inner();
+        let style = self.style_sheet.style();
 
-        let active_progress_width = if range_start >= range_end {
-            0.0
-        } else {
-            bounds.width * (self.value - range_start)
-                / (range_end - range_start)
-        };
+        // The start and end of the active part of the bar, from 0 to 1
+        let (start, end) = match self.progress.fraction() {
+            Some(fraction) => (0.0, fraction),
+            None => {
+                let cycle = self.progress.cycle(Self::CYCLE).unwrap_or(0.0);
+                let length = Self::INDETERMINATE_LENGTH;
+                let end = cycle * (1.0 + length);
 
-        let style = self.style_sheet.style();
+                ((end - length).max(0.0), end.min(1.0))
+            }
+        };
 
         renderer.fill_quad(
             renderer::Quad {
-                bounds: Rectangle { ..bounds },
+                bounds,
                 border_radius: style.border_radius,
                 border_width: 0.0,
                 border_color: Color::TRANSPARENT,
@@ -126,13 +290,23 @@ where
             style.background,
         );
 
-        if active_progress_width > 0.0 {
+        if end > start {
+            let active = match self.orientation {
+                Orientation::Horizontal => Rectangle {
+                    x: bounds.x + bounds.width * start,
+                    width: bounds.width * (end - start),
+                    ..bounds
+                },
+                Orientation::Vertical => Rectangle {
+                    y: bounds.y + bounds.height * (1.0 - end),
+                    height: bounds.height * (end - start),
+                    ..bounds
+                },
+            };
+
             renderer.fill_quad(
                 renderer::Quad {
-                    bounds: Rectangle {
-                        width: active_progress_width,
-                        ..bounds
-                    },
+                    bounds: active,
                     border_radius: style.border_radius,
                     border_width: 0.0,
                     border_color: Color::TRANSPARENT,
@@ -148,6 +322,7 @@ where
 
         self.width.hash(state);
         self.height.hash(state);
+        self.orientation.hash(state);
     }
 }
 
@@ -161,3 +336,13 @@ where
         Element::new(progress_bar)
     }
 }
+
+/// Returns the given [`Background`] with its opacity multiplied by `alpha`.
+pub(crate) fn fade(background: Background, alpha: f32) -> Background {
+    match background {
+        Background::Color(color) => Background::Color(Color {
+            a: color.a * alpha,
+            ..color
+        }),
+    }
+}
@@ -0,0 +1,216 @@
+//! Provide progress feedback to your users with a ring of dots.
+use crate::event::{self, Event};
+use crate::layout;
+use crate::renderer;
+use crate::widget::progress_bar::{self, Progress};
+use crate::{
+    Clipboard, Color, Element, Hasher, Layout, Length, Point, Rectangle, Shell,
+    Size, Widget,
+};
+
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+use std::time::Duration;
+
+pub use crate::widget::progress_bar::State;
+pub use iced_style::progress_bar::{Style, StyleSheet};
+
+/// A ring of dots that displays progress.
+///
+/// The dots of a [`ProgressRing`] are filled clockwise, starting at the top.
+/// An [`indeterminate`](ProgressRing::indeterminate) [`ProgressRing`] is a
+/// spinner instead, and needs some local [`State`] to be animated.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{progress_ring, ProgressRing};
+/// let value = 50.0;
+///
+/// ProgressRing::new(0.0..=100.0, value);
+///
+/// let state = &mut progress_ring::State::new();
+///
+/// ProgressRing::indeterminate(state);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ProgressRing<'a> {
+    progress: Progress<'a>,
+    size: u16,
+    dots: u16,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a> ProgressRing<'a> {
+    /// The default size of a [`ProgressRing`].
+    pub const DEFAULT_SIZE: u16 = 40;
+
+    /// The default amount of dots of a [`ProgressRing`].
+    pub const DEFAULT_DOTS: u16 = 12;
+
+    /// The time it takes for an indeterminate [`ProgressRing`] to complete a
+    /// turn.
+    const CYCLE: Duration = Duration::from_millis(1000);
+
+    /// Creates a new [`ProgressRing`].
+    ///
+    /// It expects:
+    ///   * an inclusive range of possible values
+    ///   * the current value of the [`ProgressRing`]
+    pub fn new(range: RangeInclusive<f32>, value: f32) -> Self {
+        Self::with_progress(Progress::determinate(range, value))
+    }
+
+    /// Creates a new indeterminate [`ProgressRing`] with the given [`State`].
+    pub fn indeterminate(state: &'a mut State) -> Self {
+        Self::with_progress(Progress::Indeterminate(state))
+    }
+
+    fn with_progress(progress: Progress<'a>) -> Self {
+        ProgressRing {
+            progress,
+            size: Self::DEFAULT_SIZE,
+            dots: Self::DEFAULT_DOTS,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the size of the [`ProgressRing`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the amount of dots of the [`ProgressRing`].
+    pub fn dots(mut self, dots: u16) -> Self {
+        self.dots = dots.max(1);
+        self
+    }
+
+    /// Sets the style of the [`ProgressRing`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer> for ProgressRing<'a>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn height(&self) -> Length {
+        Length::Units(self.size)
+    }
+
+    fn layout(
+        &self,
+        _renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits
+            .width(Length::Units(self.size))
+            .height(Length::Units(self.size));
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        _layout: Layout<'_>,
+        _cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        self.progress.on_event(&event, shell);
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        _cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        use std::f32::consts::PI;
+
+        let bounds = layout.bounds();
+        let style = self.style_sheet.style();
+
+        let dots = f32::from(self.dots);
+        let diameter = bounds.width.min(bounds.height);
+        let dot_radius = (diameter * 0.1).max(1.0);
+        let ring_radius = diameter / 2.0 - dot_radius;
+        let center = bounds.center();
+
+        let completed_dots = self
+            .progress
+            .fraction()
+            .map(|fraction| (fraction * dots).round());
+
+        // The position of the head of the spinner, in dots
+        let head = self.progress.cycle(Self::CYCLE).unwrap_or(0.0) * dots;
+
+        for dot in 0..self.dots {
+            let dot = f32::from(dot);
+            let angle = 2.0 * PI * dot / dots;
+
+            let bounds = Rectangle {
+                x: center.x + ring_radius * angle.sin() - dot_radius,
+                y: center.y - ring_radius * angle.cos() - dot_radius,
+                width: dot_radius * 2.0,
+                height: dot_radius * 2.0,
+            };
+
+            let quad = renderer::Quad {
+                bounds,
+                border_radius: dot_radius,
+                border_width: 0.0,
+                border_color: Color::TRANSPARENT,
+            };
+
+            renderer.fill_quad(quad, style.background);
+
+            // The dots behind the head of a spinner fade out as a trail
+            let alpha = match completed_dots {
+                Some(completed_dots) if dot < completed_dots => 1.0,
+                Some(_) => 0.0,
+                None => 1.0 - (head - dot).rem_euclid(dots) / dots,
+            };
+
+            if alpha > 0.0 {
+                renderer.fill_quad(quad, progress_bar::fade(style.bar, alpha));
+            }
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.size.hash(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<ProgressRing<'a>>
+    for Element<'a, Message, Renderer>
+where
+    Renderer: 'a + crate::Renderer,
+    Message: 'a,
+{
+    fn from(progress_ring: ProgressRing<'a>) -> Element<'a, Message, Renderer> {
+        Element::new(progress_ring)
+    }
+}
@@ -155,21 +155,15 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        Row::<(), Renderer>::new()
-            .width(self.width)
-            .spacing(self.spacing)
-            .align_items(Alignment::Center)
-            .push(
-                Row::new()
-                    .width(Length::Units(self.size))
-                    .height(Length::Units(self.size)),
-            )
-            .push(
-                Text::new(&self.label)
-                    .width(self.width)
-                    .size(self.text_size.unwrap_or(renderer.default_size())),
-            )
-            .layout(renderer, limits)
+        row(
+            &self.label,
+            self.width,
+            self.size,
+            self.spacing,
+            self.text_size.unwrap_or(renderer.default_size()),
+            self.font.clone(),
+        )
+        .layout(renderer, limits)
     }
 
     fn on_event(
@@ -221,64 +215,22 @@ where
         let bounds = layout.bounds();
         let is_mouse_over = bounds.contains(cursor_position);
 
-        let mut children = layout.children();
-
         let custom_style = if is_mouse_over {
             self.style_sheet.hovered()
         } else {
             self.style_sheet.active()
         };
 
-        {
-            let layout = children.next().unwrap();
-            let bounds = layout.bounds();
-
-            let size = bounds.width;
-            let dot_size = size / 2.0;
-
-            renderer.fill_quad(
-                renderer::Quad {
-                    bounds,
-                    border_radius: size / 2.0,
-                    border_width: custom_style.border_width,
-                    border_color: custom_style.border_color,
-                },
-                custom_style.background,
-            );
-
-            if self.is_selected {
-                renderer.fill_quad(
-                    renderer::Quad {
-                        bounds: Rectangle {
-                            x: bounds.x + dot_size / 2.0,
-                            y: bounds.y + dot_size / 2.0,
-                            width: bounds.width - dot_size,
-                            height: bounds.height - dot_size,
-                        },
-                        border_radius: dot_size / 2.0,
-                        border_width: 0.0,
-                        border_color: Color::TRANSPARENT,
-                    },
-                    custom_style.dot_color,
-                );
-            }
-        }
-
-        {
-            let label_layout = children.next().unwrap();
-
-            widget::text::draw(
-                renderer,
-                style,
-                label_layout,
-                &self.label,
-                self.font.clone(),
-                self.text_size,
-                custom_style.text_color,
-                alignment::Horizontal::Left,
-                alignment::Vertical::Center,
-            );
-        }
+        draw(
+            renderer,
+            style,
+            layout,
+            &custom_style,
+            self.is_selected,
+            &self.label,
+            self.font.clone(),
+            self.text_size,
+        );
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -301,3 +253,97 @@ where
         Element::new(radio)
     }
 }
+
+/// Produces a [`Row`] that lays out a radio button with the given label.
+pub(crate) fn row<'a, Renderer>(
+    label: &str,
+    width: Length,
+    size: u16,
+    spacing: u16,
+    text_size: u16,
+    font: Renderer::Font,
+) -> Row<'a, (), Renderer>
+where
+    Renderer: text::Renderer + 'a,
+{
+    Row::new()
+        .width(width)
+        .spacing(spacing)
+        .align_items(Alignment::Center)
+        .push(
+            Row::new()
+                .width(Length::Units(size))
+                .height(Length::Units(size)),
+        )
+        .push(Text::new(label).font(font).width(width).size(text_size))
+}
+
+/// Draws a radio button laid out with [`row`] using the given [`Style`].
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn draw<Renderer>(
+    renderer: &mut Renderer,
+    style: &renderer::Style,
+    layout: Layout<'_>,
+    custom_style: &Style,
+    is_selected: bool,
+    label: &str,
+    font: Renderer::Font,
+    text_size: Option<u16>,
+) where
+    Renderer: text::Renderer,
+{
+    let mut children = layout.children();
+
+    {
+        let layout = children.next().unwrap();
+        let bounds = layout.bounds();
+
+        let size = bounds.width;
+        let dot_size = size / 2.0;
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: size / 2.0,
+                border_width: custom_style.border_width,
+                border_color: custom_style.border_color,
+            },
+            custom_style.background,
+        );
+
+        if is_selected {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: bounds.x + dot_size / 2.0,
+                        y: bounds.y + dot_size / 2.0,
+                        width: bounds.width - dot_size,
+                        height: bounds.height - dot_size,
+                    },
+                    border_radius: dot_size / 2.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                custom_style.dot_color,
+            );
+        }
+    }
+
+    {
+        let label_layout = children.next().unwrap();
+
+        widget::text::draw(
+            renderer,
+            style,
+            label_layout,
+            label,
+            font,
+            text_size,
+            custom_style.text_color,
+            alignment::Horizontal::Left,
+            alignment::Vertical::Center,
+            Default::default(),
+            &[],
+        );
+    }
+}
@@ -0,0 +1,426 @@
+//! Choose a single option from a group of radio buttons.
+//!
+//! A [`RadioGroup`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::radio;
+use crate::widget::{Column, Row};
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use std::borrow::Cow;
+use std::hash::Hash;
+
+pub use iced_style::radio::{Style, StyleSheet};
+
+/// A list of radio buttons where only one option can be selected.
+///
+/// Once focused by clicking it, the selection of a [`RadioGroup`] can be
+/// moved with the arrow keys.
+///
+/// # Example
+/// ```
+/// # type RadioGroup<'a, T, Message> =
+/// #     iced_native::widget::RadioGroup<'a, T, Message, iced_native::renderer::Null>;
+/// # use iced_native::widget::radio_group;
+/// #
+/// #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// pub enum Size {
+///     Small,
+///     Large,
+/// }
+///
+/// impl std::fmt::Display for Size {
+///     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+///         write!(f, "{:?}", self)
+///     }
+/// }
+///
+/// #[derive(Debug, Clone)]
+/// pub enum Message {
+///     SizeSelected(Size),
+/// }
+///
+/// let state = &mut radio_group::State::new();
+///
+/// RadioGroup::new(
+///     state,
+///     &[Size::Small, Size::Large][..],
+///     Some(Size::Small),
+///     Message::SizeSelected,
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RadioGroup<'a, T, Message, Renderer: text::Renderer>
+where
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    state: &'a mut State,
+    options: Cow<'a, [T]>,
+    selected: Option<T>,
+    on_selected: Box<dyn Fn(T) -> Message>,
+    orientation: Orientation,
+    width: Length,
+    size: u16,
+    spacing: u16,
+    option_spacing: u16,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+/// The direction in which the options of a [`RadioGroup`] are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// The options are placed next to each other in a row.
+    Horizontal,
+
+    /// The options are placed below each other in a column.
+    Vertical,
+}
+
+impl Default for Orientation {
+    fn default() -> Self {
+        Orientation::Vertical
+    }
+}
+
+/// The local state of a [`RadioGroup`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    is_focused: bool,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`RadioGroup`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`RadioGroup`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.is_focused
+    }
+
+    /// Focuses the [`RadioGroup`].
+    pub fn focus(&mut self) {
+        self.is_focused = true;
+    }
+
+    /// Unfocuses the [`RadioGroup`].
+    pub fn unfocus(&mut self) {
+        self.is_focused = false;
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer: text::Renderer>
+    RadioGroup<'a, T, Message, Renderer>
+where
+    T: ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+{
+    /// The default spacing between the options of a [`RadioGroup`].
+    pub const DEFAULT_OPTION_SPACING: u16 = 10;
+
+    /// Creates a new [`RadioGroup`] with the given [`State`], a list of
+    /// options, the current selected value, and the message to produce when
+    /// an option is selected.
+    pub fn new(
+        state: &'a mut State,
+        options: impl Into<Cow<'a, [T]>>,
+        selected: Option<T>,
+        on_selected: impl Fn(T) -> Message + 'static,
+    ) -> Self {
+        RadioGroup {
+            state,
+            options: options.into(),
+            selected,
+            on_selected: Box::new(on_selected),
+            orientation: Orientation::default(),
+            width: Length::Shrink,
+            size: radio::Radio::<(), Renderer>::DEFAULT_SIZE,
+            spacing: radio::Radio::<(), Renderer>::DEFAULT_SPACING,
+            option_spacing: Self::DEFAULT_OPTION_SPACING,
+            text_size: None,
+            font: Default::default(),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the [`Orientation`] of the [`RadioGroup`].
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the width of the [`RadioGroup`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the size of the radio buttons of the [`RadioGroup`].
+    pub fn size(mut self, size: u16) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// Sets the spacing between each radio button and its label.
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the spacing between the options of the [`RadioGroup`].
+    pub fn option_spacing(mut self, option_spacing: u16) -> Self {
+        self.option_spacing = option_spacing;
+        self
+    }
+
+    /// Sets the text size of the [`RadioGroup`].
+    pub fn text_size(mut self, text_size: u16) -> Self {
+        self.text_size = Some(text_size);
+        self
+    }
+
+    /// Sets the text font of the [`RadioGroup`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`RadioGroup`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    fn selected_index(&self) -> Option<usize> {
+        let selected = self.selected.as_ref()?;
+
+        self.options.iter().position(|option| option == selected)
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> Widget<Message, Renderer>
+    for RadioGroup<'a, T, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Renderer: text::Renderer + 'a,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        let options = self
+            .options
+            .iter()
+            .map(|option| {
+                radio::row(
+                    &option.to_string(),
+                    Length::Shrink,
+                    self.size,
+                    self.spacing,
+                    text_size,
+                    self.font.clone(),
+                )
+                .into()
+            })
+            .collect();
+
+        match self.orientation {
+            Orientation::Horizontal => {
+                Row::<(), Renderer>::with_children(options)
+                    .width(self.width)
+                    .spacing(self.option_spacing)
+                    .layout(renderer, limits)
+            }
+            Orientation::Vertical => {
+                Column::<(), Renderer>::with_children(options)
+                    .width(self.width)
+                    .spacing(self.option_spacing)
+                    .layout(renderer, limits)
+            }
+        }
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let is_clicked = layout.bounds().contains(cursor_position);
+
+                self.state.is_focused = is_clicked;
+
+                if !is_clicked {
+                    return event::Status::Ignored;
+                }
+
+                let clicked = layout
+                    .children()
+                    .position(|option| {
+                        option.bounds().contains(cursor_position)
+                    })
+                    .and_then(|index| self.options.get(index));
+
+                if let Some(option) = clicked {
+                    shell.publish((self.on_selected)(option.clone()));
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) if self.state.is_focused && !self.options.is_empty() => {
+                let is_reversed = self.orientation == Orientation::Horizontal
+                    && layout.direction().is_right_to_left();
+
+                let is_forward = match key_code {
+                    keyboard::KeyCode::Down => true,
+                    keyboard::KeyCode::Up => false,
+                    keyboard::KeyCode::Right => !is_reversed,
+                    keyboard::KeyCode::Left => is_reversed,
+                    _ => return event::Status::Ignored,
+                };
+
+                let last = self.options.len() - 1;
+
+                // The selection wraps around the ends of the group
+                let next = match (self.selected_index(), is_forward) {
+                    (Some(index), true) if index < last => index + 1,
+                    (Some(index), false) if index > 0 => index - 1,
+                    (_, true) => 0,
+                    (_, false) => last,
+                };
+
+                shell.publish((self.on_selected)(self.options[next].clone()));
+
+                return event::Status::Captured;
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let is_mouse_over_option = layout
+            .children()
+            .any(|option| option.bounds().contains(cursor_position));
+
+        if is_mouse_over_option {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let selected = self.selected_index();
+
+        // A focused group without a selection highlights its first option,
+        // which is the one that the arrow keys select first
+        let focused = if self.state.is_focused {
+            Some(selected.unwrap_or(0))
+        } else {
+            None
+        };
+
+        for (index, (option, layout)) in
+            self.options.iter().zip(layout.children()).enumerate()
+        {
+            let custom_style = if focused == Some(index) {
+                self.style_sheet.focused()
+            } else if layout.bounds().contains(cursor_position) {
+                self.style_sheet.hovered()
+            } else {
+                self.style_sheet.active()
+            };
+
+            radio::draw(
+                renderer,
+                style,
+                layout,
+                &custom_style,
+                selected == Some(index),
+                &option.to_string(),
+                self.font.clone(),
+                self.text_size,
+            );
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.orientation.hash(state);
+        self.width.hash(state);
+        self.size.hash(state);
+        self.spacing.hash(state);
+        self.option_spacing.hash(state);
+        self.text_size.hash(state);
+        self.font.hash(state);
+
+        for option in self.options.iter() {
+            option.to_string().hash(state);
+        }
+    }
+}
+
+impl<'a, T: 'a, Message, Renderer> From<RadioGroup<'a, T, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    T: Clone + ToString + Eq,
+    [T]: ToOwned<Owned = Vec<T>>,
+    Message: 'a,
+    Renderer: text::Renderer + 'a,
+{
+    fn from(
+        radio_group: RadioGroup<'a, T, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(radio_group)
+    }
+}
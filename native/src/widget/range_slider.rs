@@ -0,0 +1,417 @@
+//! Display an interactive selector of a range of values within another range.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text;
+use crate::touch;
+use crate::widget::slider::{self, Track};
+use crate::{
+    Clipboard, Element, Hasher, Layout, Length, Point, Rectangle, Shell, Widget,
+};
+
+use std::hash::Hash;
+use std::ops::RangeInclusive;
+
+pub use crate::widget::slider::{Orientation, Tick};
+pub use iced_style::slider::{
+    Handle, HandleShape, Style, StyleSheet, TickMarks,
+};
+
+/// A bar with two handles that select a range of values from another range
+/// of values.
+///
+/// The handles of a [`RangeSlider`] cannot cross each other, so the start of
+/// the selected range is never greater than its end.
+///
+/// Like a [`Slider`](crate::widget::Slider), a [`RangeSlider`] can be
+/// vertical, have tick marks, and its step size defaults to 1 unit.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::range_slider::{self, RangeSlider};
+/// # use std::ops::RangeInclusive;
+/// #
+/// #[derive(Clone)]
+/// pub enum Message {
+///     PriceChanged(RangeInclusive<u32>),
+/// }
+///
+/// let state = &mut range_slider::State::new();
+/// let price = 20..=80;
+///
+/// RangeSlider::new(state, 0..=100, price, Message::PriceChanged);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct RangeSlider<'a, T, Message> {
+    state: &'a mut State,
+    range: RangeInclusive<T>,
+    step: T,
+    value: (T, T),
+    on_change: Box<dyn Fn(RangeInclusive<T>) -> Message>,
+    on_release: Option<Message>,
+    width: Length,
+    height: u16,
+    orientation: Orientation,
+    ticks: Vec<Tick<T>>,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, T, Message> RangeSlider<'a, T, Message>
+where
+    T: Copy + From<u8> + std::cmp::PartialOrd,
+    Message: Clone,
+{
+    /// The default height of a [`RangeSlider`].
+    pub const DEFAULT_HEIGHT: u16 = 22;
+
+    /// Creates a new [`RangeSlider`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`RangeSlider`]
+    ///   * an inclusive range of possible values
+    ///   * the currently selected range of the [`RangeSlider`]
+    ///   * a function that will be called when any of the handles of the
+    ///     [`RangeSlider`] is dragged. It receives the new selected range
+    ///     and must produce a `Message`.
+    pub fn new<F>(
+        state: &'a mut State,
+        range: RangeInclusive<T>,
+        value: RangeInclusive<T>,
+        on_change: F,
+    ) -> Self
+    where
+        F: 'static + Fn(RangeInclusive<T>) -> Message,
+    {
+        let (start, end) = value.into_inner();
+        let start = slider::clamp(start, &range);
+        let end = slider::clamp(end, &range);
+
+        let value = if start <= end {
+            (start, end)
+        } else {
+            (end, start)
+        };
+
+        RangeSlider {
+            state,
+            value,
+            range,
+            step: T::from(1),
+            on_change: Box::new(on_change),
+            on_release: None,
+            width: Length::Fill,
+            height: Self::DEFAULT_HEIGHT,
+            orientation: Orientation::default(),
+            ticks: Vec::new(),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the release message of the [`RangeSlider`], which is produced
+    /// when any of its handles is released.
+    pub fn on_release(mut self, on_release: Message) -> Self {
+        self.on_release = Some(on_release);
+        self
+    }
+
+    /// Sets the width of the [`RangeSlider`].
+    ///
+    /// When the [`RangeSlider`] is vertical, this is its height instead.
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the height of the [`RangeSlider`].
+    ///
+    /// When the [`RangeSlider`] is vertical, this is its width instead.
+    pub fn height(mut self, height: u16) -> Self {
+        self.height = height;
+        self
+    }
+
+    /// Sets the [`Orientation`] of the [`RangeSlider`].
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the tick marks of the [`RangeSlider`].
+    pub fn ticks<I>(mut self, ticks: impl IntoIterator<Item = I>) -> Self
+    where
+        I: Into<Tick<T>>,
+    {
+        self.ticks = ticks.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Sets the style of the [`RangeSlider`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Sets the step size of the [`RangeSlider`].
+    pub fn step(mut self, step: T) -> Self {
+        self.step = step;
+        self
+    }
+}
+
+/// The local state of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct State {
+    dragging: Option<Thumb>,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+}
+
+/// One of the handles of a [`RangeSlider`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Thumb {
+    Start,
+    End,
+}
+
+impl<'a, T, Message> RangeSlider<'a, T, Message>
+where
+    T: Copy + Into<f64>,
+{
+    /// Returns the handle that should be dragged to reach the given value.
+    fn thumb_at(&self, value: f64) -> Thumb {
+        let start = self.value.0.into();
+        let end = self.value.1.into();
+
+        if value < start {
+            Thumb::Start
+        } else if value > end {
+            Thumb::End
+        } else if value - start < end - value {
+            Thumb::Start
+        } else if value - start > end - value {
+            Thumb::End
+        } else if end < (*self.range.end()).into() {
+            // Overlapping handles are separated in the direction that has
+            // some room left
+            Thumb::End
+        } else {
+            Thumb::Start
+        }
+    }
+
+    fn change(
+        &mut self,
+        thumb: Thumb,
+        new_value: T,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let (start, end) = self.value;
+
+        let value = match thumb {
+            Thumb::Start if new_value.into() > end.into() => (end, end),
+            Thumb::Start => (new_value, end),
+            Thumb::End if new_value.into() < start.into() => (start, start),
+            Thumb::End => (start, new_value),
+        };
+
+        let is_different =
+            |a: T, b: T| (a.into() - b.into()).abs() > f64::EPSILON;
+
+        if is_different(value.0, start) || is_different(value.1, end) {
+            shell.publish((self.on_change)(value.0..=value.1));
+
+            self.value = value;
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> Widget<Message, Renderer>
+    for RangeSlider<'a, T, Message>
+where
+    T: Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        match self.orientation {
+            Orientation::Horizontal => self.width,
+            Orientation::Vertical => Length::Shrink,
+        }
+    }
+
+    fn height(&self) -> Length {
+        match self.orientation {
+            Orientation::Horizontal => Length::Shrink,
+            Orientation::Vertical => self.width,
+        }
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        Track::layout(
+            renderer,
+            limits,
+            self.orientation,
+            self.width,
+            self.height,
+            &self.ticks,
+        )
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let track =
+            Track::new(layout, self.orientation, self.height, &self.range);
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. })
+                if layout.bounds().contains(cursor_position) =>
+            {
+                if let Some(value) = track.value(cursor_position, self.step) {
+                    let thumb = self.thumb_at(value.into());
+
+                    self.change(thumb, value, shell);
+                    self.state.dragging = Some(thumb);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. })
+                if self.state.dragging.is_some() =>
+            {
+                if let Some(on_release) = self.on_release.clone() {
+                    shell.publish(on_release);
+                }
+                self.state.dragging = None;
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(thumb) = self.state.dragging {
+                    if let Some(value) = track.value(cursor_position, self.step)
+                    {
+                        self.change(thumb, value, shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        let style = if self.state.dragging.is_some() {
+            self.style_sheet.dragging()
+        } else if is_mouse_over {
+            self.style_sheet.hovered()
+        } else {
+            self.style_sheet.active()
+        };
+
+        let track =
+            Track::new(layout, self.orientation, self.height, &self.range);
+
+        let (start, end) = (self.value.0.into(), self.value.1.into());
+
+        track.draw_rail(renderer, &style);
+        track.draw_span(
+            renderer,
+            start,
+            end,
+            &style.handle,
+            self.style_sheet.range_color(),
+        );
+        track.draw_ticks(
+            renderer,
+            &self.ticks,
+            &self.style_sheet.tick_marks(),
+            &style.handle,
+        );
+        track.draw_handle(renderer, start, &style.handle);
+        track.draw_handle(renderer, end, &style.handle);
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        let bounds = layout.bounds();
+        let is_mouse_over = bounds.contains(cursor_position);
+
+        if self.state.dragging.is_some() {
+            mouse::Interaction::Grabbing
+        } else if is_mouse_over {
+            mouse::Interaction::Grab
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.height.hash(state);
+        self.orientation.hash(state);
+
+        for tick in &self.ticks {
+            tick.label.hash(state);
+        }
+    }
+}
+
+impl<'a, T, Message, Renderer> From<RangeSlider<'a, T, Message>>
+    for Element<'a, Message, Renderer>
+where
+    T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
+    Message: 'a + Clone,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        range_slider: RangeSlider<'a, T, Message>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(range_slider)
+    }
+}
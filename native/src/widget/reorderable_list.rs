@@ -0,0 +1,824 @@
+//! Let your users reorder a list of items by dragging them.
+//!
+//! A [`ReorderableList`] has some local [`State`].
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::overlay;
+use crate::renderer;
+use crate::touch;
+use crate::window;
+use crate::{
+    Clipboard, Color, Direction, Element, Hasher, Layout, Length, Point,
+    Rectangle, Shell, Size, Vector, Widget,
+};
+
+use std::hash::Hash;
+use std::time::Instant;
+
+pub use iced_style::reorderable_list::{Style, StyleSheet};
+
+/// A vertical list of items that can be reordered by dragging their handles.
+///
+/// While an item is dragged, the other items move out of its way to show
+/// where it will be dropped. Once it is released, a [`ReorderableList`]
+/// produces a message with the old and new indices of the item, which you
+/// should use to reorder your items.
+///
+/// The focused item of a [`ReorderableList`], which is the last one whose
+/// handle was clicked, can also be moved by pressing `Alt` and the up or down
+/// arrow keys. The arrow keys alone move the focus.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::{reorderable_list, Text};
+/// #
+/// # type ReorderableList<'a, Message> = iced_native::widget::ReorderableList<
+/// #     'a,
+/// #     Message,
+/// #     iced_native::renderer::Null,
+/// # >;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     TaskMoved(usize, usize),
+/// }
+///
+/// let tasks = ["Write code", "Test it", "Ship it"];
+/// let mut state = reorderable_list::State::new();
+///
+/// let list = tasks.iter().fold(
+///     ReorderableList::new(&mut state, Message::TaskMoved),
+///     |list, task| list.push(Text::new(*task)),
+/// );
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct ReorderableList<'a, Message, Renderer> {
+    state: &'a mut State,
+    items: Vec<Element<'a, Message, Renderer>>,
+    on_move: Box<dyn Fn(usize, usize) -> Message>,
+    width: Length,
+    spacing: u16,
+    handle_width: u16,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> ReorderableList<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    /// The default width of the handles of a [`ReorderableList`].
+    pub const DEFAULT_HANDLE_WIDTH: u16 = 24;
+
+    /// How fast the items move out of the way of a dragged item. The higher,
+    /// the faster.
+    const REFLOW_SPEED: f32 = 20.0;
+
+    /// Creates an empty [`ReorderableList`] with some local [`State`].
+    ///
+    /// The given function is called when an item is moved. It receives the
+    /// index of the item and the index that it should be moved to, once
+    /// removed from its current position.
+    pub fn new(
+        state: &'a mut State,
+        on_move: impl Fn(usize, usize) -> Message + 'static,
+    ) -> Self {
+        ReorderableList {
+            state,
+            items: Vec::new(),
+            on_move: Box::new(on_move),
+            width: Length::Fill,
+            spacing: 0,
+            handle_width: Self::DEFAULT_HANDLE_WIDTH,
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Adds an item to the [`ReorderableList`].
+    pub fn push<E>(mut self, item: E) -> Self
+    where
+        E: Into<Element<'a, Message, Renderer>>,
+    {
+        self.items.push(item.into());
+        self
+    }
+
+    /// Sets the width of the [`ReorderableList`].
+    pub fn width(mut self, width: Length) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Sets the vertical spacing _between_ the items of the
+    /// [`ReorderableList`].
+    pub fn spacing(mut self, spacing: u16) -> Self {
+        self.spacing = spacing;
+        self
+    }
+
+    /// Sets the width of the handles of the [`ReorderableList`].
+    pub fn handle_width(mut self, handle_width: u16) -> Self {
+        self.handle_width = handle_width;
+        self
+    }
+
+    /// Sets the style of the [`ReorderableList`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Returns the bounds of the handle of an item, given its bounds.
+    fn handle_bounds(
+        &self,
+        item: Rectangle,
+        direction: Direction,
+    ) -> Rectangle {
+        let width = f32::from(self.handle_width).min(item.width);
+
+        Rectangle {
+            x: if direction.is_right_to_left() {
+                item.x + item.width - width
+            } else {
+                item.x
+            },
+            width,
+            ..item
+        }
+    }
+
+    /// Returns the item being dragged, if any.
+    fn drag(&self) -> Option<Drag> {
+        self.state.drag.filter(|drag| drag.from < self.items.len())
+    }
+
+    /// Returns the bounds where the dragged item is drawn.
+    fn dragged_bounds(&self, layout: Layout<'_>, drag: Drag) -> Rectangle {
+        let bounds = layout.bounds();
+        let item = layout.children().nth(drag.from).unwrap().bounds();
+
+        let y = (drag.cursor_y - drag.grabbed_at)
+            .min(bounds.y + bounds.height - item.height)
+            .max(bounds.y);
+
+        Rectangle { y, ..item }
+    }
+
+    /// Returns the index where the dragged item would be moved to if it was
+    /// released.
+    fn target(&self, layout: Layout<'_>, drag: Drag) -> usize {
+        let center_y = self.dragged_bounds(layout, drag).center_y();
+
+        layout
+            .children()
+            .enumerate()
+            .filter(|(index, item)| {
+                *index != drag.from && item.bounds().center_y() < center_y
+            })
+            .count()
+    }
+
+    /// Returns how far every item needs to move out of the way of the
+    /// dragged item.
+    fn displacements(&self, layout: Layout<'_>) -> Vec<f32> {
+        let drag = match self.drag() {
+            Some(drag) => drag,
+            None => return vec![0.0; self.items.len()],
+        };
+
+        let from = drag.from;
+        let to = self.target(layout, drag);
+        let shift =
+            self.dragged_bounds(layout, drag).height + f32::from(self.spacing);
+
+        (0..self.items.len())
+            .map(|index| {
+                if from < to && index > from && index <= to {
+                    -shift
+                } else if to < from && index >= to && index < from {
+                    shift
+                } else {
+                    0.0
+                }
+            })
+            .collect()
+    }
+
+    /// Moves the items towards their displacements, requesting a redraw
+    /// until they arrive.
+    fn reflow(
+        &mut self,
+        now: Instant,
+        layout: Layout<'_>,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let displacements = self.displacements(layout);
+
+        let elapsed = self
+            .state
+            .reflowed_at
+            .and_then(|reflowed_at| now.checked_duration_since(reflowed_at))
+            .map(|elapsed| elapsed.as_secs_f32())
+            .unwrap_or(0.0);
+
+        let progress = 1.0 - (-elapsed * Self::REFLOW_SPEED).exp();
+        let mut is_settled = true;
+
+        self.state.offsets.resize(displacements.len(), 0.0);
+
+        for (offset, displacement) in
+            self.state.offsets.iter_mut().zip(displacements)
+        {
+            *offset += (displacement - *offset) * progress;
+
+            if (displacement - *offset).abs() < 0.5 {
+                *offset = displacement;
+            } else {
+                is_settled = false;
+            }
+        }
+
+        if is_settled {
+            self.state.reflowed_at = None;
+        } else {
+            self.state.reflowed_at = Some(now);
+
+            shell.request_redraw(window::RedrawRequest::NextFrame);
+        }
+    }
+
+    fn draw_handle(
+        &self,
+        renderer: &mut Renderer,
+        bounds: Rectangle,
+        style: &Style,
+    ) {
+        if let Some(background) = style.handle_background {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds,
+                    border_radius: style.handle_border_radius,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                background,
+            );
+        }
+
+        // The grip is made of three short horizontal lines
+        let line_width = (bounds.width / 2.0).round();
+        let center = bounds.center();
+
+        for line in -1..=1 {
+            renderer.fill_quad(
+                renderer::Quad {
+                    bounds: Rectangle {
+                        x: (center.x - line_width / 2.0).round(),
+                        y: (center.y - 1.0 + line as f32 * 4.0).round(),
+                        width: line_width,
+                        height: 2.0,
+                    },
+                    border_radius: 1.0,
+                    border_width: 0.0,
+                    border_color: Color::TRANSPARENT,
+                },
+                style.handle_color,
+            );
+        }
+    }
+}
+
+/// The local state of a [`ReorderableList`].
+#[derive(Debug, Clone, Default)]
+pub struct State {
+    drag: Option<Drag>,
+    focused: Option<usize>,
+    offsets: Vec<f32>,
+    reflowed_at: Option<Instant>,
+}
+
+/// An item of a [`ReorderableList`] being dragged.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Drag {
+    from: usize,
+    grabbed_at: f32,
+    cursor_y: f32,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> State {
+        State::default()
+    }
+
+    /// Returns whether an item of the [`ReorderableList`] is being dragged.
+    pub fn is_dragging(&self) -> bool {
+        self.drag.is_some()
+    }
+
+    /// Returns the index of the focused item of the [`ReorderableList`], if
+    /// any.
+    pub fn focused(&self) -> Option<usize> {
+        self.focused
+    }
+
+    /// Focuses the item of the [`ReorderableList`] with the given index.
+    pub fn focus(&mut self, index: usize) {
+        self.focused = Some(index);
+    }
+
+    /// Unfocuses the [`ReorderableList`].
+    pub fn unfocus(&mut self) {
+        self.focused = None;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for ReorderableList<'a, Message, Renderer>
+where
+    Renderer: crate::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let limits = limits.width(self.width).height(Length::Shrink);
+        let direction = limits.direction();
+        let handle_width = f32::from(self.handle_width);
+        let spacing = f32::from(self.spacing);
+
+        let item_limits = layout::Limits::new(
+            Size::ZERO,
+            Size::new(
+                (limits.max().width - handle_width).max(0.0),
+                f32::INFINITY,
+            ),
+        )
+        .with_direction(direction);
+
+        let contents: Vec<_> = self
+            .items
+            .iter()
+            .map(|item| item.layout(renderer, &item_limits))
+            .collect();
+
+        let intrinsic_width = contents
+            .iter()
+            .map(|content| content.size().width + handle_width)
+            .fold(0.0, f32::max);
+
+        let intrinsic_height = contents
+            .iter()
+            .map(|content| content.size().height)
+            .sum::<f32>()
+            + spacing * contents.len().saturating_sub(1) as f32;
+
+        let size = limits.resolve(Size::new(intrinsic_width, intrinsic_height));
+
+        let mut y = 0.0;
+
+        let items = contents
+            .into_iter()
+            .map(|mut content| {
+                let content_size = content.size();
+
+                content.move_to(Point::new(
+                    if direction.is_right_to_left() {
+                        size.width - handle_width - content_size.width
+                    } else {
+                        handle_width
+                    },
+                    0.0,
+                ));
+
+                let mut item = layout::Node::with_children(
+                    Size::new(size.width, content_size.height),
+                    vec![content],
+                )
+                .with_direction(direction);
+
+                item.move_to(Point::new(0.0, y));
+                y += content_size.height + spacing;
+
+                item
+            })
+            .collect();
+
+        layout::Node::with_children(size, items).with_direction(direction)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let is_pointer_event =
+            matches!(event, Event::Mouse(_) | Event::Touch(_));
+
+        // The items cannot be interacted with while one of them is dragged
+        if self.drag().is_none() || !is_pointer_event {
+            let event_status = self
+                .items
+                .iter_mut()
+                .zip(layout.children())
+                .map(|(item, layout)| {
+                    item.on_event(
+                        event.clone(),
+                        layout.children().next().unwrap(),
+                        cursor_position,
+                        renderer,
+                        clipboard,
+                        shell,
+                    )
+                })
+                .fold(event::Status::Ignored, event::Status::merge);
+
+            if let event::Status::Captured = event_status {
+                return event::Status::Captured;
+            }
+        }
+
+        let direction = layout.direction();
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                let grabbed = layout.children().position(|item| {
+                    self.handle_bounds(item.bounds(), direction)
+                        .contains(cursor_position)
+                });
+
+                self.state.focused = grabbed;
+
+                if let Some(index) = grabbed {
+                    let item = layout.children().nth(index).unwrap().bounds();
+
+                    self.state.drag = Some(Drag {
+                        from: index,
+                        grabbed_at: cursor_position.y - item.y,
+                        cursor_y: cursor_position.y,
+                    });
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                if let Some(drag) = &mut self.state.drag {
+                    drag.cursor_y = cursor_position.y;
+
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                if let Some(drag) = self.drag() {
+                    let to = self.target(layout, drag);
+
+                    if to != drag.from {
+                        shell.publish((self.on_move)(drag.from, to));
+                    }
+
+                    // The new order of the items matches their current
+                    // positions, so they do not need to move back
+                    self.state.drag = None;
+                    self.state.focused = Some(to);
+                    self.state.offsets.clear();
+                    self.state.reflowed_at = None;
+
+                    return event::Status::Captured;
+                }
+
+                self.state.drag = None;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code: keyboard::KeyCode::Escape,
+                ..
+            }) if self.state.drag.is_some() => {
+                self.state.drag = None;
+
+                shell.request_redraw(window::RedrawRequest::NextFrame);
+
+                return event::Status::Captured;
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code,
+                modifiers,
+            }) if self.state.drag.is_none() => {
+                let index = match self.state.focused {
+                    Some(index) if index < self.items.len() => index,
+                    _ => return event::Status::Ignored,
+                };
+
+                let next = match key_code {
+                    keyboard::KeyCode::Up if index > 0 => index - 1,
+                    keyboard::KeyCode::Down if index + 1 < self.items.len() => {
+                        index + 1
+                    }
+                    keyboard::KeyCode::Up | keyboard::KeyCode::Down => index,
+                    keyboard::KeyCode::Escape => {
+                        self.state.focused = None;
+
+                        return event::Status::Captured;
+                    }
+                    _ => return event::Status::Ignored,
+                };
+
+                if modifiers.alt() && next != index {
+                    shell.publish((self.on_move)(index, next));
+                }
+
+                self.state.focused = Some(next);
+
+                return event::Status::Captured;
+            }
+            Event::Window(window::Event::RedrawRequested(now)) => {
+                // The list may be scrolled while dragging without the cursor
+                // moving, so the dragged item keeps following the cursor
+                if let Some(drag) = &mut self.state.drag {
+                    drag.cursor_y = cursor_position.y;
+                }
+
+                self.reflow(now, layout, shell);
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+        renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if self.drag().is_some() {
+            return mouse::Interaction::Grabbing;
+        }
+
+        let direction = layout.direction();
+
+        let is_mouse_over_handle = layout.children().any(|item| {
+            self.handle_bounds(item.bounds(), direction)
+                .contains(cursor_position)
+        });
+
+        if is_mouse_over_handle {
+            return mouse::Interaction::Grab;
+        }
+
+        self.items
+            .iter()
+            .zip(layout.children())
+            .map(|(item, layout)| {
+                item.mouse_interaction(
+                    layout.children().next().unwrap(),
+                    cursor_position,
+                    viewport,
+                    renderer,
+                )
+            })
+            .max()
+            .unwrap_or_default()
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        viewport: &Rectangle,
+    ) {
+        let direction = layout.direction();
+        let drag = self.drag();
+
+        if let Some(drag) = drag {
+            if let Some(placeholder) = self.style_sheet.dragging().placeholder {
+                let to = self.target(layout, drag);
+                let height = self.dragged_bounds(layout, drag).height;
+                let target = layout.children().nth(to).unwrap().bounds();
+
+                // The space left for the dragged item lines up with the item
+                // it replaces, at the side it is moved from
+                let y = if to > drag.from {
+                    target.y + target.height - height
+                } else {
+                    target.y
+                };
+
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: Rectangle {
+                            y,
+                            height,
+                            ..target
+                        },
+                        border_radius: 0.0,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    placeholder,
+                );
+            }
+        }
+
+        for (index, (item, layout)) in
+            self.items.iter().zip(layout.children()).enumerate()
+        {
+            if drag.map(|drag| drag.from) == Some(index) {
+                continue;
+            }
+
+            let offset = self.state.offsets.get(index).copied().unwrap_or(0.0);
+            let handle = self.handle_bounds(layout.bounds(), direction);
+
+            let cursor_position =
+                Point::new(cursor_position.x, cursor_position.y - offset);
+
+            let item_style = if self.state.focused == Some(index) {
+                self.style_sheet.focused()
+            } else if drag.is_none() && handle.contains(cursor_position) {
+                self.style_sheet.hovered()
+            } else {
+                self.style_sheet.active()
+            };
+
+            renderer.with_translation(Vector::new(0.0, offset), |renderer| {
+                self.draw_handle(renderer, handle, &item_style);
+
+                item.draw(
+                    renderer,
+                    style,
+                    layout.children().next().unwrap(),
+                    cursor_position,
+                    viewport,
+                );
+            });
+        }
+
+        if let Some(drag) = drag {
+            let layout_bounds = layout.bounds();
+            let item_layout = layout.children().nth(drag.from).unwrap();
+            let item_bounds = item_layout.bounds();
+            let dragged_bounds = self.dragged_bounds(layout, drag);
+            let offset = dragged_bounds.y - item_bounds.y;
+
+            // The dragged item is drawn in its own layer, on top of the others
+            renderer.with_layer(layout_bounds, |renderer| {
+                renderer.with_translation(
+                    Vector::new(0.0, offset),
+                    |renderer| {
+                        self.draw_handle(
+                            renderer,
+                            self.handle_bounds(item_bounds, direction),
+                            &self.style_sheet.dragging(),
+                        );
+
+                        self.items[drag.from].draw(
+                            renderer,
+                            style,
+                            item_layout.children().next().unwrap(),
+                            Point::new(
+                                cursor_position.x,
+                                cursor_position.y - offset,
+                            ),
+                            viewport,
+                        );
+                    },
+                );
+            });
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.width.hash(state);
+        self.spacing.hash(state);
+        self.handle_width.hash(state);
+
+        for item in &self.items {
+            item.hash_layout(state);
+        }
+    }
+
+    fn overlay(
+        &mut self,
+        layout: Layout<'_>,
+        renderer: &Renderer,
+    ) -> Option<overlay::Element<'_, Message, Renderer>> {
+        self.items.iter_mut().zip(layout.children()).find_map(
+            |(item, layout)| {
+                item.overlay(layout.children().next().unwrap(), renderer)
+            },
+        )
+    }
+}
+
+impl<'a, Message, Renderer> From<ReorderableList<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + crate::Renderer,
+{
+    fn from(
+        reorderable_list: ReorderableList<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(reorderable_list)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::clipboard;
+    use crate::renderer::Null;
+    use crate::widget::Space;
+
+    fn on_event(
+        state: &mut State,
+        event: Event,
+        cursor_position: Point,
+    ) -> Option<window::RedrawRequest> {
+        let mut list = (0..3).fold(
+            ReorderableList::<(usize, usize), Null>::new(state, |from, to| {
+                (from, to)
+            }),
+            |list, _| list.push(Space::new(Length::Fill, Length::Units(20))),
+        );
+
+        let node = Widget::layout(
+            &list,
+            &Null::new(),
+            &layout::Limits::new(Size::ZERO, Size::new(100.0, f32::INFINITY)),
+        );
+
+        let mut messages = Vec::new();
+        let mut shell = Shell::new(&mut messages);
+
+        let _ = list.on_event(
+            event,
+            Layout::new(&node),
+            cursor_position,
+            &Null::new(),
+            &mut clipboard::Null,
+            &mut shell,
+        );
+
+        shell.redraw_request()
+    }
+
+    #[test]
+    fn follows_the_cursor_on_redraw() {
+        let mut state = State::new();
+
+        let _ = on_event(
+            &mut state,
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left)),
+            Point::new(5.0, 10.0),
+        );
+
+        assert_eq!(
+            state.drag,
+            Some(Drag {
+                from: 0,
+                grabbed_at: 10.0,
+                cursor_y: 10.0,
+            })
+        );
+
+        // A scrollable moved the list under a still cursor
+        let redraw_request = on_event(
+            &mut state,
+            Event::Window(window::Event::RedrawRequested(Instant::now())),
+            Point::new(5.0, 45.0),
+        );
+
+        assert_eq!(state.drag.map(|drag| drag.cursor_y), Some(45.0));
+        assert_eq!(redraw_request, Some(window::RedrawRequest::NextFrame));
+    }
+}
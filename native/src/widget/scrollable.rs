@@ -6,17 +6,24 @@ use crate::overlay;
 use crate::renderer;
 use crate::touch;
 use crate::widget::Column;
+use crate::window;
 use crate::{
-    Alignment, Background, Clipboard, Color, Element, Hasher, Layout, Length,
-    Padding, Point, Rectangle, Shell, Size, Vector, Widget,
+    Alignment, Background, Clipboard, Color, Direction, Element, Hasher,
+    Layout, Length, Padding, Point, Rectangle, Shell, Size, Vector, Widget,
 };
 
+use std::time::Instant;
 use std::{f32, hash::Hash, u32};
 
 pub use iced_style::scrollable::StyleSheet;
 
 /// A widget that can vertically display an infinite amount of content with a
 /// scrollbar.
+///
+/// A [`Scrollable`] scrolls automatically when some of its content is dragged
+/// near its top or bottom edges. Its content is considered dragged when it
+/// captures the movements of the mouse cursor while the left button is
+/// pressed.
 #[allow(missing_debug_implementations)]
 pub struct Scrollable<'a, Message, Renderer> {
     state: &'a mut State,
@@ -31,6 +38,13 @@ pub struct Scrollable<'a, Message, Renderer> {
 }
 
 impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
+    /// The distance from the edges of a [`Scrollable`] where dragged content
+    /// starts scrolling it automatically.
+    const AUTO_SCROLL_MARGIN: f32 = 30.0;
+
+    /// The maximum speed of automatic scrolling, in pixels per second.
+    const AUTO_SCROLL_SPEED: f32 = 600.0;
+
     /// Creates a new [`Scrollable`] with the given [`State`].
     pub fn new(state: &'a mut State) -> Self {
         Scrollable {
@@ -158,8 +172,70 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
         }
     }
 
+    /// Returns the speed at which dragged content should scroll the
+    /// [`Scrollable`] automatically, in pixels per second.
+    ///
+    /// It is negative when scrolling up and zero when the content is not
+    /// dragged near an edge.
+    fn auto_scroll_speed(
+        &self,
+        cursor_position: Point,
+        bounds: Rectangle,
+    ) -> f32 {
+        if !self.state.is_content_dragged {
+            return 0.0;
+        }
+
+        let margin = Self::AUTO_SCROLL_MARGIN;
+        let top = bounds.y + margin - cursor_position.y;
+        let bottom = cursor_position.y - (bounds.y + bounds.height - margin);
+
+        if top > 0.0 {
+            -Self::AUTO_SCROLL_SPEED * (top / margin).min(1.0)
+        } else if bottom > 0.0 {
+            Self::AUTO_SCROLL_SPEED * (bottom / margin).min(1.0)
+        } else {
+            0.0
+        }
+    }
+
+    fn auto_scroll(
+        &mut self,
+        now: Instant,
+        cursor_position: Point,
+        bounds: Rectangle,
+        content_bounds: Rectangle,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let speed = self.auto_scroll_speed(cursor_position, bounds);
+
+        if speed == 0.0 {
+            self.state.auto_scrolled_at = None;
+            return;
+        }
+
+        if let Some(elapsed) =
+            self.state.auto_scrolled_at.and_then(|auto_scrolled_at| {
+                now.checked_duration_since(auto_scrolled_at)
+            })
+        {
+            self.state.scroll(
+                -speed * elapsed.as_secs_f32(),
+                bounds,
+                content_bounds,
+            );
+
+            self.notify_on_scroll(bounds, content_bounds, shell);
+        }
+
+        self.state.auto_scrolled_at = Some(now);
+
+        shell.request_redraw(window::RedrawRequest::NextFrame);
+    }
+
     fn scrollbar(
         &self,
+        direction: Direction,
         bounds: Rectangle,
         content_bounds: Rectangle,
     ) -> Option<Scrollbar> {
@@ -169,16 +245,31 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
             let outer_width = self.scrollbar_width.max(self.scroller_width)
                 + 2 * self.scrollbar_margin;
 
+            // The scrollbar is placed on the left side when the content flows
+            // from right to left.
+            let x = |width: u16| {
+                if direction.is_right_to_left() {
+                    bounds.x + f32::from(outer_width / 2 + width / 2)
+                        - f32::from(width)
+                } else {
+                    bounds.x + bounds.width
+                        - f32::from(outer_width / 2 + width / 2)
+                }
+            };
+
             let outer_bounds = Rectangle {
-                x: bounds.x + bounds.width - outer_width as f32,
+                x: if direction.is_right_to_left() {
+                    bounds.x
+                } else {
+                    bounds.x + bounds.width - outer_width as f32
+                },
                 y: bounds.y,
                 width: outer_width as f32,
                 height: bounds.height,
             };
 
             let scrollbar_bounds = Rectangle {
-                x: bounds.x + bounds.width
-                    - f32::from(outer_width / 2 + self.scrollbar_width / 2),
+                x: x(self.scrollbar_width),
                 y: bounds.y,
                 width: self.scrollbar_width as f32,
                 height: bounds.height,
@@ -189,8 +280,7 @@ impl<'a, Message, Renderer: crate::Renderer> Scrollable<'a, Message, Renderer> {
             let y_offset = offset as f32 * ratio;
 
             let scroller_bounds = Rectangle {
-                x: bounds.x + bounds.width
-                    - f32::from(outer_width / 2 + self.scroller_width / 2),
+                x: x(self.scroller_width),
                 y: scrollbar_bounds.y + y_offset,
                 width: self.scroller_width as f32,
                 height: scroller_height,
@@ -235,12 +325,14 @@ where
         let child_limits = layout::Limits::new(
             Size::new(limits.min().width, 0.0),
             Size::new(limits.max().width, f32::INFINITY),
-        );
+        )
+        .with_direction(limits.direction());
 
         let content = self.content.layout(renderer, &child_limits);
         let size = limits.resolve(content.size());
 
         layout::Node::with_children(size, vec![content])
+            .with_direction(limits.direction())
     }
 
     fn on_event(
@@ -258,14 +350,29 @@ where
         let content = layout.children().next().unwrap();
         let content_bounds = content.bounds();
 
-        let scrollbar = self.scrollbar(bounds, content_bounds);
+        let scrollbar =
+            self.scrollbar(layout.direction(), bounds, content_bounds);
         let is_mouse_over_scrollbar = scrollbar
             .as_ref()
             .map(|scrollbar| scrollbar.is_mouse_over(cursor_position))
             .unwrap_or(false);
 
+        if let Event::Window(window::Event::RedrawRequested(now)) = event {
+            self.auto_scroll(
+                now,
+                cursor_position,
+                bounds,
+                content_bounds,
+                shell,
+            );
+        }
+
         let event_status = {
-            let cursor_position = if is_mouse_over && !is_mouse_over_scrollbar {
+            // Dragged content keeps track of the cursor outside of the
+            // scrollable, so it can be dragged past its edges
+            let cursor_position = if (is_mouse_over && !is_mouse_over_scrollbar)
+                || self.state.is_content_dragged
+            {
                 Point::new(
                     cursor_position.x,
                     cursor_position.y
@@ -289,6 +396,32 @@ where
             )
         };
 
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                self.state.is_content_pressed =
+                    is_mouse_over && !is_mouse_over_scrollbar;
+            }
+            Event::Mouse(mouse::Event::ButtonReleased(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerLifted { .. })
+            | Event::Touch(touch::Event::FingerLost { .. }) => {
+                self.state.is_content_pressed = false;
+                self.state.is_content_dragged = false;
+            }
+            Event::Mouse(mouse::Event::CursorMoved { .. })
+            | Event::Touch(touch::Event::FingerMoved { .. }) => {
+                self.state.is_content_dragged = self.state.is_content_pressed
+                    && event_status == event::Status::Captured;
+
+                if self.state.auto_scrolled_at.is_none()
+                    && self.auto_scroll_speed(cursor_position, bounds) != 0.0
+                {
+                    shell.request_redraw(window::RedrawRequest::NextFrame);
+                }
+            }
+            _ => {}
+        }
+
         if let event::Status::Captured = event_status {
             return event::Status::Captured;
         }
@@ -432,7 +565,8 @@ where
         let bounds = layout.bounds();
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
-        let scrollbar = self.scrollbar(bounds, content_bounds);
+        let scrollbar =
+            self.scrollbar(layout.direction(), bounds, content_bounds);
 
         let is_mouse_over = bounds.contains(cursor_position);
         let is_mouse_over_scrollbar = scrollbar
@@ -475,7 +609,8 @@ where
         let content_layout = layout.children().next().unwrap();
         let content_bounds = content_layout.bounds();
         let offset = self.state.offset(bounds, content_bounds);
-        let scrollbar = self.scrollbar(bounds, content_bounds);
+        let scrollbar =
+            self.scrollbar(layout.direction(), bounds, content_bounds);
 
         let is_mouse_over = bounds.contains(cursor_position);
         let is_mouse_over_scrollbar = scrollbar
@@ -606,6 +741,9 @@ pub struct State {
     scroller_grabbed_at: Option<f32>,
     scroll_box_touched_at: Option<Point>,
     offset: Offset,
+    is_content_pressed: bool,
+    is_content_dragged: bool,
+    auto_scrolled_at: Option<Instant>,
 }
 
 impl Default for State {
@@ -614,6 +752,9 @@ impl Default for State {
             scroller_grabbed_at: None,
             scroll_box_touched_at: None,
             offset: Offset::Absolute(0.0),
+            is_content_pressed: false,
+            is_content_dragged: false,
+            auto_scrolled_at: None,
         }
     }
 }
@@ -775,3 +916,96 @@ where
         Element::new(scrollable)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    use std::time::Duration;
+
+    const BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 100.0,
+        width: 100.0,
+        height: 200.0,
+    };
+
+    const CONTENT_BOUNDS: Rectangle = Rectangle {
+        x: 0.0,
+        y: 100.0,
+        width: 100.0,
+        height: 1000.0,
+    };
+
+    fn dragged() -> State {
+        State {
+            is_content_pressed: true,
+            is_content_dragged: true,
+            ..State::new()
+        }
+    }
+
+    #[test]
+    fn auto_scrolls_near_the_edges() {
+        let mut state = dragged();
+        let scrollable = Scrollable::<(), Null>::new(&mut state);
+
+        let speed =
+            |y| scrollable.auto_scroll_speed(Point::new(50.0, y), BOUNDS);
+
+        assert_eq!(speed(200.0), 0.0);
+        assert_eq!(speed(130.0), 0.0);
+        assert_eq!(speed(115.0), -300.0);
+        assert_eq!(speed(100.0), -600.0);
+        assert_eq!(speed(50.0), -600.0);
+        assert_eq!(speed(270.0), 0.0);
+        assert_eq!(speed(285.0), 300.0);
+        assert_eq!(speed(400.0), 600.0);
+    }
+
+    #[test]
+    fn does_not_auto_scroll_without_dragging() {
+        let mut state = State::new();
+        let scrollable = Scrollable::<(), Null>::new(&mut state);
+
+        assert_eq!(
+            scrollable.auto_scroll_speed(Point::new(50.0, 100.0), BOUNDS),
+            0.0
+        );
+    }
+
+    #[test]
+    fn auto_scrolls_over_time() {
+        let mut state = dragged();
+        let start = Instant::now();
+        let bottom = Point::new(50.0, 300.0);
+
+        let mut auto_scroll = |now| {
+            let mut messages = Vec::new();
+            let mut shell = Shell::new(&mut messages);
+
+            Scrollable::<(), Null>::new(&mut state).auto_scroll(
+                now,
+                bottom,
+                BOUNDS,
+                CONTENT_BOUNDS,
+                &mut shell,
+            );
+
+            shell.redraw_request()
+        };
+
+        assert_eq!(auto_scroll(start), Some(window::RedrawRequest::NextFrame));
+        assert_eq!(
+            auto_scroll(start + Duration::from_millis(100)),
+            Some(window::RedrawRequest::NextFrame)
+        );
+
+        assert_eq!(state.offset(BOUNDS, CONTENT_BOUNDS), 60);
+        assert_eq!(
+            state.auto_scrolled_at,
+            Some(start + Duration::from_millis(100))
+        );
+    }
+}
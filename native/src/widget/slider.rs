@@ -1,10 +1,12 @@
 //! Display an interactive selector of a single value from a range of values.
 //!
 //! A [`Slider`] has some local [`State`].
+use crate::alignment;
 use crate::event::{self, Event};
 use crate::layout;
 use crate::mouse;
 use crate::renderer;
+use crate::text::{self, Text};
 use crate::touch;
 use crate::{
     Background, Clipboard, Color, Element, Hasher, Layout, Length, Point,
@@ -14,12 +16,18 @@ use crate::{
 use std::hash::Hash;
 use std::ops::RangeInclusive;
 
-pub use iced_style::slider::{Handle, HandleShape, Style, StyleSheet};
+pub use iced_style::slider::{
+    Handle, HandleShape, Style, StyleSheet, TickMarks,
+};
 
 /// An horizontal bar and a handle that selects a single value from a range of
 /// values.
 ///
-/// A [`Slider`] will try to fill the horizontal space of its container.
+/// A [`Slider`] will try to fill the horizontal space of its container, or
+/// the vertical one when its [`Orientation`] is vertical.
+///
+/// When laid out right-to-left, the start of the range is placed on the right
+/// side of an horizontal [`Slider`].
 ///
 /// The [`Slider`] range of numeric values is generic and its step size defaults
 /// to 1 unit.
@@ -50,6 +58,8 @@ pub struct Slider<'a, T, Message> {
     on_release: Option<Message>,
     width: Length,
     height: u16,
+    orientation: Orientation,
+    ticks: Vec<Tick<T>>,
     style_sheet: Box<dyn StyleSheet + 'a>,
 }
 
@@ -79,17 +89,7 @@ where
     where
         F: 'static + Fn(T) -> Message,
     {
-        let value = if value >= *range.start() {
-            value
-        } else {
-            *range.start()
-        };
-
-        let value = if value <= *range.end() {
-            value
-        } else {
-            *range.end()
-        };
+        let value = clamp(value, &range);
 
         Slider {
             state,
@@ -100,6 +100,8 @@ where
             on_release: None,
             width: Length::Fill,
             height: Self::DEFAULT_HEIGHT,
+            orientation: Orientation::default(),
+            ticks: Vec::new(),
             style_sheet: Default::default(),
         }
     }
@@ -116,17 +118,39 @@ where
     }
 
     /// Sets the width of the [`Slider`].
+    ///
+    /// When the [`Slider`] is vertical, this is its height instead.
     pub fn width(mut self, width: Length) -> Self {
         self.width = width;
         self
     }
 
     /// Sets the height of the [`Slider`].
+    ///
+    /// When the [`Slider`] is vertical, this is its width instead.
     pub fn height(mut self, height: u16) -> Self {
         self.height = height;
         self
     }
 
+    /// Sets the [`Orientation`] of the [`Slider`].
+    pub fn orientation(mut self, orientation: Orientation) -> Self {
+        self.orientation = orientation;
+        self
+    }
+
+    /// Sets the tick marks of the [`Slider`].
+    ///
+    /// Ticks with a label reserve some space next to the rail of the
+    /// [`Slider`] to display it.
+    pub fn ticks<I>(mut self, ticks: impl IntoIterator<Item = I>) -> Self
+    where
+        I: Into<Tick<T>>,
+    {
+        self.ticks = ticks.into_iter().map(Into::into).collect();
+        self
+    }
+
     /// Sets the style of the [`Slider`].
     pub fn style(
         mut self,
@@ -156,32 +180,85 @@ impl State {
     }
 }
 
+/// The direction in which a [`Slider`] is laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Orientation {
+    /// The rail of the [`Slider`] goes from left to right.
+    Horizontal,
+
+    /// The rail of the [`Slider`] goes from bottom to top.
+    Vertical,
+}
+
+impl Default for Orientation {
+    fn default() -> Self {
+        Orientation::Horizontal
+    }
+}
+
+/// A mark on the rail of a [`Slider`] at a specific value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Tick<T> {
+    /// The value where the [`Tick`] is placed.
+    pub value: T,
+
+    /// The label displayed next to the [`Tick`], if any.
+    pub label: Option<String>,
+}
+
+impl<T> Tick<T> {
+    /// Creates a new [`Tick`] at the given value, without a label.
+    pub fn new(value: T) -> Self {
+        Tick { value, label: None }
+    }
+
+    /// Sets the label of the [`Tick`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+}
+
+impl<T> From<T> for Tick<T> {
+    fn from(value: T) -> Self {
+        Tick::new(value)
+    }
+}
+
 impl<'a, T, Message, Renderer> Widget<Message, Renderer>
     for Slider<'a, T, Message>
 where
     T: Copy + Into<f64> + num_traits::FromPrimitive,
     Message: Clone,
-    Renderer: crate::Renderer,
+    Renderer: text::Renderer,
 {
     fn width(&self) -> Length {
-        self.width
+        match self.orientation {
+            Orientation::Horizontal => self.width,
+            Orientation::Vertical => Length::Shrink,
+        }
     }
 
     fn height(&self) -> Length {
-        Length::Shrink
+        match self.orientation {
+            Orientation::Horizontal => Length::Shrink,
+            Orientation::Vertical => self.width,
+        }
     }
 
     fn layout(
         &self,
-        _renderer: &Renderer,
+        renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let limits =
-            limits.width(self.width).height(Length::Units(self.height));
-
-        let size = limits.resolve(Size::ZERO);
-
-        layout::Node::new(size)
+        Track::layout(
+            renderer,
+            limits,
+            self.orientation,
+            self.width,
+            self.height,
+            &self.ticks,
+        )
     }
 
     fn on_event(
@@ -194,29 +271,13 @@ where
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
         let is_dragging = self.state.is_dragging;
+        let track =
+            Track::new(layout, self.orientation, self.height, &self.range);
 
         let mut change = || {
-            let bounds = layout.bounds();
-            let new_value = if cursor_position.x <= bounds.x {
-                *self.range.start()
-            } else if cursor_position.x >= bounds.x + bounds.width {
-                *self.range.end()
-            } else {
-                let step = self.step.into();
-                let start = (*self.range.start()).into();
-                let end = (*self.range.end()).into();
-
-                let percent = f64::from(cursor_position.x - bounds.x)
-                    / f64::from(bounds.width);
-
-                let steps = (percent * (end - start) / step).round();
-                let value = steps * step + start;
-
-                if let Some(value) = T::from_f64(value) {
-                    value
-                } else {
-                    return;
-                }
+            let new_value = match track.value(cursor_position, self.step) {
+                Some(value) => value,
+                None => return,
             };
 
             if (self.value.into() - new_value.into()).abs() > f64::EPSILON {
@@ -281,79 +342,17 @@ where
             self.style_sheet.active()
         };
 
-        let rail_y = bounds.y + (bounds.height / 2.0).round();
+        let track =
+            Track::new(layout, self.orientation, self.height, &self.range);
 
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: rail_y,
-                    width: bounds.width,
-                    height: 2.0,
-                },
-                border_radius: 0.0,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            },
-            style.rail_colors.0,
-        );
-
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: Rectangle {
-                    x: bounds.x,
-                    y: rail_y + 2.0,
-                    width: bounds.width,
-                    height: 2.0,
-                },
-                border_radius: 0.0,
-                border_width: 0.0,
-                border_color: Color::TRANSPARENT,
-            },
-            Background::Color(style.rail_colors.1),
-        );
-
-        let (handle_width, handle_height, handle_border_radius) = match style
-            .handle
-            .shape
-        {
-            HandleShape::Circle { radius } => {
-                (radius * 2.0, radius * 2.0, radius)
-            }
-            HandleShape::Rectangle {
-                width,
-                border_radius,
-            } => (f32::from(width), f32::from(bounds.height), border_radius),
-        };
-
-        let value = self.value.into() as f32;
-        let (range_start, range_end) = {
-            let (start, end) = self.range.clone().into_inner();
-
-            (start.into() as f32, end.into() as f32)
-        };
-
-        let handle_offset = if range_start >= range_end {
-            0.0
-        } else {
-            (bounds.width - handle_width) * (value - range_start)
-                / (range_end - range_start)
-        };
-
-        renderer.fill_quad(
-            renderer::Quad {
-                bounds: Rectangle {
-                    x: bounds.x + handle_offset.round(),
-                    y: rail_y - handle_height / 2.0,
-                    width: handle_width,
-                    height: handle_height,
-                },
-                border_radius: handle_border_radius,
-                border_width: style.handle.border_width,
-                border_color: style.handle.border_color,
-            },
-            style.handle.color,
+        track.draw_rail(renderer, &style);
+        track.draw_ticks(
+            renderer,
+            &self.ticks,
+            &self.style_sheet.tick_marks(),
+            &style.handle,
         );
+        track.draw_handle(renderer, self.value.into(), &style.handle);
     }
 
     fn mouse_interaction(
@@ -380,6 +379,12 @@ where
         std::any::TypeId::of::<Marker>().hash(state);
 
         self.width.hash(state);
+        self.height.hash(state);
+        self.orientation.hash(state);
+
+        for tick in &self.ticks {
+            tick.label.hash(state);
+        }
     }
 }
 
@@ -388,9 +393,421 @@ impl<'a, T, Message, Renderer> From<Slider<'a, T, Message>>
 where
     T: 'a + Copy + Into<f64> + num_traits::FromPrimitive,
     Message: 'a + Clone,
-    Renderer: 'a + crate::Renderer,
+    Renderer: 'a + text::Renderer,
 {
     fn from(slider: Slider<'a, T, Message>) -> Element<'a, Message, Renderer> {
         Element::new(slider)
     }
 }
+
+/// Clamps a value to the given range.
+pub(crate) fn clamp<T: Copy + PartialOrd>(
+    value: T,
+    range: &RangeInclusive<T>,
+) -> T {
+    if value < *range.start() {
+        *range.start()
+    } else if value > *range.end() {
+        *range.end()
+    } else {
+        value
+    }
+}
+
+/// The rail of a slider, mapping values of its range to positions on the
+/// screen and back.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Track {
+    bounds: Rectangle,
+    orientation: Orientation,
+    is_reversed: bool,
+    start: f64,
+    end: f64,
+}
+
+impl Track {
+    /// The space between the rail of a slider and the labels of its ticks.
+    const LABEL_SPACING: u16 = 4;
+
+    /// Lays out a slider with the given length, thickness, and ticks.
+    pub fn layout<T, Renderer>(
+        renderer: &Renderer,
+        limits: &layout::Limits,
+        orientation: Orientation,
+        length: Length,
+        thickness: u16,
+        ticks: &[Tick<T>],
+    ) -> layout::Node
+    where
+        Renderer: text::Renderer,
+    {
+        let mut labels = ticks
+            .iter()
+            .filter_map(|tick| tick.label.as_deref())
+            .peekable();
+
+        let labels_size = if labels.peek().is_none() {
+            0
+        } else {
+            let size = match orientation {
+                Orientation::Horizontal => f32::from(renderer.default_size()),
+                Orientation::Vertical => labels
+                    .map(|label| {
+                        renderer.measure_width(
+                            label,
+                            renderer.default_size(),
+                            Renderer::Font::default(),
+                        )
+                    })
+                    .fold(0.0, f32::max),
+            };
+
+            Self::LABEL_SPACING + size.ceil() as u16
+        };
+
+        let thickness = Length::Units(thickness.saturating_add(labels_size));
+
+        let limits = match orientation {
+            Orientation::Horizontal => limits.width(length).height(thickness),
+            Orientation::Vertical => limits.width(thickness).height(length),
+        };
+
+        let size = limits.resolve(Size::ZERO);
+
+        layout::Node::new(size).with_direction(limits.direction())
+    }
+
+    /// Creates the [`Track`] of a slider with the given layout.
+    pub fn new<T: Copy + Into<f64>>(
+        layout: Layout<'_>,
+        orientation: Orientation,
+        thickness: u16,
+        range: &RangeInclusive<T>,
+    ) -> Self {
+        let bounds = layout.bounds();
+        let thickness = f32::from(thickness);
+
+        let bounds = match orientation {
+            Orientation::Horizontal => Rectangle {
+                height: thickness.min(bounds.height),
+                ..bounds
+            },
+            Orientation::Vertical => Rectangle {
+                width: thickness.min(bounds.width),
+                ..bounds
+            },
+        };
+
+        Track {
+            bounds,
+            orientation,
+            is_reversed: orientation == Orientation::Horizontal
+                && layout.direction().is_right_to_left(),
+            start: (*range.start()).into(),
+            end: (*range.end()).into(),
+        }
+    }
+
+    /// Returns the value of the range under the given cursor position,
+    /// snapped to the given step.
+    pub fn value<T>(&self, cursor_position: Point, step: T) -> Option<T>
+    where
+        T: Into<f64> + num_traits::FromPrimitive,
+    {
+        let length = self.length();
+
+        let distance = match self.orientation {
+            Orientation::Horizontal if self.is_reversed => {
+                self.bounds.x + self.bounds.width - cursor_position.x
+            }
+            Orientation::Horizontal => cursor_position.x - self.bounds.x,
+            Orientation::Vertical => {
+                self.bounds.y + self.bounds.height - cursor_position.y
+            }
+        };
+
+        let value = if distance <= 0.0 {
+            self.start
+        } else if distance >= length {
+            self.end
+        } else {
+            let step = step.into();
+            let percent = f64::from(distance) / f64::from(length);
+
+            let steps = (percent * (self.end - self.start) / step).round();
+
+            steps * step + self.start
+        };
+
+        T::from_f64(value)
+    }
+
+    /// Draws the rail of the [`Track`].
+    pub fn draw_rail<Renderer: crate::Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        style: &Style,
+    ) {
+        let (first, second) = match self.orientation {
+            Orientation::Horizontal => {
+                let rail = Rectangle {
+                    y: self.rail_center(),
+                    height: 2.0,
+                    ..self.bounds
+                };
+
+                (
+                    rail,
+                    Rectangle {
+                        y: rail.y + 2.0,
+                        ..rail
+                    },
+                )
+            }
+            Orientation::Vertical => {
+                let rail = Rectangle {
+                    x: self.rail_center(),
+                    width: 2.0,
+                    ..self.bounds
+                };
+
+                (
+                    rail,
+                    Rectangle {
+                        x: rail.x + 2.0,
+                        ..rail
+                    },
+                )
+            }
+        };
+
+        fill(renderer, first, style.rail_colors.0);
+        fill(renderer, second, style.rail_colors.1);
+    }
+
+    /// Draws the part of the rail between two values of the range with the
+    /// given color.
+    pub fn draw_span<Renderer: crate::Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        from: f64,
+        to: f64,
+        handle: &Handle,
+        color: Color,
+    ) {
+        let from = self.handle_center(from, handle);
+        let to = self.handle_center(to, handle);
+
+        let span = match self.orientation {
+            Orientation::Horizontal => Rectangle {
+                x: from.min(to),
+                y: self.rail_center(),
+                width: (to - from).abs(),
+                height: 2.0,
+            },
+            Orientation::Vertical => Rectangle {
+                x: self.rail_center(),
+                y: from.min(to),
+                width: 2.0,
+                height: (to - from).abs(),
+            },
+        };
+
+        fill(renderer, span, color);
+    }
+
+    /// Draws the given ticks and their labels.
+    pub fn draw_ticks<T, Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        ticks: &[Tick<T>],
+        tick_marks: &TickMarks,
+        handle: &Handle,
+    ) where
+        T: Copy + Into<f64>,
+        Renderer: text::Renderer,
+    {
+        let rail_center = self.rail_center() + 2.0;
+        let label_size = f32::from(renderer.default_size());
+        let label_spacing = f32::from(Self::LABEL_SPACING);
+
+        for tick in ticks {
+            let center = self.handle_center(tick.value.into(), handle);
+
+            let (mark, label_bounds, horizontal_alignment, vertical_alignment) =
+                match self.orientation {
+                    Orientation::Horizontal => (
+                        Rectangle {
+                            x: center - tick_marks.width / 2.0,
+                            y: rail_center - tick_marks.length / 2.0,
+                            width: tick_marks.width,
+                            height: tick_marks.length,
+                        },
+                        Rectangle {
+                            x: center,
+                            y: self.bounds.y
+                                + self.bounds.height
+                                + label_spacing,
+                            width: f32::INFINITY,
+                            height: label_size,
+                        },
+                        alignment::Horizontal::Center,
+                        alignment::Vertical::Top,
+                    ),
+                    Orientation::Vertical => (
+                        Rectangle {
+                            x: rail_center - tick_marks.length / 2.0,
+                            y: center - tick_marks.width / 2.0,
+                            width: tick_marks.length,
+                            height: tick_marks.width,
+                        },
+                        Rectangle {
+                            x: self.bounds.x
+                                + self.bounds.width
+                                + label_spacing,
+                            y: center,
+                            width: f32::INFINITY,
+                            height: label_size,
+                        },
+                        alignment::Horizontal::Left,
+                        alignment::Vertical::Center,
+                    ),
+                };
+
+            fill(renderer, mark, tick_marks.color);
+
+            if let Some(label) = &tick.label {
+                renderer.fill_text(Text {
+                    content: label,
+                    bounds: label_bounds,
+                    size: label_size,
+                    color: tick_marks.label_color,
+                    font: Renderer::Font::default(),
+                    horizontal_alignment,
+                    vertical_alignment,
+                    format: text::Format::default(),
+                    decorations: &[],
+                });
+            }
+        }
+    }
+
+    /// Draws a handle at the given value of the range.
+    pub fn draw_handle<Renderer: crate::Renderer>(
+        &self,
+        renderer: &mut Renderer,
+        value: f64,
+        handle: &Handle,
+    ) {
+        let (extent, thickness, border_radius) = self.handle_size(handle);
+        let position = self.handle_position(value, extent);
+        let rail_center = self.rail_center();
+
+        let bounds = match self.orientation {
+            Orientation::Horizontal => Rectangle {
+                x: position,
+                y: rail_center - thickness / 2.0,
+                width: extent,
+                height: thickness,
+            },
+            Orientation::Vertical => Rectangle {
+                x: rail_center - thickness / 2.0,
+                y: position,
+                width: thickness,
+                height: extent,
+            },
+        };
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius,
+                border_width: handle.border_width,
+                border_color: handle.border_color,
+            },
+            handle.color,
+        );
+    }
+
+    fn length(&self) -> f32 {
+        match self.orientation {
+            Orientation::Horizontal => self.bounds.width,
+            Orientation::Vertical => self.bounds.height,
+        }
+    }
+
+    fn rail_center(&self) -> f32 {
+        match self.orientation {
+            Orientation::Horizontal => {
+                self.bounds.y + (self.bounds.height / 2.0).round()
+            }
+            Orientation::Vertical => {
+                self.bounds.x + (self.bounds.width / 2.0).round()
+            }
+        }
+    }
+
+    /// Returns the extent of a handle along the rail, its thickness across
+    /// it, and its border radius.
+    fn handle_size(&self, handle: &Handle) -> (f32, f32, f32) {
+        match handle.shape {
+            HandleShape::Circle { radius } => {
+                (radius * 2.0, radius * 2.0, radius)
+            }
+            HandleShape::Rectangle {
+                width,
+                border_radius,
+            } => {
+                let thickness = match self.orientation {
+                    Orientation::Horizontal => self.bounds.height,
+                    Orientation::Vertical => self.bounds.width,
+                };
+
+                (f32::from(width), thickness, border_radius)
+            }
+        }
+    }
+
+    /// Returns the position along the rail where a handle with the given
+    /// extent starts when placed at the given value.
+    fn handle_position(&self, value: f64, extent: f32) -> f32 {
+        let offset = if self.start >= self.end {
+            0.0
+        } else {
+            (self.length() - extent)
+                * ((value - self.start) / (self.end - self.start)) as f32
+        };
+
+        match self.orientation {
+            Orientation::Horizontal if self.is_reversed => {
+                self.bounds.x + (self.bounds.width - extent - offset).round()
+            }
+            Orientation::Horizontal => self.bounds.x + offset.round(),
+            Orientation::Vertical => {
+                self.bounds.y + (self.bounds.height - extent - offset).round()
+            }
+        }
+    }
+
+    fn handle_center(&self, value: f64, handle: &Handle) -> f32 {
+        let (extent, _, _) = self.handle_size(handle);
+
+        self.handle_position(value, extent) + extent / 2.0
+    }
+}
+
+fn fill<Renderer: crate::Renderer>(
+    renderer: &mut Renderer,
+    bounds: Rectangle,
+    color: Color,
+) {
+    renderer.fill_quad(
+        renderer::Quad {
+            bounds,
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        },
+        Background::Color(color),
+    );
+}
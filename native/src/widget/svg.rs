@@ -1,8 +1,12 @@
 //! Display vector graphics in your application.
+use crate::alignment;
 use crate::layout;
 use crate::renderer;
 use crate::svg::{self, Handle};
-use crate::{Element, Hasher, Layout, Length, Point, Rectangle, Size, Widget};
+use crate::widget::image;
+use crate::{
+    ContentFit, Element, Hasher, Layout, Length, Point, Rectangle, Widget,
+};
 
 use std::hash::Hash;
 use std::path::PathBuf;
@@ -13,11 +17,16 @@ use std::path::PathBuf;
 ///
 /// [`Svg`] images can have a considerable rendering cost when resized,
 /// specially when they are complex.
+///
+/// How the image fits its bounds can be changed with a [`ContentFit`].
 #[derive(Debug, Clone)]
 pub struct Svg {
     handle: Handle,
     width: Length,
     height: Length,
+    content_fit: ContentFit,
+    horizontal_alignment: alignment::Horizontal,
+    vertical_alignment: alignment::Vertical,
 }
 
 impl Svg {
@@ -27,6 +36,9 @@ impl Svg {
             handle: handle.into(),
             width: Length::Fill,
             height: Length::Shrink,
+            content_fit: ContentFit::default(),
+            horizontal_alignment: alignment::Horizontal::Center,
+            vertical_alignment: alignment::Vertical::Center,
         }
     }
 
@@ -47,6 +59,28 @@ impl Svg {
         self.height = height;
         self
     }
+
+    /// Sets the [`ContentFit`] of the [`Svg`].
+    ///
+    /// Defaults to [`ContentFit::Contain`].
+    pub fn content_fit(mut self, content_fit: ContentFit) -> Self {
+        self.content_fit = content_fit;
+        self
+    }
+
+    /// Sets the horizontal alignment of the image within the boundaries of
+    /// the [`Svg`].
+    pub fn align_x(mut self, alignment: alignment::Horizontal) -> Self {
+        self.horizontal_alignment = alignment;
+        self
+    }
+
+    /// Sets the vertical alignment of the image within the boundaries of the
+    /// [`Svg`].
+    pub fn align_y(mut self, alignment: alignment::Vertical) -> Self {
+        self.vertical_alignment = alignment;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Svg
@@ -66,24 +100,13 @@ where
         renderer: &Renderer,
         limits: &layout::Limits,
     ) -> layout::Node {
-        let (width, height) = renderer.dimensions(&self.handle);
-
-        let aspect_ratio = width as f32 / height as f32;
-
-        let mut size = limits
-            .width(self.width)
-            .height(self.height)
-            .resolve(Size::new(width as f32, height as f32));
-
-        let viewport_aspect_ratio = size.width / size.height;
-
-        if viewport_aspect_ratio > aspect_ratio {
-            size.width = width as f32 * size.height / height as f32;
-        } else {
-            size.height = height as f32 * size.width / width as f32;
-        }
-
-        layout::Node::new(size)
+        image::layout(
+            limits,
+            renderer.dimensions(&self.handle),
+            self.content_fit,
+            self.width,
+            self.height,
+        )
     }
 
     fn draw(
@@ -94,7 +117,27 @@ where
         _cursor_position: Point,
         _viewport: &Rectangle,
     ) {
-        renderer.draw(self.handle.clone(), layout.bounds())
+        let bounds = layout.bounds();
+
+        let content_bounds = image::content_bounds(
+            bounds,
+            renderer.dimensions(&self.handle),
+            self.content_fit,
+            self.horizontal_alignment,
+            self.vertical_alignment,
+        );
+
+        let render = |renderer: &mut Renderer| {
+            renderer.draw(self.handle.clone(), content_bounds);
+        };
+
+        if content_bounds.width > bounds.width
+            || content_bounds.height > bounds.height
+        {
+            renderer.with_layer(bounds, render);
+        } else {
+            render(renderer);
+        }
     }
 
     fn hash_layout(&self, state: &mut Hasher) {
@@ -103,6 +146,7 @@ where
         self.handle.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.content_fit.hash(state);
     }
 }
 
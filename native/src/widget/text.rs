@@ -32,6 +32,8 @@ pub struct Text<Renderer: text::Renderer> {
     height: Length,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    format: text::Format,
+    decorations: Vec<text::Decoration>,
 }
 
 impl<Renderer: text::Renderer> Text<Renderer> {
@@ -46,6 +48,8 @@ impl<Renderer: text::Renderer> Text<Renderer> {
             height: Length::Shrink,
             horizontal_alignment: alignment::Horizontal::Left,
             vertical_alignment: alignment::Vertical::Top,
+            format: text::Format::default(),
+            decorations: Vec::new(),
         }
     }
 
@@ -98,6 +102,44 @@ impl<Renderer: text::Renderer> Text<Renderer> {
         self.vertical_alignment = alignment;
         self
     }
+
+    /// Sets the [`Wrapping`] strategy of the [`Text`].
+    ///
+    /// [`Wrapping`]: text::Wrapping
+    pub fn wrapping(mut self, wrapping: text::Wrapping) -> Self {
+        self.format.wrapping = wrapping;
+        self
+    }
+
+    /// Sets the maximum amount of lines of the [`Text`].
+    ///
+    /// If the [`Text`] does not fit, its last line is truncated with an
+    /// ellipsis.
+    pub fn max_lines(mut self, max_lines: usize) -> Self {
+        self.format.max_lines = Some(max_lines);
+        self
+    }
+
+    /// Sets the [`LineHeight`] of the [`Text`].
+    ///
+    /// [`LineHeight`]: text::LineHeight
+    pub fn line_height(mut self, line_height: text::LineHeight) -> Self {
+        self.format.line_height = line_height;
+        self
+    }
+
+    /// Sets the extra space added after every character of the [`Text`], in
+    /// pixels.
+    pub fn letter_spacing(mut self, letter_spacing: f32) -> Self {
+        self.format.letter_spacing = letter_spacing;
+        self
+    }
+
+    /// Sets the decorations of the [`Text`], like underlines or highlights.
+    pub fn decorations(mut self, decorations: Vec<text::Decoration>) -> Self {
+        self.decorations = decorations;
+        self
+    }
 }
 
 impl<Message, Renderer> Widget<Message, Renderer> for Text<Renderer>
@@ -123,12 +165,17 @@ where
 
         let bounds = limits.max();
 
-        let (width, height) =
-            renderer.measure(&self.content, size, self.font.clone(), bounds);
+        let (width, height) = renderer.measure(
+            &self.content,
+            size,
+            self.font.clone(),
+            bounds,
+            self.format,
+        );
 
         let size = limits.resolve(Size::new(width, height));
 
-        layout::Node::new(size)
+        layout::Node::new(size).with_direction(limits.direction())
     }
 
     fn draw(
@@ -149,6 +196,8 @@ where
             self.color,
             self.horizontal_alignment,
             self.vertical_alignment,
+            self.format,
+            &self.decorations,
         );
     }
 
@@ -160,6 +209,7 @@ where
         self.size.hash(state);
         self.width.hash(state);
         self.height.hash(state);
+        self.format.hash(state);
     }
 }
 
@@ -173,6 +223,7 @@ where
 ///   used.
 /// * The alignment attributes do not affect the position of the bounds of the
 ///   [`Layout`].
+/// * The horizontal alignment is mirrored if the [`Layout`] is right-to-left.
 pub fn draw<Renderer>(
     renderer: &mut Renderer,
     style: &renderer::Style,
@@ -183,10 +234,13 @@ pub fn draw<Renderer>(
     color: Option<Color>,
     horizontal_alignment: alignment::Horizontal,
     vertical_alignment: alignment::Vertical,
+    format: text::Format,
+    decorations: &[text::Decoration],
 ) where
     Renderer: text::Renderer,
 {
     let bounds = layout.bounds();
+    let horizontal_alignment = layout.direction().align_x(horizontal_alignment);
 
     let x = match horizontal_alignment {
         alignment::Horizontal::Left => bounds.x,
@@ -208,6 +262,8 @@ pub fn draw<Renderer>(
         font,
         horizontal_alignment,
         vertical_alignment,
+        format,
+        decorations,
     });
 }
 
@@ -232,6 +288,8 @@ impl<Renderer: text::Renderer> Clone for Text<Renderer> {
             height: self.height,
             horizontal_alignment: self.horizontal_alignment,
             vertical_alignment: self.vertical_alignment,
+            format: self.format,
+            decorations: self.decorations.clone(),
         }
     }
 }
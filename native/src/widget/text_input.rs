@@ -2,14 +2,18 @@
 //!
 //! A [`TextInput`] has some local [`State`].
 mod editor;
+mod history;
 mod value;
 
 pub mod cursor;
+pub mod filter;
 
 pub use cursor::Cursor;
+pub use filter::Filter;
 pub use value::Value;
 
 use editor::Editor;
+use history::History;
 
 use crate::alignment;
 use crate::event::{self, Event};
@@ -64,6 +68,9 @@ pub struct TextInput<'a, Message, Renderer: text::Renderer> {
     max_width: u32,
     padding: Padding,
     size: Option<u16>,
+    decorations: Vec<text::Decoration>,
+    history_limit: usize,
+    filters: Vec<Filter>,
     on_change: Box<dyn Fn(String) -> Message>,
     on_submit: Option<Message>,
     style_sheet: Box<dyn StyleSheet + 'a>,
@@ -74,6 +81,9 @@ where
     Message: Clone,
     Renderer: text::Renderer,
 {
+    /// The default amount of steps that can be undone in a [`TextInput`].
+    pub const DEFAULT_HISTORY_LIMIT: usize = 100;
+
     /// Creates a new [`TextInput`].
     ///
     /// It expects:
@@ -100,6 +110,9 @@ where
             max_width: u32::MAX,
             padding: Padding::ZERO,
             size: None,
+            decorations: Vec::new(),
+            history_limit: Self::DEFAULT_HISTORY_LIMIT,
+            filters: Vec::new(),
             on_change: Box::new(on_change),
             on_submit: None,
             style_sheet: Default::default(),
@@ -144,6 +157,35 @@ where
         self
     }
 
+    /// Sets the decorations of the value of the [`TextInput`], like
+    /// underlines or highlights.
+    ///
+    /// The decorations are not drawn over the placeholder.
+    pub fn decorations(mut self, decorations: Vec<text::Decoration>) -> Self {
+        self.decorations = decorations;
+        self
+    }
+
+    /// Sets the maximum amount of steps that can be undone in the
+    /// [`TextInput`].
+    ///
+    /// Consecutive typing is undone a word at a time, while pasting and
+    /// cutting are undone in a single step.
+    pub fn history_limit(mut self, limit: usize) -> Self {
+        self.history_limit = limit;
+        self
+    }
+
+    /// Adds a [`Filter`] to the [`TextInput`].
+    ///
+    /// Text that does not pass all of the filters of the [`TextInput`] is
+    /// never inserted. While unfocused, the [`TextInput`] uses the invalid
+    /// style of its [`StyleSheet`] if its value does not satisfy them.
+    pub fn filter(mut self, filter: Filter) -> Self {
+        self.filters.push(filter);
+        self
+    }
+
     /// Sets the message that should be produced when the [`TextInput`] is
     /// focused and the enter key is pressed.
     pub fn on_submit(mut self, message: Message) -> Self {
@@ -164,6 +206,11 @@ where
     pub fn state(&self) -> &State {
         self.state
     }
+
+    /// Replaces the [`Value`] of the [`TextInput`].
+    pub(crate) fn replace_value(&mut self, value: &str) {
+        self.value = Value::new(value);
+    }
 }
 
 impl<'a, Message, Renderer> TextInput<'a, Message, Renderer>
@@ -180,9 +227,38 @@ where
         value: Option<&Value>,
     ) {
         let value = value.unwrap_or(&self.value);
+
+        let is_valid = value.is_empty()
+            || self
+                .filters
+                .iter()
+                .all(|filter| filter.is_satisfied_by(value));
+
         let secure_value = self.is_secure.then(|| value.secure());
         let value = secure_value.as_ref().unwrap_or(&value);
 
+        // The text being composed with an IME is shown underlined in place of
+        // the selection, until it is committed
+        let composition = self
+            .state
+            .preedit
+            .as_ref()
+            .filter(|_| self.state.is_focused() && !self.is_secure)
+            .map(|preedit| {
+                preedit.compose(value, self.state.cursor, &self.decorations)
+            });
+
+        let (value, cursor_state, decorations) = match &composition {
+            Some((value, cursor, decorations)) => {
+                (value, *cursor, decorations.as_slice())
+            }
+            None => (
+                value,
+                self.state.cursor.state(value),
+                self.decorations.as_slice(),
+            ),
+        };
+
         let bounds = layout.bounds();
         let text_bounds = layout.children().next().unwrap().bounds();
 
@@ -190,6 +266,8 @@ where
 
         let style = if self.state.is_focused() {
             self.style_sheet.focused()
+        } else if !is_valid {
+            self.style_sheet.invalid()
         } else if is_mouse_over {
             self.style_sheet.hovered()
         } else {
@@ -209,8 +287,24 @@ where
         let text = value.to_string();
         let size = self.size.unwrap_or(renderer.default_size());
 
+        let direction = text::direction(if text.is_empty() {
+            &self.placeholder
+        } else {
+            &text
+        })
+        .unwrap_or_else(|| layout.direction());
+
+        // Right-to-left content starts at the right edge of the text bounds
+        let text_x = |width: f32| {
+            if direction.is_right_to_left() {
+                text_bounds.x + text_bounds.width - width
+            } else {
+                text_bounds.x + width
+            }
+        };
+
         let (cursor, offset) = if self.state.is_focused() {
-            match self.state.cursor.state(&value) {
+            match cursor_state {
                 cursor::State::Index(position) => {
                     let (text_value_width, offset) =
                         measure_cursor_and_scroll_offset(
@@ -226,7 +320,7 @@ where
                         Some((
                             renderer::Quad {
                                 bounds: Rectangle {
-                                    x: text_bounds.x + text_value_width,
+                                    x: text_x(text_value_width),
                                     y: text_bounds.y,
                                     width: 1.0,
                                     height: text_bounds.height,
@@ -270,7 +364,8 @@ where
                         Some((
                             renderer::Quad {
                                 bounds: Rectangle {
-                                    x: text_bounds.x + left_position,
+                                    x: text_x(left_position)
+                                        .min(text_x(right_position)),
                                     y: text_bounds.y,
                                     width,
                                     height: text_bounds.height,
@@ -321,6 +416,11 @@ where
                 },
                 font: self.font.clone(),
                 bounds: Rectangle {
+                    x: if direction.is_right_to_left() {
+                        text_x(text_width)
+                    } else {
+                        text_bounds.x
+                    },
                     y: text_bounds.center_y(),
                     width: f32::INFINITY,
                     ..text_bounds
@@ -328,12 +428,20 @@ where
                 size: f32::from(size),
                 horizontal_alignment: alignment::Horizontal::Left,
                 vertical_alignment: alignment::Vertical::Center,
+                format: text::Format::default(),
+                decorations: if text.is_empty() { &[] } else { decorations },
             });
         };
 
         if text_width > text_bounds.width {
             renderer.with_layer(text_bounds, |renderer| {
-                renderer.with_translation(Vector::new(-offset, 0.0), render)
+                let offset = if direction.is_right_to_left() {
+                    offset
+                } else {
+                    -offset
+                };
+
+                renderer.with_translation(Vector::new(offset, 0.0), render)
             });
         } else {
             render(renderer);
@@ -341,43 +449,12 @@ where
     }
 }
 
-impl<'a, Message, Renderer> Widget<Message, Renderer>
-    for TextInput<'a, Message, Renderer>
+impl<'a, Message, Renderer> TextInput<'a, Message, Renderer>
 where
     Message: Clone,
     Renderer: text::Renderer,
 {
-    fn width(&self) -> Length {
-        self.width
-    }
-
-    fn height(&self) -> Length {
-        Length::Shrink
-    }
-
-    fn layout(
-        &self,
-        renderer: &Renderer,
-        limits: &layout::Limits,
-    ) -> layout::Node {
-        let text_size = self.size.unwrap_or(renderer.default_size());
-
-        let limits = limits
-            .pad(self.padding)
-            .width(self.width)
-            .max_width(self.max_width)
-            .height(Length::Units(text_size));
-
-        let mut text = layout::Node::new(limits.resolve(Size::ZERO));
-        text.move_to(Point::new(
-            self.padding.left.into(),
-            self.padding.top.into(),
-        ));
-
-        layout::Node::with_children(text.size().pad(self.padding), vec![text])
-    }
-
-    fn on_event(
+    fn update(
         &mut self,
         event: Event,
         layout: Layout<'_>,
@@ -392,6 +469,8 @@ where
                 let is_clicked = layout.bounds().contains(cursor_position);
 
                 self.state.is_focused = is_clicked;
+                self.state.preedit = None;
+                self.state.history.seal();
 
                 if is_clicked {
                     let text_layout = layout.children().next().unwrap();
@@ -413,7 +492,7 @@ where
 
                                 find_cursor_position(
                                     renderer,
-                                    text_layout.bounds(),
+                                    text_layout,
                                     self.font.clone(),
                                     self.size,
                                     &value,
@@ -433,7 +512,7 @@ where
                             } else {
                                 let position = find_cursor_position(
                                     renderer,
-                                    text_layout.bounds(),
+                                    text_layout,
                                     self.font.clone(),
                                     self.size,
                                     &self.value,
@@ -480,7 +559,7 @@ where
 
                     let position = find_cursor_position(
                         renderer,
-                        text_layout.bounds(),
+                        text_layout,
                         self.font.clone(),
                         self.size,
                         &value,
@@ -503,13 +582,20 @@ where
                     && !self.state.keyboard_modifiers.command()
                     && !c.is_control() =>
             {
-                let mut editor =
-                    Editor::new(&mut self.value, &mut self.state.cursor);
-
-                editor.insert(c);
-
-                let message = (self.on_change)(editor.contents());
-                shell.publish(message);
+                self.record(history::Edit::Type {
+                    is_whitespace: c.is_whitespace(),
+                });
+
+                let mut editor = Editor::new(
+                    &mut self.value,
+                    &mut self.state.cursor,
+                    &self.filters,
+                );
+
+                if editor.insert(c) {
+                    let message = (self.on_change)(editor.contents());
+                    shell.publish(message);
+                }
 
                 return event::Status::Captured;
             }
@@ -518,6 +604,37 @@ where
             }) if self.state.is_focused => {
                 let modifiers = self.state.keyboard_modifiers;
 
+                // Arrow keys move the cursor visually, so they are swapped
+                // for right-to-left content.
+                let is_right_to_left = if self.is_secure {
+                    None
+                } else {
+                    text::direction(&self.value.to_string())
+                }
+                .unwrap_or_else(|| layout.direction())
+                .is_right_to_left();
+
+                let key_code = match key_code {
+                    keyboard::KeyCode::Left if is_right_to_left => {
+                        keyboard::KeyCode::Right
+                    }
+                    keyboard::KeyCode::Right if is_right_to_left => {
+                        keyboard::KeyCode::Left
+                    }
+                    key_code => key_code,
+                };
+
+                // Moving the cursor starts a new step in the history
+                if matches!(
+                    key_code,
+                    keyboard::KeyCode::Left
+                        | keyboard::KeyCode::Right
+                        | keyboard::KeyCode::Home
+                        | keyboard::KeyCode::End
+                ) {
+                    self.state.history.seal();
+                }
+
                 match key_code {
                     keyboard::KeyCode::Enter
                     | keyboard::KeyCode::NumpadEnter => {
@@ -544,9 +661,12 @@ where
                             }
                         }
 
+                        self.record_deletion();
+
                         let mut editor = Editor::new(
                             &mut self.value,
                             &mut self.state.cursor,
+                            &self.filters,
                         );
 
                         editor.backspace();
@@ -575,9 +695,12 @@ where
                             }
                         }
 
+                        self.record_deletion();
+
                         let mut editor = Editor::new(
                             &mut self.value,
                             &mut self.state.cursor,
+                            &self.filters,
                         );
 
                         editor.delete();
@@ -667,9 +790,12 @@ where
                             None => {}
                         }
 
+                        self.record(history::Edit::Replace);
+
                         let mut editor = Editor::new(
                             &mut self.value,
                             &mut self.state.cursor,
+                            &self.filters,
                         );
 
                         editor.delete();
@@ -693,21 +819,47 @@ where
                                 }
                             };
 
+                            self.record(history::Edit::Replace);
+
                             let mut editor = Editor::new(
                                 &mut self.value,
                                 &mut self.state.cursor,
+                                &self.filters,
                             );
 
-                            editor.paste(content.clone());
-
-                            let message = (self.on_change)(editor.contents());
-                            shell.publish(message);
+                            if editor.paste(content.clone()) {
+                                let message =
+                                    (self.on_change)(editor.contents());
+                                shell.publish(message);
+                            }
 
                             self.state.is_pasting = Some(content);
                         } else {
                             self.state.is_pasting = None;
                         }
                     }
+                    keyboard::KeyCode::Z
+                        if self.state.keyboard_modifiers.command() =>
+                    {
+                        let step = if modifiers.shift() {
+                            self.state
+                                .history
+                                .redo(&self.value, self.state.cursor)
+                        } else {
+                            self.state
+                                .history
+                                .undo(&self.value, self.state.cursor)
+                        };
+
+                        if let Some((value, cursor)) = step {
+                            self.value = value;
+                            self.state.cursor = cursor;
+
+                            let message =
+                                (self.on_change)(self.value.to_string());
+                            shell.publish(message);
+                        }
+                    }
                     keyboard::KeyCode::A
                         if self.state.keyboard_modifiers.command() =>
                     {
@@ -717,6 +869,7 @@ where
                         self.state.is_focused = false;
                         self.state.is_dragging = false;
                         self.state.is_pasting = None;
+                        self.state.preedit = None;
 
                         self.state.keyboard_modifiers =
                             keyboard::Modifiers::default();
@@ -753,12 +906,201 @@ where
             {
                 self.state.keyboard_modifiers = modifiers;
             }
+            Event::Keyboard(keyboard::Event::Ime(ime))
+                if self.state.is_focused =>
+            {
+                match ime {
+                    keyboard::Ime::Preedit { content, cursor } => {
+                        self.state.preedit = if content.is_empty() {
+                            None
+                        } else {
+                            Some(Preedit { content, cursor })
+                        };
+                    }
+                    keyboard::Ime::Commit(content) => {
+                        self.state.preedit = None;
+
+                        let content: String = content
+                            .chars()
+                            .filter(|c| !c.is_control())
+                            .collect();
+
+                        self.record(history::Edit::Replace);
+
+                        let mut editor = Editor::new(
+                            &mut self.value,
+                            &mut self.state.cursor,
+                            &self.filters,
+                        );
+
+                        if editor.paste(Value::new(&content)) {
+                            let message = (self.on_change)(editor.contents());
+                            shell.publish(message);
+                        }
+                    }
+                    keyboard::Ime::Enabled | keyboard::Ime::Disabled => {
+                        self.state.preedit = None;
+                    }
+                }
+
+                return event::Status::Captured;
+            }
             _ => {}
         }
 
         event::Status::Ignored
     }
 
+    /// Records the current value in the history of the [`TextInput`],
+    /// right before applying the given [`Edit`].
+    ///
+    /// [`Edit`]: history::Edit
+    fn record(&mut self, edit: history::Edit) {
+        self.state.history.record(
+            edit,
+            &self.value,
+            self.state.cursor,
+            self.history_limit,
+        );
+    }
+
+    /// Records the current value in the history of the [`TextInput`],
+    /// right before deleting text.
+    ///
+    /// Deleting a selection is a step of its own.
+    fn record_deletion(&mut self) {
+        if self.state.cursor.selection(&self.value).is_some() {
+            self.record(history::Edit::Replace);
+        } else {
+            self.record(history::Edit::Delete);
+        }
+    }
+
+    /// Returns the position right below the text cursor, where the input
+    /// method editor (IME) of the platform should be placed.
+    fn ime_position(&self, layout: Layout<'_>, renderer: &Renderer) -> Point {
+        let text_bounds = layout.children().next().unwrap().bounds();
+        let size = self.size.unwrap_or(renderer.default_size());
+
+        let value = if self.is_secure {
+            self.value.secure()
+        } else {
+            self.value.clone()
+        };
+
+        let position = match self.state.cursor.state(&value) {
+            cursor::State::Index(position) => position,
+            cursor::State::Selection { start, end } => start.min(end),
+        };
+
+        let (width, _) = measure_cursor_and_scroll_offset(
+            renderer,
+            text_bounds,
+            &value,
+            size,
+            position,
+            self.font.clone(),
+        );
+
+        let content = value.to_string();
+        let text_width =
+            renderer.measure_width(&content, size, self.font.clone());
+
+        let offset = if text_width > text_bounds.width {
+            offset(
+                renderer,
+                text_bounds,
+                self.font.clone(),
+                size,
+                &value,
+                &self.state,
+            )
+        } else {
+            0.0
+        };
+
+        let x = if text::direction(&content)
+            .unwrap_or_else(|| layout.direction())
+            .is_right_to_left()
+        {
+            text_bounds.x + text_bounds.width - width + offset
+        } else {
+            text_bounds.x + width - offset
+        };
+
+        Point::new(x, text_bounds.y + text_bounds.height)
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TextInput<'a, Message, Renderer>
+where
+    Message: Clone,
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        self.width
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.size.unwrap_or(renderer.default_size());
+
+        let limits = limits
+            .pad(self.padding)
+            .width(self.width)
+            .max_width(self.max_width)
+            .height(Length::Units(text_size));
+
+        let direction = limits.direction();
+
+        let mut text = layout::Node::new(limits.resolve(Size::ZERO))
+            .with_direction(direction);
+        text.move_to(Point::new(
+            if direction.is_right_to_left() {
+                self.padding.right.into()
+            } else {
+                self.padding.left.into()
+            },
+            self.padding.top.into(),
+        ));
+
+        layout::Node::with_children(text.size().pad(self.padding), vec![text])
+            .with_direction(direction)
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        renderer: &Renderer,
+        clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let status = self.update(
+            event,
+            layout,
+            cursor_position,
+            renderer,
+            clipboard,
+            shell,
+        );
+
+        if self.state.is_focused {
+            shell.set_ime_position(self.ime_position(layout, renderer));
+        }
+
+        status
+    }
+
     fn mouse_interaction(
         &self,
         layout: Layout<'_>,
@@ -818,9 +1160,87 @@ pub struct State {
     last_click: Option<mouse::Click>,
     cursor: Cursor,
     keyboard_modifiers: keyboard::Modifiers,
+    preedit: Option<Preedit>,
+    history: History,
     // TODO: Add stateful horizontal scrolling offset
 }
 
+/// The text being composed with an input method editor (IME).
+#[derive(Debug, Clone)]
+struct Preedit {
+    content: String,
+    cursor: Option<(usize, usize)>,
+}
+
+impl Preedit {
+    /// Composes the [`Preedit`] with a [`Value`] by replacing the selection
+    /// of its [`Cursor`].
+    ///
+    /// Returns the composed [`Value`], the state of its cursor, and the
+    /// given decorations moved accordingly, plus an underline for the
+    /// [`Preedit`].
+    fn compose(
+        &self,
+        value: &Value,
+        cursor: Cursor,
+        decorations: &[text::Decoration],
+    ) -> (Value, cursor::State, Vec<text::Decoration>) {
+        let (left, right) = match cursor.state(value) {
+            cursor::State::Index(position) => (position, position),
+            cursor::State::Selection { start, end } => {
+                (start.min(end), start.max(end))
+            }
+        };
+
+        let content = Value::new(&self.content);
+        let graphemes = |end: usize| {
+            Value::new(self.content.get(..end).unwrap_or(&self.content)).len()
+        };
+
+        let cursor = match self.cursor {
+            Some((start, end)) if start != end => cursor::State::Selection {
+                start: left + graphemes(start),
+                end: left + graphemes(end),
+            },
+            Some((start, _)) => cursor::State::Index(left + graphemes(start)),
+            None => cursor::State::Index(left + content.len()),
+        };
+
+        // Decorations use character indices
+        let start = value.until(left).to_string().chars().count();
+        let removed = value.select(left, right).to_string().chars().count();
+        let inserted = self.content.chars().count();
+
+        let shift = |index: usize| {
+            if index <= start {
+                index
+            } else if index >= start + removed {
+                index - removed + inserted
+            } else {
+                start
+            }
+        };
+
+        let mut composed = value.clone();
+        composed.remove_many(left, right);
+        composed.insert_many(left, content);
+
+        let decorations = decorations
+            .iter()
+            .map(|decoration| text::Decoration {
+                range: shift(decoration.range.start)
+                    ..shift(decoration.range.end),
+                ..decoration.clone()
+            })
+            .chain(std::iter::once(text::Decoration::underline(
+                start..start + inserted,
+            )))
+            .collect();
+
+        (composed, cursor, decorations)
+    }
+}
+
 impl State {
     /// Creates a new [`State`], representing an unfocused [`TextInput`].
     pub fn new() -> Self {
@@ -836,6 +1256,8 @@ impl State {
             last_click: None,
             cursor: Cursor::default(),
             keyboard_modifiers: keyboard::Modifiers::default(),
+            preedit: None,
+            history: History::default(),
         }
     }
 
@@ -857,6 +1279,7 @@ impl State {
     /// Unfocuses the [`TextInput`].
     pub fn unfocus(&mut self) {
         self.is_focused = false;
+        self.preedit = None;
     }
 
     /// Moves the [`Cursor`] of the [`TextInput`] to the front of the input text.
@@ -878,6 +1301,14 @@ impl State {
     pub fn select_all(&mut self) {
         self.cursor.select_range(0, usize::MAX);
     }
+
+    /// Clears the undo history of the [`TextInput`].
+    ///
+    /// You should call this when replacing the value of the [`TextInput`]
+    /// programmatically, so its previous edits cannot be undone.
+    pub fn clear_history(&mut self) {
+        self.history.clear();
+    }
 }
 
 mod platform {
@@ -951,7 +1382,7 @@ where
 /// a [`TextInput`].
 fn find_cursor_position<Renderer>(
     renderer: &Renderer,
-    text_bounds: Rectangle,
+    text_layout: Layout<'_>,
     font: Renderer::Font,
     size: Option<u16>,
     value: &Value,
@@ -961,18 +1392,32 @@ fn find_cursor_position<Renderer>(
 where
     Renderer: text::Renderer,
 {
+    let text_bounds = text_layout.bounds();
     let size = size.unwrap_or(renderer.default_size());
+    let content = value.to_string();
 
     let offset =
         offset(renderer, text_bounds, font.clone(), size, &value, &state);
 
+    let x = if text::direction(&content)
+        .unwrap_or_else(|| text_layout.direction())
+        .is_right_to_left()
+    {
+        let text_width = renderer.measure_width(&content, size, font.clone());
+
+        x - (text_bounds.width - text_width + offset)
+    } else {
+        x + offset
+    };
+
     renderer
         .hit_test(
-            &value.to_string(),
+            &content,
             size.into(),
             font.clone(),
             Size::INFINITY,
-            Point::new(x + offset, text_bounds.height / 2.0),
+            text::Format::default(),
+            Point::new(x, text_bounds.height / 2.0),
             true,
         )
         .map(text::Hit::cursor)
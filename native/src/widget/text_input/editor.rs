@@ -1,33 +1,69 @@
-use crate::widget::text_input::{Cursor, Value};
+use crate::widget::text_input::{Cursor, Filter, Value};
 
 pub struct Editor<'a> {
     value: &'a mut Value,
     cursor: &'a mut Cursor,
+    filters: &'a [Filter],
 }
 
 impl<'a> Editor<'a> {
-    pub fn new(value: &'a mut Value, cursor: &'a mut Cursor) -> Editor<'a> {
-        Editor { value, cursor }
+    pub fn new(
+        value: &'a mut Value,
+        cursor: &'a mut Cursor,
+        filters: &'a [Filter],
+    ) -> Editor<'a> {
+        Editor {
+            value,
+            cursor,
+            filters,
+        }
     }
 
     pub fn contents(&self) -> String {
         self.value.to_string()
     }
 
-    pub fn insert(&mut self, character: char) {
-        match self.cursor.selection(self.value) {
-            Some((left, right)) => {
-                self.cursor.move_left(self.value);
-                self.value.remove_many(left, right);
+    /// Inserts a character, returning whether the filters accepted it.
+    pub fn insert(&mut self, character: char) -> bool {
+        let inserted = Value::new(&character.to_string());
+
+        match self.filter(inserted.clone()) {
+            Some(content) if content == inserted => {
+                match self.cursor.selection(self.value) {
+                    Some((left, right)) => {
+                        self.cursor.move_left(self.value);
+                        self.value.remove_many(left, right);
+                    }
+                    _ => {}
+                }
+
+                self.value.insert(self.cursor.end(self.value), character);
+                self.cursor.move_right(self.value);
+
+                true
             }
-            _ => {}
+            Some(content) => {
+                self.replace_selection(content);
+
+                true
+            }
+            None => false,
         }
+    }
+
+    /// Pastes some content, returning whether the filters accepted it.
+    pub fn paste(&mut self, content: Value) -> bool {
+        match self.filter(content) {
+            Some(content) => {
+                self.replace_selection(content);
 
-        self.value.insert(self.cursor.end(self.value), character);
-        self.cursor.move_right(self.value);
+                true
+            }
+            None => false,
+        }
     }
 
-    pub fn paste(&mut self, content: Value) {
+    fn replace_selection(&mut self, content: Value) {
         let length = content.len();
 
         match self.cursor.selection(self.value) {
@@ -43,6 +79,29 @@ impl<'a> Editor<'a> {
         self.cursor.move_right_by_amount(self.value, length);
     }
 
+    /// Runs the filters on some content that is about to replace the current
+    /// selection, returning the content that should be inserted instead, if
+    /// any.
+    fn filter(&self, content: Value) -> Option<Value> {
+        if self.filters.is_empty() {
+            return Some(content);
+        }
+
+        let mut value = self.value.clone();
+
+        let position = match self.cursor.selection(self.value) {
+            Some((left, right)) => {
+                value.remove_many(left, right);
+                left
+            }
+            None => self.cursor.end(self.value),
+        };
+
+        self.filters.iter().try_fold(content, |content, filter| {
+            filter.insert(&value, position, content)
+        })
+    }
+
     pub fn backspace(&mut self) {
         match self.cursor.selection(self.value) {
             Some((start, end)) => {
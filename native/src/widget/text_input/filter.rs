@@ -0,0 +1,108 @@
+//! Restrict the text that can be entered in a text input.
+mod mask;
+mod pattern;
+
+pub use mask::Mask;
+pub use pattern::{Error, Pattern};
+
+use crate::widget::text_input::Value;
+
+/// A rule that restricts the text that can be entered in a [`TextInput`].
+///
+/// Filters run before any text is inserted in the [`TextInput`], so rejected
+/// input never produces a message.
+///
+/// [`TextInput`]: crate::widget::TextInput
+#[derive(Debug, Clone, PartialEq)]
+pub enum Filter {
+    /// Only ASCII digits are accepted.
+    Digits,
+
+    /// At most the given amount of graphemes are accepted.
+    ///
+    /// Pasted text that does not fit is truncated.
+    MaxLength(usize),
+
+    /// The value must match a [`Pattern`].
+    Pattern(Pattern),
+
+    /// The value must follow a [`Mask`].
+    Mask(Mask),
+}
+
+impl Filter {
+    /// Creates a [`Filter::Pattern`] from the given source.
+    ///
+    /// Check out [`Pattern`] for the supported syntax.
+    pub fn pattern(source: &str) -> Result<Filter, Error> {
+        Pattern::new(source).map(Filter::Pattern)
+    }
+
+    /// Creates a [`Filter::Mask`] from the given source.
+    ///
+    /// Check out [`Mask`] for the supported syntax.
+    pub fn mask(source: &str) -> Filter {
+        Filter::Mask(Mask::new(source))
+    }
+
+    /// Filters the content that is about to be inserted at the given
+    /// grapheme position of a [`Value`].
+    ///
+    /// Returns the content that should be inserted instead, or `None` if the
+    /// content is rejected.
+    pub fn insert(
+        &self,
+        value: &Value,
+        position: usize,
+        content: Value,
+    ) -> Option<Value> {
+        match self {
+            Filter::Digits => {
+                let is_numeric =
+                    content.to_string().chars().all(|c| c.is_ascii_digit());
+
+                if is_numeric {
+                    Some(content)
+                } else {
+                    None
+                }
+            }
+            Filter::MaxLength(max_length) => {
+                let room = max_length.saturating_sub(value.len());
+                let content = content.select(0, room);
+
+                if content.is_empty() {
+                    None
+                } else {
+                    Some(content)
+                }
+            }
+            Filter::Pattern(pattern) => {
+                let mut candidate = value.clone();
+                candidate.insert_many(position, content.clone());
+
+                if pattern.matches_prefix(&candidate.to_string()) {
+                    Some(content)
+                } else {
+                    None
+                }
+            }
+            Filter::Mask(mask) => mask.insert(value, position, content),
+        }
+    }
+
+    /// Returns whether the given [`Value`] fully satisfies the [`Filter`].
+    ///
+    /// A [`Value`] may be accepted while typing and still not satisfy the
+    /// [`Filter`], like a date that is missing its year.
+    pub fn is_satisfied_by(&self, value: &Value) -> bool {
+        match self {
+            Filter::Digits => {
+                value.to_string().chars().all(|c| c.is_ascii_digit())
+            }
+            Filter::MaxLength(max_length) => value.len() <= *max_length,
+            Filter::Pattern(pattern) => pattern.matches(&value.to_string()),
+            Filter::Mask(mask) => mask.is_complete(&value.to_string()),
+        }
+    }
+}
@@ -0,0 +1,178 @@
+use crate::widget::text_input::Value;
+
+/// A fixed format that the value of a text input must follow, like
+/// `##/##/####` for dates.
+///
+/// In a [`Mask`]:
+///
+/// * `#` is a placeholder for a digit.
+/// * `A` is a placeholder for a letter.
+/// * `*` is a placeholder for a letter or a digit.
+/// * `\` escapes the next character.
+/// * Any other character is a literal, which is inserted automatically
+///   while typing.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mask {
+    slots: Vec<Slot>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Slot {
+    Digit,
+    Letter,
+    Alphanumeric,
+    Literal(char),
+}
+
+impl Slot {
+    fn accepts(self, c: char) -> bool {
+        match self {
+            Slot::Digit => c.is_ascii_digit(),
+            Slot::Letter => c.is_alphabetic(),
+            Slot::Alphanumeric => c.is_alphanumeric(),
+            Slot::Literal(literal) => c == literal,
+        }
+    }
+}
+
+impl Mask {
+    /// Creates a new [`Mask`] from the given source.
+    pub fn new(source: &str) -> Mask {
+        let mut chars = source.chars();
+        let mut slots = Vec::new();
+
+        while let Some(c) = chars.next() {
+            slots.push(match c {
+                '#' => Slot::Digit,
+                'A' => Slot::Letter,
+                '*' => Slot::Alphanumeric,
+                '\\' => Slot::Literal(chars.next().unwrap_or('\\')),
+                c => Slot::Literal(c),
+            });
+        }
+
+        Mask { slots }
+    }
+
+    /// Returns whether the given text follows the [`Mask`], even if it is
+    /// not complete yet.
+    pub fn accepts(&self, text: &str) -> bool {
+        text.chars().count() <= self.slots.len()
+            && text
+                .chars()
+                .zip(&self.slots)
+                .all(|(c, slot)| slot.accepts(c))
+    }
+
+    /// Returns whether the given text follows the [`Mask`] and fills all of
+    /// its slots.
+    pub fn is_complete(&self, text: &str) -> bool {
+        text.chars().count() == self.slots.len() && self.accepts(text)
+    }
+
+    /// Fits the content that is about to be inserted at the given position
+    /// of a [`Value`] into the [`Mask`].
+    ///
+    /// Literals of the [`Mask`] are inserted as needed, and the ones right
+    /// after the content too when inserting at the end of the [`Value`].
+    pub fn insert(
+        &self,
+        value: &Value,
+        position: usize,
+        content: Value,
+    ) -> Option<Value> {
+        let mut inserted = String::new();
+        let mut slot = position;
+
+        for c in content.to_string().chars() {
+            loop {
+                let current = *self.slots.get(slot)?;
+                slot += 1;
+
+                match current {
+                    Slot::Literal(literal) => {
+                        inserted.push(literal);
+
+                        if literal == c {
+                            break;
+                        }
+                    }
+                    placeholder if placeholder.accepts(c) => {
+                        inserted.push(c);
+                        break;
+                    }
+                    _ => return None,
+                }
+            }
+        }
+
+        if position == value.len() {
+            while let Some(Slot::Literal(literal)) = self.slots.get(slot) {
+                inserted.push(*literal);
+                slot += 1;
+            }
+        }
+
+        let inserted = Value::new(&inserted);
+
+        let mut candidate = value.clone();
+        candidate.insert_many(position, inserted.clone());
+
+        if self.accepts(&candidate.to_string()) {
+            Some(inserted)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_text(mask: &Mask, text: &str) -> String {
+        let mut value = Value::new("");
+
+        for c in text.chars() {
+            let content = Value::new(&c.to_string());
+
+            if let Some(inserted) = mask.insert(&value, value.len(), content) {
+                value.insert_many(value.len(), inserted);
+            }
+        }
+
+        value.to_string()
+    }
+
+    #[test]
+    fn inserts_literals_while_typing() {
+        let mask = Mask::new("##/##/####");
+
+        assert_eq!(type_text(&mask, "1"), "1");
+        assert_eq!(type_text(&mask, "12"), "12/");
+        assert_eq!(type_text(&mask, "31121999"), "31/12/1999");
+        assert_eq!(type_text(&mask, "31/12/1999"), "31/12/1999");
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        let mask = Mask::new("##/##/####");
+
+        assert_eq!(mask.insert(&Value::new("1"), 1, Value::new("a")), None);
+        assert_eq!(type_text(&mask, "311219990"), "31/12/1999");
+
+        assert_eq!(
+            mask.insert(&Value::new(""), 0, Value::new("31/12/1999")),
+            Some(Value::new("31/12/1999"))
+        );
+    }
+
+    #[test]
+    fn completes_when_every_slot_is_filled() {
+        let mask = Mask::new("AA-#");
+
+        assert!(mask.accepts("AB-"));
+        assert!(!mask.is_complete("AB-"));
+        assert!(mask.is_complete("AB-1"));
+    }
+}
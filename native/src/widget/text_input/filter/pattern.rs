@@ -0,0 +1,322 @@
+use std::fmt;
+use std::iter::Peekable;
+use std::str::Chars;
+
+/// A simple regular expression that the value of a text input must match,
+/// like `\d{3}-\d{4}` for phone numbers.
+///
+/// A [`Pattern`] is a sequence of character classes, each optionally
+/// followed by a quantifier. The supported classes are:
+///
+/// * Any literal character, or an escaped one like `\.`.
+/// * `.`, which matches any character.
+/// * `\d`, `\w`, and `\s`, which match digits, word characters, and
+///   whitespace, respectively.
+/// * Sets like `[a-f0-9]` or `[^,]`.
+///
+/// The supported quantifiers are `?`, `*`, `+`, `{n}`, `{n,}`, and `{n,m}`.
+/// Alternations and groups are not supported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Pattern {
+    tokens: Vec<Token>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Token {
+    class: Class,
+    min: usize,
+    max: Option<usize>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Class {
+    Any,
+    Literal(char),
+    Range(char, char),
+    Digit,
+    Word,
+    Whitespace,
+    Set {
+        members: Vec<Class>,
+        is_negated: bool,
+    },
+}
+
+impl Class {
+    fn matches(&self, c: char) -> bool {
+        match self {
+            Class::Any => true,
+            Class::Literal(literal) => c == *literal,
+            Class::Range(start, end) => (*start..=*end).contains(&c),
+            Class::Digit => c.is_ascii_digit(),
+            Class::Word => c.is_alphanumeric() || c == '_',
+            Class::Whitespace => c.is_whitespace(),
+            Class::Set {
+                members,
+                is_negated,
+            } => members.iter().any(|member| member.matches(c)) != *is_negated,
+        }
+    }
+}
+
+/// The position of a [`Pattern`] while matching: the index of a token and
+/// the amount of times it has been repeated.
+type State = (usize, usize);
+
+impl Pattern {
+    /// Parses a new [`Pattern`] from the given source.
+    pub fn new(source: &str) -> Result<Pattern, Error> {
+        let mut chars = source.chars().peekable();
+        let mut tokens = Vec::new();
+
+        while let Some(c) = chars.next() {
+            let class = match c {
+                '.' => Class::Any,
+                '\\' => escaped(chars.next().ok_or(Error::UnexpectedEnd)?),
+                '[' => set(&mut chars)?,
+                '?' | '*' | '+' | '{' => return Err(Error::NothingToRepeat),
+                c => Class::Literal(c),
+            };
+
+            let (min, max) = match chars.peek() {
+                Some('?') => {
+                    let _ = chars.next();
+                    (0, Some(1))
+                }
+                Some('*') => {
+                    let _ = chars.next();
+                    (0, None)
+                }
+                Some('+') => {
+                    let _ = chars.next();
+                    (1, None)
+                }
+                Some('{') => {
+                    let _ = chars.next();
+                    repetition(&mut chars)?
+                }
+                _ => (1, Some(1)),
+            };
+
+            tokens.push(Token { class, min, max });
+        }
+
+        Ok(Pattern { tokens })
+    }
+
+    /// Returns whether the given text matches the [`Pattern`] completely.
+    pub fn matches(&self, text: &str) -> bool {
+        self.run(text).contains(&(self.tokens.len(), 0))
+    }
+
+    /// Returns whether the given text could still match the [`Pattern`] if
+    /// more characters were added at its end.
+    pub fn matches_prefix(&self, text: &str) -> bool {
+        !self.run(text).is_empty()
+    }
+
+    fn run(&self, text: &str) -> Vec<State> {
+        text.chars().fold(self.closure(vec![(0, 0)]), |states, c| {
+            self.closure(self.step(&states, c))
+        })
+    }
+
+    fn step(&self, states: &[State], c: char) -> Vec<State> {
+        states
+            .iter()
+            .filter_map(|&(index, repetitions)| {
+                let token = self.tokens.get(index)?;
+
+                let can_repeat = match token.max {
+                    Some(max) => repetitions < max,
+                    None => true,
+                };
+
+                if !can_repeat || !token.class.matches(c) {
+                    return None;
+                }
+
+                // Unbounded repetitions only need to be counted up to the
+                // minimum, so the amount of states stays finite
+                let repetitions = match token.max {
+                    Some(_) => repetitions + 1,
+                    None => (repetitions + 1).min(token.min),
+                };
+
+                Some((index, repetitions))
+            })
+            .collect()
+    }
+
+    fn closure(&self, mut pending: Vec<State>) -> Vec<State> {
+        let mut states = Vec::new();
+
+        while let Some(state) = pending.pop() {
+            if states.contains(&state) {
+                continue;
+            }
+
+            let (index, repetitions) = state;
+            states.push(state);
+
+            if let Some(token) = self.tokens.get(index) {
+                if repetitions >= token.min {
+                    pending.push((index + 1, 0));
+                }
+            }
+        }
+
+        states
+    }
+}
+
+fn escaped(c: char) -> Class {
+    match c {
+        'd' => Class::Digit,
+        'w' => Class::Word,
+        's' => Class::Whitespace,
+        c => Class::Literal(c),
+    }
+}
+
+fn set(chars: &mut Peekable<Chars<'_>>) -> Result<Class, Error> {
+    let is_negated = chars.peek() == Some(&'^');
+
+    if is_negated {
+        let _ = chars.next();
+    }
+
+    let mut members = Vec::new();
+
+    loop {
+        let member = match chars.next().ok_or(Error::UnexpectedEnd)? {
+            ']' => break,
+            '\\' => escaped(chars.next().ok_or(Error::UnexpectedEnd)?),
+            start => {
+                let mut lookahead = chars.clone();
+
+                match (lookahead.next(), lookahead.next()) {
+                    (Some('-'), Some(end)) if end != ']' => {
+                        let _ = chars.next();
+                        let _ = chars.next();
+
+                        Class::Range(start, end)
+                    }
+                    _ => Class::Literal(start),
+                }
+            }
+        };
+
+        members.push(member);
+    }
+
+    Ok(Class::Set {
+        members,
+        is_negated,
+    })
+}
+
+fn repetition(
+    chars: &mut Peekable<Chars<'_>>,
+) -> Result<(usize, Option<usize>), Error> {
+    let mut body = String::new();
+
+    loop {
+        match chars.next().ok_or(Error::UnexpectedEnd)? {
+            '}' => break,
+            c => body.push(c),
+        }
+    }
+
+    let number = |text: &str| {
+        text.trim()
+            .parse::<usize>()
+            .map_err(|_| Error::InvalidRepetition)
+    };
+
+    let (min, max) = match body.split_once(',') {
+        Some((min, max)) if max.trim().is_empty() => (number(min)?, None),
+        Some((min, max)) => (number(min)?, Some(number(max)?)),
+        None => {
+            let count = number(&body)?;
+
+            (count, Some(count))
+        }
+    };
+
+    if matches!(max, Some(max) if max < min) {
+        return Err(Error::InvalidRepetition);
+    }
+
+    Ok((min, max))
+}
+
+/// An error produced when parsing an invalid [`Pattern`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Error {
+    /// The pattern ended in the middle of an escape, a set, or a repetition.
+    UnexpectedEnd,
+
+    /// A quantifier was found without a character class to repeat.
+    NothingToRepeat,
+
+    /// A repetition like `{n,m}` was malformed.
+    InvalidRepetition,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::UnexpectedEnd => write!(f, "the pattern ended unexpectedly"),
+            Error::NothingToRepeat => {
+                write!(f, "a quantifier has nothing to repeat")
+            }
+            Error::InvalidRepetition => {
+                write!(f, "a repetition is malformed")
+            }
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_prefixes_of_ip_addresses() {
+        let pattern =
+            Pattern::new(r"\d{1,3}\.\d{1,3}\.\d{1,3}\.\d{1,3}").unwrap();
+
+        assert!(pattern.matches_prefix(""));
+        assert!(pattern.matches_prefix("192.16"));
+        assert!(!pattern.matches_prefix("1921."));
+        assert!(!pattern.matches_prefix("192.a"));
+
+        assert!(!pattern.matches("192.168"));
+        assert!(pattern.matches("192.168.0.1"));
+    }
+
+    #[test]
+    fn matches_sets_and_unbounded_repetitions() {
+        let pattern = Pattern::new("[A-F0-9]+h?").unwrap();
+
+        assert!(pattern.matches("FF00"));
+        assert!(pattern.matches("1Ah"));
+        assert!(!pattern.matches("1Ahh"));
+        assert!(!pattern.matches_prefix("G"));
+
+        let pattern = Pattern::new("[^,]*").unwrap();
+
+        assert!(pattern.matches("no commas"));
+        assert!(!pattern.matches_prefix("a,b"));
+    }
+
+    #[test]
+    fn rejects_invalid_patterns() {
+        assert_eq!(Pattern::new("+a"), Err(Error::NothingToRepeat));
+        assert_eq!(Pattern::new("[a-z"), Err(Error::UnexpectedEnd));
+        assert_eq!(Pattern::new(r"\d{3,1}"), Err(Error::InvalidRepetition));
+    }
+}
@@ -0,0 +1,206 @@
+use crate::widget::text_input::{Cursor, Value};
+
+use std::collections::VecDeque;
+
+/// The undo history of a text input.
+#[derive(Debug, Clone, Default)]
+pub struct History {
+    undo: VecDeque<Entry>,
+    redo: Vec<Entry>,
+    last_edit: Option<Edit>,
+}
+
+/// A kind of change to the value of a text input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Edit {
+    /// A character was typed.
+    Type { is_whitespace: bool },
+
+    /// A character was deleted.
+    Delete,
+
+    /// The selection was replaced, like when pasting or cutting text.
+    Replace,
+}
+
+#[derive(Debug, Clone)]
+struct Entry {
+    value: Value,
+    cursor: Cursor,
+}
+
+impl History {
+    /// Records the value and cursor of a text input right before the given
+    /// [`Edit`] is applied, keeping at most `limit` steps.
+    ///
+    /// Consecutive typing is grouped into words, including their trailing
+    /// whitespace, and consecutive deletions are grouped together.
+    pub fn record(
+        &mut self,
+        edit: Edit,
+        value: &Value,
+        cursor: Cursor,
+        limit: usize,
+    ) {
+        let is_grouped = match (self.last_edit, edit) {
+            (
+                Some(Edit::Type {
+                    is_whitespace: was_whitespace,
+                }),
+                Edit::Type { is_whitespace },
+            ) => !was_whitespace || is_whitespace,
+            (Some(Edit::Delete), Edit::Delete) => true,
+            _ => false,
+        };
+
+        self.last_edit = Some(edit);
+        self.redo.clear();
+
+        if is_grouped {
+            return;
+        }
+
+        self.undo.push_back(Entry {
+            value: value.clone(),
+            cursor,
+        });
+
+        while self.undo.len() > limit {
+            let _ = self.undo.pop_front();
+        }
+    }
+
+    /// Ends the current group of edits, so the next one starts a new step.
+    pub fn seal(&mut self) {
+        self.last_edit = None;
+    }
+
+    /// Undoes the last step, returning the value and cursor to restore.
+    pub fn undo(
+        &mut self,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Option<(Value, Cursor)> {
+        self.last_edit = None;
+
+        // Edits that did not change the value are skipped
+        while let Some(entry) = self.undo.pop_back() {
+            if entry.value != *value {
+                self.redo.push(Entry {
+                    value: value.clone(),
+                    cursor,
+                });
+
+                return Some((entry.value, entry.cursor));
+            }
+        }
+
+        None
+    }
+
+    /// Redoes the last undone step, returning the value and cursor to
+    /// restore.
+    pub fn redo(
+        &mut self,
+        value: &Value,
+        cursor: Cursor,
+    ) -> Option<(Value, Cursor)> {
+        self.last_edit = None;
+
+        let entry = self.redo.pop()?;
+
+        self.undo.push_back(Entry {
+            value: value.clone(),
+            cursor,
+        });
+
+        Some((entry.value, entry.cursor))
+    }
+
+    /// Forgets all the recorded steps.
+    pub fn clear(&mut self) {
+        *self = History::default();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn type_text(history: &mut History, value: &mut Value, text: &str) {
+        for c in text.chars() {
+            history.record(
+                Edit::Type {
+                    is_whitespace: c.is_whitespace(),
+                },
+                value,
+                Cursor::default(),
+                100,
+            );
+
+            value.insert(value.len(), c);
+        }
+    }
+
+    fn undo(history: &mut History, value: &mut Value) -> bool {
+        match history.undo(value, Cursor::default()) {
+            Some((previous, _)) => {
+                *value = previous;
+                true
+            }
+            None => false,
+        }
+    }
+
+    #[test]
+    fn groups_typing_into_words() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+
+        type_text(&mut history, &mut value, "hello big world");
+
+        assert!(undo(&mut history, &mut value));
+        assert_eq!(value.to_string(), "hello big ");
+
+        assert!(undo(&mut history, &mut value));
+        assert_eq!(value.to_string(), "hello ");
+
+        assert!(undo(&mut history, &mut value));
+        assert_eq!(value.to_string(), "");
+
+        assert!(!undo(&mut history, &mut value));
+    }
+
+    #[test]
+    fn redoes_undone_steps() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+
+        type_text(&mut history, &mut value, "one two");
+
+        assert!(undo(&mut history, &mut value));
+
+        let (redone, _) =
+            history.redo(&value, Cursor::default()).expect("Redo step");
+
+        assert_eq!(redone.to_string(), "one two");
+        assert!(history.redo(&redone, Cursor::default()).is_none());
+    }
+
+    #[test]
+    fn respects_the_limit() {
+        let mut history = History::default();
+        let mut value = Value::new("");
+
+        for text in &["a", "b", "c"] {
+            history.record(Edit::Replace, &value, Cursor::default(), 2);
+            value.insert_many(value.len(), Value::new(text));
+        }
+
+        assert!(undo(&mut history, &mut value));
+        assert!(undo(&mut history, &mut value));
+        assert_eq!(value.to_string(), "a");
+
+        assert!(!undo(&mut history, &mut value));
+    }
+}
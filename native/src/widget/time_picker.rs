@@ -0,0 +1,613 @@
+//! Let your users pick a time of the day.
+//!
+//! A [`TimePicker`] has some local [`State`].
+use crate::alignment;
+use crate::event::{self, Event};
+use crate::keyboard;
+use crate::layout;
+use crate::mouse;
+use crate::renderer;
+use crate::text::{self, Text};
+use crate::touch;
+use crate::{
+    Clipboard, Color, Element, Hasher, Layout, Length, Padding, Point,
+    Rectangle, Shell, Size, Widget,
+};
+
+use std::fmt;
+use std::hash::Hash;
+
+pub use iced_style::time_picker::{Style, StyleSheet};
+
+/// A time of the day, with a precision of minutes.
+///
+/// Times are ordered chronologically.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Time {
+    hour: u32,
+    minute: u32,
+}
+
+impl Time {
+    /// Creates a new [`Time`] from an hour from 0 to 23 and a minute from 0
+    /// to 59.
+    ///
+    /// Returns `None` if the time does not exist.
+    pub fn from_hm(hour: u32, minute: u32) -> Option<Time> {
+        if hour < 24 && minute < 60 {
+            Some(Time { hour, minute })
+        } else {
+            None
+        }
+    }
+
+    /// Returns the hour of the [`Time`], from 0 to 23.
+    pub fn hour(&self) -> u32 {
+        self.hour
+    }
+
+    /// Returns the minute of the [`Time`], from 0 to 59.
+    pub fn minute(&self) -> u32 {
+        self.minute
+    }
+}
+
+impl fmt::Display for Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:02}:{:02}", self.hour, self.minute)
+    }
+}
+
+/// A field that picks a [`Time`] with an hour spinner and a minute spinner.
+///
+/// Each spinner can be stepped with its buttons, with the mouse wheel while
+/// hovered, or with the arrow keys while focused. The left and right arrow
+/// keys move the focus between the spinners. Both spinners wrap around.
+///
+/// # Example
+/// ```
+/// # use iced_native::widget::time_picker::{self, Time};
+/// #
+/// # type TimePicker<'a, Message> =
+/// #     iced_native::widget::TimePicker<'a, Message, iced_native::renderer::Null>;
+/// #
+/// #[derive(Debug, Clone)]
+/// enum Message {
+///     AlarmChanged(Time),
+/// }
+///
+/// let mut state = time_picker::State::new();
+/// let alarm = Time::from_hm(7, 30).unwrap();
+///
+/// let time_picker = TimePicker::new(&mut state, alarm, Message::AlarmChanged)
+///     .minute_step(15);
+/// ```
+#[allow(missing_debug_implementations)]
+pub struct TimePicker<'a, Message, Renderer: text::Renderer> {
+    state: &'a mut State,
+    value: Time,
+    on_change: Box<dyn Fn(Time) -> Message>,
+    minute_step: u32,
+    padding: Padding,
+    text_size: Option<u16>,
+    font: Renderer::Font,
+    style_sheet: Box<dyn StyleSheet + 'a>,
+}
+
+impl<'a, Message, Renderer> TimePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    /// The default padding of a [`TimePicker`].
+    pub const DEFAULT_PADDING: Padding = Padding::new(5);
+
+    /// Creates a new [`TimePicker`].
+    ///
+    /// It expects:
+    ///   * the local [`State`] of the [`TimePicker`]
+    ///   * the current value of the [`TimePicker`]
+    ///   * a function that will be called when the value changes. It
+    ///     receives the new [`Time`] and must produce a `Message`.
+    pub fn new(
+        state: &'a mut State,
+        value: Time,
+        on_change: impl Fn(Time) -> Message + 'static,
+    ) -> Self {
+        TimePicker {
+            state,
+            value,
+            on_change: Box::new(on_change),
+            minute_step: 1,
+            padding: Self::DEFAULT_PADDING,
+            text_size: None,
+            font: Default::default(),
+            style_sheet: Default::default(),
+        }
+    }
+
+    /// Sets the amount of minutes the minute spinner of the [`TimePicker`]
+    /// steps by.
+    ///
+    /// Stepping snaps the minutes to multiples of the step, which should
+    /// divide an hour evenly. By default, it is 1 minute.
+    pub fn minute_step(mut self, minute_step: u32) -> Self {
+        self.minute_step = minute_step.clamp(1, 59);
+        self
+    }
+
+    /// Sets the [`Padding`] of the spinners of the [`TimePicker`].
+    pub fn padding<P: Into<Padding>>(mut self, padding: P) -> Self {
+        self.padding = padding.into();
+        self
+    }
+
+    /// Sets the text size of the [`TimePicker`].
+    pub fn text_size(mut self, size: u16) -> Self {
+        self.text_size = Some(size);
+        self
+    }
+
+    /// Sets the font of the [`TimePicker`].
+    pub fn font(mut self, font: Renderer::Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Sets the style of the [`TimePicker`].
+    pub fn style(
+        mut self,
+        style_sheet: impl Into<Box<dyn StyleSheet + 'a>>,
+    ) -> Self {
+        self.style_sheet = style_sheet.into();
+        self
+    }
+
+    /// Steps the given [`Segment`] once, forwards or backwards.
+    fn step(
+        &mut self,
+        segment: Segment,
+        is_forward: bool,
+        shell: &mut Shell<'_, Message>,
+    ) {
+        let Time { hour, minute } = self.value;
+
+        let value = match segment {
+            Segment::Hour => Time {
+                hour: if is_forward {
+                    (hour + 1) % 24
+                } else {
+                    (hour + 23) % 24
+                },
+                minute,
+            },
+            Segment::Minute => {
+                let step = self.minute_step;
+
+                let minute = if is_forward {
+                    let next = (minute / step + 1) * step;
+
+                    if next < 60 {
+                        next
+                    } else {
+                        0
+                    }
+                } else if minute % step != 0 {
+                    minute - minute % step
+                } else if minute >= step {
+                    minute - step
+                } else {
+                    59 / step * step
+                };
+
+                Time { hour, minute }
+            }
+        };
+
+        if value != self.value {
+            self.value = value;
+
+            shell.publish((self.on_change)(value));
+        }
+    }
+}
+
+/// The local state of a [`TimePicker`].
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    focused: Option<Segment>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Segment {
+    Hour,
+    Minute,
+}
+
+impl State {
+    /// Creates a new [`State`], representing an unfocused [`TimePicker`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns whether the [`TimePicker`] is currently focused or not.
+    pub fn is_focused(&self) -> bool {
+        self.focused.is_some()
+    }
+
+    /// Focuses the hour spinner of the [`TimePicker`].
+    pub fn focus(&mut self) {
+        self.focused = Some(Segment::Hour);
+    }
+
+    /// Unfocuses the [`TimePicker`].
+    pub fn unfocus(&mut self) {
+        self.focused = None;
+    }
+}
+
+impl<'a, Message, Renderer> Widget<Message, Renderer>
+    for TimePicker<'a, Message, Renderer>
+where
+    Renderer: text::Renderer,
+{
+    fn width(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn height(&self) -> Length {
+        Length::Shrink
+    }
+
+    fn layout(
+        &self,
+        renderer: &Renderer,
+        limits: &layout::Limits,
+    ) -> layout::Node {
+        let text_size = self.text_size.unwrap_or(renderer.default_size());
+
+        let digits = renderer
+            .measure_width("00", text_size, self.font.clone())
+            .ceil();
+        let colon = renderer
+            .measure_width(":", text_size, self.font.clone())
+            .ceil();
+
+        let width = digits + f32::from(self.padding.horizontal());
+        let value_height =
+            f32::from(text_size) + f32::from(self.padding.vertical());
+        let button_height = f32::from(text_size);
+
+        let segment = |x: f32| {
+            let up = layout::Node::new(Size::new(width, button_height));
+
+            let mut value = layout::Node::new(Size::new(width, value_height));
+            value.move_to(Point::new(0.0, button_height));
+
+            let mut down = layout::Node::new(Size::new(width, button_height));
+            down.move_to(Point::new(0.0, button_height + value_height));
+
+            let mut segment = layout::Node::with_children(
+                Size::new(width, value_height + button_height * 2.0),
+                vec![up, value, down],
+            );
+            segment.move_to(Point::new(x, 0.0));
+
+            segment
+        };
+
+        let hour = segment(0.0);
+        let minute = segment(width + colon);
+
+        let mut separator =
+            layout::Node::new(Size::new(colon, hour.size().height));
+        separator.move_to(Point::new(width, 0.0));
+
+        let size = limits.resolve(Size::new(
+            width * 2.0 + colon,
+            value_height + button_height * 2.0,
+        ));
+
+        layout::Node::with_children(size, vec![hour, separator, minute])
+    }
+
+    fn on_event(
+        &mut self,
+        event: Event,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _renderer: &Renderer,
+        _clipboard: &mut dyn Clipboard,
+        shell: &mut Shell<'_, Message>,
+    ) -> event::Status {
+        let mut children = layout.children();
+        let hour = children.next().unwrap();
+        let _separator = children.next().unwrap();
+        let minute = children.next().unwrap();
+
+        let segments = [(Segment::Hour, hour), (Segment::Minute, minute)];
+
+        match event {
+            Event::Mouse(mouse::Event::ButtonPressed(mouse::Button::Left))
+            | Event::Touch(touch::Event::FingerPressed { .. }) => {
+                if !layout.bounds().contains(cursor_position) {
+                    self.state.focused = None;
+
+                    return event::Status::Ignored;
+                }
+
+                let segment = segments
+                    .iter()
+                    .find(|(_, layout)| {
+                        layout.bounds().contains(cursor_position)
+                    })
+                    .copied();
+
+                let (segment, layout) = match segment {
+                    Some(segment) => segment,
+                    None => (self.state.focused.unwrap_or(Segment::Hour), hour),
+                };
+
+                self.state.focused = Some(segment);
+
+                let mut parts = layout.children();
+                let up = parts.next().unwrap();
+                let _value = parts.next().unwrap();
+                let down = parts.next().unwrap();
+
+                if up.bounds().contains(cursor_position) {
+                    self.step(segment, true, shell);
+                } else if down.bounds().contains(cursor_position) {
+                    self.step(segment, false, shell);
+                }
+
+                return event::Status::Captured;
+            }
+            Event::Mouse(mouse::Event::WheelScrolled { delta }) => {
+                let y = match delta {
+                    mouse::ScrollDelta::Lines { y, .. }
+                    | mouse::ScrollDelta::Pixels { y, .. } => y,
+                };
+
+                if let Some((segment, _)) =
+                    segments.iter().find(|(_, layout)| {
+                        layout.bounds().contains(cursor_position)
+                    })
+                {
+                    if y != 0.0 {
+                        self.step(*segment, y > 0.0, shell);
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            Event::Keyboard(keyboard::Event::KeyPressed {
+                key_code, ..
+            }) => {
+                if let Some(segment) = self.state.focused {
+                    match key_code {
+                        keyboard::KeyCode::Up => {
+                            self.step(segment, true, shell)
+                        }
+                        keyboard::KeyCode::Down => {
+                            self.step(segment, false, shell)
+                        }
+                        keyboard::KeyCode::Left => {
+                            self.state.focused = Some(Segment::Hour)
+                        }
+                        keyboard::KeyCode::Right => {
+                            self.state.focused = Some(Segment::Minute)
+                        }
+                        keyboard::KeyCode::Escape => self.state.focused = None,
+                        _ => return event::Status::Ignored,
+                    }
+
+                    return event::Status::Captured;
+                }
+            }
+            _ => {}
+        }
+
+        event::Status::Ignored
+    }
+
+    fn mouse_interaction(
+        &self,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+        _renderer: &Renderer,
+    ) -> mouse::Interaction {
+        if layout.bounds().contains(cursor_position) {
+            mouse::Interaction::Pointer
+        } else {
+            mouse::Interaction::default()
+        }
+    }
+
+    fn draw(
+        &self,
+        renderer: &mut Renderer,
+        _style: &renderer::Style,
+        layout: Layout<'_>,
+        cursor_position: Point,
+        _viewport: &Rectangle,
+    ) {
+        let bounds = layout.bounds();
+        let mut children = layout.children();
+        let hour = children.next().unwrap();
+        let separator = children.next().unwrap();
+        let minute = children.next().unwrap();
+
+        let style = self.style_sheet.active();
+        let size = f32::from(self.text_size.unwrap_or(renderer.default_size()));
+
+        renderer.fill_quad(
+            renderer::Quad {
+                bounds,
+                border_radius: style.border_radius,
+                border_width: style.border_width,
+                border_color: style.border_color,
+            },
+            style.background,
+        );
+
+        let label = |renderer: &mut Renderer,
+                     content: &str,
+                     bounds: Rectangle,
+                     color: Color| {
+            renderer.fill_text(Text {
+                content,
+                bounds: Rectangle {
+                    x: bounds.center_x(),
+                    y: bounds.center_y(),
+                    ..bounds
+                },
+                size,
+                color,
+                font: self.font.clone(),
+                horizontal_alignment: alignment::Horizontal::Center,
+                vertical_alignment: alignment::Vertical::Center,
+                format: text::Format::default(),
+                decorations: &[],
+            });
+        };
+
+        label(renderer, ":", separator.bounds(), style.text_color);
+
+        let segments = [
+            (Segment::Hour, hour, self.value.hour),
+            (Segment::Minute, minute, self.value.minute),
+        ];
+
+        for (segment, layout, value) in segments.iter() {
+            let mut parts = layout.children();
+            let up = parts.next().unwrap();
+            let value_layout = parts.next().unwrap();
+            let down = parts.next().unwrap();
+
+            let segment_style = if self.state.focused == Some(*segment) {
+                self.style_sheet.focused()
+            } else {
+                style
+            };
+
+            if let Some(background) = segment_style.segment_background {
+                renderer.fill_quad(
+                    renderer::Quad {
+                        bounds: value_layout.bounds(),
+                        border_radius: segment_style.segment_border_radius,
+                        border_width: 0.0,
+                        border_color: Color::TRANSPARENT,
+                    },
+                    background,
+                );
+            }
+
+            label(
+                renderer,
+                &format!("{:02}", value),
+                value_layout.bounds(),
+                segment_style.text_color,
+            );
+
+            for (button, icon) in &[(up, "+"), (down, "-")] {
+                let bounds = button.bounds();
+
+                let button_style = if bounds.contains(cursor_position) {
+                    self.style_sheet.hovered()
+                } else {
+                    segment_style
+                };
+
+                if let Some(background) = button_style.button_background {
+                    renderer.fill_quad(
+                        renderer::Quad {
+                            bounds,
+                            border_radius: button_style.segment_border_radius,
+                            border_width: 0.0,
+                            border_color: Color::TRANSPARENT,
+                        },
+                        background,
+                    );
+                }
+
+                label(renderer, icon, bounds, button_style.icon_color);
+            }
+        }
+    }
+
+    fn hash_layout(&self, state: &mut Hasher) {
+        struct Marker;
+        std::any::TypeId::of::<Marker>().hash(state);
+
+        self.padding.hash(state);
+        self.text_size.hash(state);
+        self.font.hash(state);
+    }
+}
+
+impl<'a, Message, Renderer> From<TimePicker<'a, Message, Renderer>>
+    for Element<'a, Message, Renderer>
+where
+    Message: 'a,
+    Renderer: 'a + text::Renderer,
+{
+    fn from(
+        time_picker: TimePicker<'a, Message, Renderer>,
+    ) -> Element<'a, Message, Renderer> {
+        Element::new(time_picker)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::renderer::Null;
+
+    fn step(
+        value: (u32, u32),
+        minute_step: u32,
+        segment: Segment,
+        is_forward: bool,
+    ) -> Option<(u32, u32)> {
+        let mut state = State::new();
+        let mut messages = Vec::new();
+
+        let value = Time::from_hm(value.0, value.1).unwrap();
+
+        TimePicker::<Time, Null>::new(&mut state, value, |time| time)
+            .minute_step(minute_step)
+            .step(segment, is_forward, &mut Shell::new(&mut messages));
+
+        messages.pop().map(|time| (time.hour(), time.minute()))
+    }
+
+    #[test]
+    fn wraps_hours_around() {
+        assert_eq!(step((23, 30), 1, Segment::Hour, true), Some((0, 30)));
+        assert_eq!(step((0, 30), 1, Segment::Hour, false), Some((23, 30)));
+    }
+
+    #[test]
+    fn wraps_minutes_around_without_changing_the_hour() {
+        assert_eq!(step((10, 59), 1, Segment::Minute, true), Some((10, 0)));
+        assert_eq!(step((10, 0), 1, Segment::Minute, false), Some((10, 59)));
+
+        assert_eq!(step((10, 45), 15, Segment::Minute, true), Some((10, 0)));
+        assert_eq!(step((10, 0), 15, Segment::Minute, false), Some((10, 45)));
+    }
+
+    #[test]
+    fn snaps_minutes_to_the_step() {
+        assert_eq!(step((10, 7), 15, Segment::Minute, true), Some((10, 15)));
+        assert_eq!(step((10, 7), 15, Segment::Minute, false), Some((10, 0)));
+        assert_eq!(step((10, 52), 15, Segment::Minute, true), Some((10, 0)));
+    }
+
+    #[test]
+    fn wraps_minutes_with_an_uneven_step() {
+        assert_eq!(step((10, 0), 25, Segment::Minute, false), Some((10, 50)));
+        assert_eq!(step((10, 50), 25, Segment::Minute, true), Some((10, 0)));
+        assert_eq!(step((10, 0), 90, Segment::Minute, true), Some((10, 59)));
+    }
+}
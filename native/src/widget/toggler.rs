@@ -235,6 +235,8 @@ where
                 None,
                 self.text_alignment,
                 alignment::Vertical::Center,
+                Default::default(),
+                &[],
             );
         }
 
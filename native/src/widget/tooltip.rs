@@ -1,5 +1,6 @@
 //! Display a widget over another.
 use std::hash::Hash;
+use std::time::{Duration, Instant};
 
 use iced_core::Rectangle;
 
@@ -10,20 +11,38 @@ use crate::renderer;
 use crate::text;
 use crate::widget::container;
 use crate::widget::text::Text;
+use crate::window;
 use crate::{
     Clipboard, Element, Event, Hasher, Layout, Length, Padding, Point, Shell,
     Size, Vector, Widget,
 };
 
 /// An element to display a widget over another.
+///
+/// The tooltip can be some text or any [`Element`], and it can be shown and
+/// hidden after a delay by keeping some local [`State`].
 #[allow(missing_debug_implementations)]
 pub struct Tooltip<'a, Message, Renderer: text::Renderer> {
     content: Element<'a, Message, Renderer>,
-    tooltip: Text<Renderer>,
+    tooltip: Content<'a, Message, Renderer>,
     position: Position,
     style_sheet: Box<dyn container::StyleSheet + 'a>,
     gap: u16,
     padding: u16,
+    delay: Option<Delay<'a>>,
+}
+
+/// The contents of a [`Tooltip`].
+enum Content<'a, Message, Renderer: text::Renderer> {
+    Text(Text<Renderer>),
+    Element(Element<'a, Message, Renderer>),
+}
+
+/// The delays of a [`Tooltip`] and the [`State`] used to track them.
+struct Delay<'a> {
+    state: &'a mut State,
+    show: Duration,
+    hide: Duration,
 }
 
 impl<'a, Message, Renderer> Tooltip<'a, Message, Renderer>
@@ -40,28 +59,81 @@ where
         content: impl Into<Element<'a, Message, Renderer>>,
         tooltip: impl ToString,
         position: Position,
+    ) -> Self {
+        Self::with_content(
+            content.into(),
+            Content::Text(Text::new(tooltip.to_string())),
+            position,
+        )
+    }
+
+    /// Creates a new [`Tooltip`] displaying the given [`Element`] over its
+    /// content, like a [`Column`] with an icon, a title, and a key hint.
+    ///
+    /// The [`Element`] of the tooltip is only displayed; it does not receive
+    /// any events.
+    ///
+    /// [`Column`]: crate::widget::Column
+    pub fn with_element(
+        content: impl Into<Element<'a, Message, Renderer>>,
+        tooltip: impl Into<Element<'a, Message, Renderer>>,
+        position: Position,
+    ) -> Self {
+        Self::with_content(
+            content.into(),
+            Content::Element(tooltip.into()),
+            position,
+        )
+    }
+
+    fn with_content(
+        content: Element<'a, Message, Renderer>,
+        tooltip: Content<'a, Message, Renderer>,
+        position: Position,
     ) -> Self {
         Tooltip {
-            content: content.into(),
-            tooltip: Text::new(tooltip.to_string()),
+            content,
+            tooltip,
             position,
             style_sheet: Default::default(),
             gap: 0,
             padding: Self::DEFAULT_PADDING,
+            delay: None,
         }
     }
 
     /// Sets the size of the text of the [`Tooltip`].
+    ///
+    /// It has no effect on a [`Tooltip`] displaying an [`Element`].
     pub fn size(mut self, size: u16) -> Self {
-        self.tooltip = self.tooltip.size(size);
+        self.tooltip = match self.tooltip {
+            Content::Text(text) => Content::Text(text.size(size)),
+            element => element,
+        };
         self
     }
 
     /// Sets the font of the [`Tooltip`].
     ///
+    /// It has no effect on a [`Tooltip`] displaying an [`Element`].
+    ///
     /// [`Font`]: Renderer::Font
     pub fn font(mut self, font: impl Into<Renderer::Font>) -> Self {
-        self.tooltip = self.tooltip.font(font);
+        self.tooltip = match self.tooltip {
+            Content::Text(text) => Content::Text(text.font(font)),
+            element => element,
+        };
+        self
+    }
+
+    /// Sets the decorations of the text of the [`Tooltip`].
+    ///
+    /// It has no effect on a [`Tooltip`] displaying an [`Element`].
+    pub fn decorations(mut self, decorations: Vec<text::Decoration>) -> Self {
+        self.tooltip = match self.tooltip {
+            Content::Text(text) => Content::Text(text.decorations(decorations)),
+            element => element,
+        };
         self
     }
 
@@ -77,6 +149,20 @@ where
         self
     }
 
+    /// Sets the delays before the [`Tooltip`] is shown, once the cursor
+    /// hovers its content, and before it is hidden, once the cursor leaves.
+    ///
+    /// The hovering is tracked in the given [`State`].
+    pub fn delay(
+        mut self,
+        state: &'a mut State,
+        show: Duration,
+        hide: Duration,
+    ) -> Self {
+        self.delay = Some(Delay { state, show, hide });
+        self
+    }
+
     /// Sets the style of the [`Tooltip`].
     pub fn style(
         mut self,
@@ -102,6 +188,66 @@ pub enum Position {
     Right,
 }
 
+/// The local state of a [`Tooltip`] with a delay.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct State {
+    is_visible: bool,
+    hovered_at: Option<Instant>,
+    left_at: Option<Instant>,
+    cursor_position: Point,
+}
+
+impl State {
+    /// Creates a new [`State`].
+    pub fn new() -> Self {
+        State::default()
+    }
+
+    /// Returns whether the [`Tooltip`] is currently shown.
+    pub fn is_visible(&self) -> bool {
+        self.is_visible
+    }
+
+    /// Updates the visibility of the [`Tooltip`] at the given time and
+    /// returns when it needs to be updated again, if ever.
+    fn update(
+        &mut self,
+        is_hovered: bool,
+        now: Instant,
+        show: Duration,
+        hide: Duration,
+    ) -> Option<Instant> {
+        if is_hovered {
+            self.left_at = None;
+
+            let hovered_at = *self.hovered_at.get_or_insert(now);
+
+            if !self.is_visible {
+                if now < hovered_at + show {
+                    return Some(hovered_at + show);
+                }
+
+                self.is_visible = true;
+            }
+        } else {
+            self.hovered_at = None;
+
+            if self.is_visible {
+                let left_at = *self.left_at.get_or_insert(now);
+
+                if now < left_at + hide {
+                    return Some(left_at + hide);
+                }
+
+                self.is_visible = false;
+                self.left_at = None;
+            }
+        }
+
+        None
+    }
+}
+
 impl<'a, Message, Renderer> Widget<Message, Renderer>
     for Tooltip<'a, Message, Renderer>
 where
@@ -132,6 +278,25 @@ where
         clipboard: &mut dyn Clipboard,
         shell: &mut Shell<'_, Message>,
     ) -> event::Status {
+        if let Some(delay) = &mut self.delay {
+            let is_hovered = layout.bounds().contains(cursor_position);
+
+            if is_hovered {
+                delay.state.cursor_position = cursor_position;
+            }
+
+            let now = match event {
+                Event::Window(window::Event::RedrawRequested(now)) => now,
+                _ => Instant::now(),
+            };
+
+            if let Some(at) =
+                delay.state.update(is_hovered, now, delay.show, delay.hide)
+            {
+                shell.request_redraw(window::RedrawRequest::At(at));
+            }
+        }
+
         self.content.widget.on_event(
             event,
             layout,
@@ -175,8 +340,14 @@ where
 
         let bounds = layout.bounds();
 
-        if bounds.contains(cursor_position) {
-            let gap = f32::from(self.gap);
+        let (is_visible, cursor_position) = match &self.delay {
+            Some(delay) => {
+                (delay.state.is_visible, delay.state.cursor_position)
+            }
+            None => (bounds.contains(cursor_position), cursor_position),
+        };
+
+        if is_visible {
             let style = self.style_sheet.style();
 
             let defaults = renderer::Style {
@@ -185,86 +356,59 @@ where
                     .unwrap_or(inherited_style.text_color),
             };
 
-            let text_layout = Widget::<(), Renderer>::layout(
-                &self.tooltip,
-                renderer,
-                &layout::Limits::new(Size::ZERO, viewport.size())
-                    .pad(Padding::new(self.padding)),
-            );
-
-            let padding = f32::from(self.padding);
-            let text_bounds = text_layout.bounds();
-            let x_center = bounds.x + (bounds.width - text_bounds.width) / 2.0;
-            let y_center =
-                bounds.y + (bounds.height - text_bounds.height) / 2.0;
-
-            let mut tooltip_bounds = {
-                let offset = match self.position {
-                    Position::Top => Vector::new(
-                        x_center,
-                        bounds.y - text_bounds.height - gap - padding,
-                    ),
-                    Position::Bottom => Vector::new(
-                        x_center,
-                        bounds.y + bounds.height + gap + padding,
-                    ),
-                    Position::Left => Vector::new(
-                        bounds.x - text_bounds.width - gap - padding,
-                        y_center,
-                    ),
-                    Position::Right => Vector::new(
-                        bounds.x + bounds.width + gap + padding,
-                        y_center,
-                    ),
-                    Position::FollowCursor => Vector::new(
-                        cursor_position.x,
-                        cursor_position.y - text_bounds.height,
-                    ),
-                };
+            let limits = layout::Limits::new(Size::ZERO, viewport.size())
+                .pad(Padding::new(self.padding));
 
-                Rectangle {
-                    x: offset.x - padding,
-                    y: offset.y - padding,
-                    width: text_bounds.width + padding * 2.0,
-                    height: text_bounds.height + padding * 2.0,
+            let tooltip_layout = match &self.tooltip {
+                Content::Text(text) => {
+                    Widget::<(), Renderer>::layout(text, renderer, &limits)
                 }
+                Content::Element(element) => element.layout(renderer, &limits),
             };
 
-            if tooltip_bounds.x < viewport.x {
-                tooltip_bounds.x = viewport.x;
-            } else if viewport.x + viewport.width
-                < tooltip_bounds.x + tooltip_bounds.width
-            {
-                tooltip_bounds.x =
-                    viewport.x + viewport.width - tooltip_bounds.width;
-            }
+            let padding = f32::from(self.padding);
+            let size = tooltip_layout.size();
 
-            if tooltip_bounds.y < viewport.y {
-                tooltip_bounds.y = viewport.y;
-            } else if viewport.y + viewport.height
-                < tooltip_bounds.y + tooltip_bounds.height
-            {
-                tooltip_bounds.y =
-                    viewport.y + viewport.height - tooltip_bounds.height;
-            }
+            let tooltip_bounds = place(
+                self.position,
+                bounds,
+                cursor_position,
+                Size::new(
+                    size.width + padding * 2.0,
+                    size.height + padding * 2.0,
+                ),
+                f32::from(self.gap),
+                *viewport,
+            );
 
             renderer.with_layer(*viewport, |renderer| {
                 container::draw_background(renderer, &style, tooltip_bounds);
 
-                Widget::<(), Renderer>::draw(
-                    &self.tooltip,
-                    renderer,
-                    &defaults,
-                    Layout::with_offset(
-                        Vector::new(
-                            tooltip_bounds.x + padding,
-                            tooltip_bounds.y + padding,
-                        ),
-                        &text_layout,
+                let layout = Layout::with_offset(
+                    Vector::new(
+                        tooltip_bounds.x + padding,
+                        tooltip_bounds.y + padding,
                     ),
-                    cursor_position,
-                    viewport,
+                    &tooltip_layout,
                 );
+
+                match &self.tooltip {
+                    Content::Text(text) => Widget::<(), Renderer>::draw(
+                        text,
+                        renderer,
+                        &defaults,
+                        layout,
+                        cursor_position,
+                        viewport,
+                    ),
+                    Content::Element(element) => element.draw(
+                        renderer,
+                        &defaults,
+                        layout,
+                        cursor_position,
+                        viewport,
+                    ),
+                }
             });
         }
     }
@@ -277,6 +421,77 @@ where
     }
 }
 
+/// Places a tooltip of the given size next to the bounds of its content, or
+/// the cursor, moving it to the opposite side and then inside the viewport
+/// whenever it does not fit.
+fn place(
+    position: Position,
+    bounds: Rectangle,
+    cursor_position: Point,
+    size: Size,
+    gap: f32,
+    viewport: Rectangle,
+) -> Rectangle {
+    let x_center = bounds.x + (bounds.width - size.width) / 2.0;
+    let y_center = bounds.y + (bounds.height - size.height) / 2.0;
+
+    let top = bounds.y - gap - size.height;
+    let bottom = bounds.y + bounds.height + gap;
+    let left = bounds.x - gap - size.width;
+    let right = bounds.x + bounds.width + gap;
+
+    let fits_above = |y: f32| y >= viewport.y;
+    let fits_below = |y: f32| y + size.height <= viewport.y + viewport.height;
+    let fits_before = |x: f32| x >= viewport.x;
+    let fits_after = |x: f32| x + size.width <= viewport.x + viewport.width;
+
+    let origin = match position {
+        Position::Top if !fits_above(top) && fits_below(bottom) => {
+            Point::new(x_center, bottom)
+        }
+        Position::Top => Point::new(x_center, top),
+        Position::Bottom if !fits_below(bottom) && fits_above(top) => {
+            Point::new(x_center, top)
+        }
+        Position::Bottom => Point::new(x_center, bottom),
+        Position::Left if !fits_before(left) && fits_after(right) => {
+            Point::new(right, y_center)
+        }
+        Position::Left => Point::new(left, y_center),
+        Position::Right if !fits_after(right) && fits_before(left) => {
+            Point::new(left, y_center)
+        }
+        Position::Right => Point::new(right, y_center),
+        Position::FollowCursor => {
+            let above = cursor_position.y - size.height;
+            let below = cursor_position.y + gap;
+
+            if !fits_above(above) && fits_below(below) {
+                Point::new(cursor_position.x, below)
+            } else {
+                Point::new(cursor_position.x, above)
+            }
+        }
+    };
+
+    let x = origin
+        .x
+        .min(viewport.x + viewport.width - size.width)
+        .max(viewport.x);
+
+    let y = origin
+        .y
+        .min(viewport.y + viewport.height - size.height)
+        .max(viewport.y);
+
+    Rectangle {
+        x,
+        y,
+        width: size.width,
+        height: size.height,
+    }
+}
+
 impl<'a, Message, Renderer> From<Tooltip<'a, Message, Renderer>>
     for Element<'a, Message, Renderer>
 where
@@ -289,3 +504,48 @@ where
         Element::new(column)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn delays_showing_and_hiding() {
+        let show = Duration::from_millis(500);
+        let hide = Duration::from_millis(200);
+        let start = Instant::now();
+
+        let mut state = State::new();
+
+        assert_eq!(state.update(true, start, show, hide), Some(start + show));
+        assert!(!state.is_visible());
+
+        assert_eq!(state.update(true, start + show, show, hide), None);
+        assert!(state.is_visible());
+
+        let left_at = start + Duration::from_secs(1);
+
+        assert_eq!(
+            state.update(false, left_at, show, hide),
+            Some(left_at + hide)
+        );
+        assert!(state.is_visible());
+
+        assert_eq!(state.update(false, left_at + hide, show, hide), None);
+        assert!(!state.is_visible());
+    }
+
+    #[test]
+    fn flips_to_the_opposite_side() {
+        let viewport = Rectangle::new(Point::ORIGIN, Size::new(200.0, 200.0));
+        let bounds =
+            Rectangle::new(Point::new(50.0, 5.0), Size::new(100.0, 20.0));
+        let size = Size::new(60.0, 30.0);
+
+        let tooltip =
+            place(Position::Top, bounds, Point::ORIGIN, size, 5.0, viewport);
+
+        assert_eq!(tooltip.y, 30.0);
+        assert_eq!(tooltip.x, 70.0);
+    }
+}
@@ -1,6 +1,8 @@
 //! Build window-based GUI applications.
 mod action;
 mod event;
+mod redraw_request;
 
 pub use action::Action;
 pub use event::Event;
+pub use redraw_request::RedrawRequest;
@@ -1,4 +1,5 @@
 use std::path::PathBuf;
+use std::time::Instant;
 
 /// A window-related event.
 #[derive(PartialEq, Clone, Debug)]
@@ -48,4 +49,11 @@ pub enum Event {
     /// There will be a single `FilesHoveredLeft` event triggered even if
     /// multiple files were hovered.
     FilesHoveredLeft,
+
+    /// A window is about to be redrawn at the given time.
+    ///
+    /// Widgets that are animated can use this event to advance their
+    /// animations and schedule the next frame with
+    /// [`Shell::request_redraw`](crate::Shell::request_redraw).
+    RedrawRequested(Instant),
 }
@@ -0,0 +1,12 @@
+use std::time::Instant;
+
+/// A request to redraw a window, produced by widgets that need to be
+/// animated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum RedrawRequest {
+    /// Redraw the next frame.
+    NextFrame,
+
+    /// Redraw at the given time.
+    At(Instant),
+}
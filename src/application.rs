@@ -204,6 +204,7 @@ pub trait Application: Sized {
                 default_font: settings.default_font,
                 default_text_size: settings.default_text_size,
                 text_multithreading: settings.text_multithreading,
+                direction: settings.direction,
                 antialiasing: if settings.antialiasing {
                     Some(crate::renderer::settings::Antialiasing::MSAAx4)
                 } else {
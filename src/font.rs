@@ -0,0 +1,5 @@
+//! Describe and load fonts.
+pub use crate::runtime::font::{Descriptor, Error, Stretch, Style, Weight};
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use crate::runtime::font::load;
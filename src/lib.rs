@@ -188,6 +188,7 @@ mod sandbox;
 
 pub mod clipboard;
 pub mod executor;
+pub mod font;
 pub mod keyboard;
 pub mod mouse;
 pub mod settings;
@@ -245,6 +246,6 @@ pub use settings::Settings;
 pub use runtime::alignment;
 pub use runtime::futures;
 pub use runtime::{
-    Alignment, Background, Color, Command, Font, Length, Point, Rectangle,
-    Size, Subscription, Vector,
+    Alignment, Background, Color, Command, ContentFit, Direction, Font, Length,
+    Point, Rectangle, Size, Subscription, Vector,
 };
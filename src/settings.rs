@@ -1,5 +1,6 @@
 //! Configure your application.
 use crate::window;
+use crate::Direction;
 
 /// The settings of an application.
 #[derive(Debug, Clone)]
@@ -48,6 +49,13 @@ pub struct Settings<Flags> {
     /// [`Canvas`]: crate::widget::Canvas
     pub antialiasing: bool,
 
+    /// The [`Direction`] in which the user interface is laid out.
+    ///
+    /// Individual containers can override it.
+    ///
+    /// By default, it is [`Direction::LeftToRight`].
+    pub direction: Direction,
+
     /// Whether the [`Application`] should exit when the user requests the
     /// window to close (e.g. the user presses the close button).
     ///
@@ -72,6 +80,7 @@ impl<Flags> Settings<Flags> {
             default_text_size: default_settings.default_text_size,
             text_multithreading: default_settings.text_multithreading,
             antialiasing: default_settings.antialiasing,
+            direction: default_settings.direction,
             exit_on_close_request: default_settings.exit_on_close_request,
         }
     }
@@ -90,6 +99,7 @@ where
             default_text_size: 20,
             text_multithreading: false,
             antialiasing: false,
+            direction: Direction::LeftToRight,
             exit_on_close_request: true,
         }
     }
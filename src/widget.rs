@@ -16,9 +16,11 @@
 #[cfg(not(target_arch = "wasm32"))]
 mod platform {
     pub use crate::renderer::widget::{
-        button, checkbox, container, pane_grid, pick_list, progress_bar, radio,
-        rule, scrollable, slider, text_input, toggler, tooltip, Column, Row,
-        Space, Text,
+        button, carousel, checkbox, collapsible, color_picker, container,
+        date_picker, drag_and_drop, mouse_area, number_input, pane_grid,
+        pick_list, progress_bar, progress_ring, radio, radio_group,
+        range_slider, reorderable_list, rule, scrollable, slider, text_input,
+        time_picker, toggler, tooltip, Column, Row, Space, Text,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
@@ -28,6 +30,27 @@ mod platform {
     )]
     pub use crate::renderer::widget::canvas;
 
+    #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "canvas", feature = "glow_canvas")))
+    )]
+    pub use crate::renderer::widget::chart;
+
+    #[cfg(any(feature = "code_editor", feature = "glow_code_editor"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "code_editor", feature = "glow_code_editor")))
+    )]
+    pub use crate::renderer::widget::code_editor;
+
+    #[cfg(any(feature = "markdown", feature = "glow_markdown"))]
+    #[cfg_attr(
+        docsrs,
+        doc(cfg(any(feature = "markdown", feature = "glow_markdown")))
+    )]
+    pub use crate::renderer::widget::markdown;
+
     #[cfg(any(feature = "qr_code", feature = "glow_qr_code"))]
     #[cfg_attr(
         docsrs,
@@ -40,7 +63,7 @@ mod platform {
         //! Display images in your user interface.
         pub use crate::runtime::image::Handle;
         pub use crate::runtime::widget::image::viewer;
-        pub use crate::runtime::widget::image::{Image, Viewer};
+        pub use crate::runtime::widget::image::{Image, Playback, State, Viewer};
     }
 
     #[cfg_attr(docsrs, doc(cfg(feature = "svg")))]
@@ -52,16 +75,35 @@ mod platform {
 
     #[doc(no_inline)]
     pub use {
-        button::Button, checkbox::Checkbox, container::Container, image::Image,
-        pane_grid::PaneGrid, pick_list::PickList, progress_bar::ProgressBar,
-        radio::Radio, rule::Rule, scrollable::Scrollable, slider::Slider,
-        svg::Svg, text_input::TextInput, toggler::Toggler, tooltip::Tooltip,
+        button::Button, carousel::Carousel, checkbox::Checkbox,
+        collapsible::Accordion, collapsible::Collapsible,
+        color_picker::ColorPicker, container::Container,
+        date_picker::DatePicker, drag_and_drop::DragSource,
+        drag_and_drop::DropTarget, image::Image, mouse_area::MouseArea,
+        number_input::NumberInput, pane_grid::PaneGrid, pick_list::PickList,
+        progress_bar::ProgressBar, progress_ring::ProgressRing, radio::Radio,
+        radio_group::RadioGroup, range_slider::RangeSlider,
+        reorderable_list::ReorderableList, rule::Rule, scrollable::Scrollable,
+        slider::Slider, svg::Svg, text_input::TextInput,
+        time_picker::TimePicker, toggler::Toggler, tooltip::Tooltip,
     };
 
     #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
     #[doc(no_inline)]
     pub use canvas::Canvas;
 
+    #[cfg(any(feature = "canvas", feature = "glow_canvas"))]
+    #[doc(no_inline)]
+    pub use chart::Chart;
+
+    #[cfg(any(feature = "code_editor", feature = "glow_code_editor"))]
+    #[doc(no_inline)]
+    pub use code_editor::CodeEditor;
+
+    #[cfg(any(feature = "markdown", feature = "glow_markdown"))]
+    #[doc(no_inline)]
+    pub use markdown::Markdown;
+
     #[cfg(any(feature = "qr_code", feature = "glow_qr_code"))]
     #[doc(no_inline)]
     pub use qr_code::QRCode;
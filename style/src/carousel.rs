@@ -0,0 +1,49 @@
+//! Page through content horizontally.
+use iced_core::{Background, Color};
+
+/// The appearance of a carousel.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Option<Background>,
+    pub indicator_color: Color,
+    pub active_indicator_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: None,
+            indicator_color: [0.8, 0.8, 0.8].into(),
+            active_indicator_color: [0.3, 0.3, 0.3].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a carousel.
+pub trait StyleSheet {
+    /// Produces the style of a carousel.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style::default()
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: 'a + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
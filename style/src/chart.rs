@@ -0,0 +1,70 @@
+//! Plot series of data points with axes and a legend.
+use iced_core::Color;
+
+/// The appearance of a chart.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Style {
+    pub background: Option<Color>,
+    pub grid_color: Color,
+    pub axis_color: Color,
+    pub label_color: Color,
+    pub crosshair_color: Color,
+    pub tooltip_background: Color,
+    pub tooltip_border_color: Color,
+    pub tooltip_text_color: Color,
+}
+
+/// A set of rules that dictate the style of a chart.
+pub trait StyleSheet {
+    /// Produces the style of a chart.
+    fn style(&self) -> Style;
+
+    /// Produces the color of the series at the given index, unless the series
+    /// has a color of its own.
+    ///
+    /// By default, it cycles through a palette of distinct colors.
+    fn series_color(&self, index: usize) -> Color {
+        const PALETTE: [Color; 6] = [
+            Color::from_rgb(0.12, 0.47, 0.71),
+            Color::from_rgb(1.0, 0.5, 0.05),
+            Color::from_rgb(0.17, 0.63, 0.17),
+            Color::from_rgb(0.84, 0.15, 0.16),
+            Color::from_rgb(0.58, 0.4, 0.74),
+            Color::from_rgb(0.55, 0.34, 0.29),
+        ];
+
+        PALETTE[index % PALETTE.len()]
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            background: None,
+            grid_color: Color::from_rgb(0.9, 0.9, 0.9),
+            axis_color: Color::from_rgb(0.5, 0.5, 0.5),
+            label_color: Color::from_rgb(0.3, 0.3, 0.3),
+            crosshair_color: Color::from_rgba(0.0, 0.0, 0.0, 0.4),
+            tooltip_background: Color::WHITE,
+            tooltip_border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            tooltip_text_color: Color::BLACK,
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
@@ -17,6 +17,18 @@ pub trait StyleSheet {
     fn active(&self, is_checked: bool) -> Style;
 
     fn hovered(&self, is_checked: bool) -> Style;
+
+    /// Produces the style of an active checkbox that is neither checked nor
+    /// unchecked.
+    fn active_indeterminate(&self) -> Style {
+        self.active(true)
+    }
+
+    /// Produces the style of a hovered checkbox that is neither checked nor
+    /// unchecked.
+    fn hovered_indeterminate(&self) -> Style {
+        self.hovered(true)
+    }
 }
 
 struct Default;
@@ -0,0 +1,60 @@
+//! Display and edit source code with syntax highlighting.
+use iced_core::Color;
+
+/// The appearance of a code editor.
+///
+/// The colors of the code itself, its background, and its gutter are defined
+/// by the highlighting theme of the editor.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub scrollbar_color: Color,
+}
+
+/// A set of rules that dictate the style of a code editor.
+pub trait StyleSheet {
+    /// Produces the style of an active code editor.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a focused code editor.
+    fn focused(&self) -> Style {
+        self.active()
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            border_radius: 5.0,
+            border_width: 1.0,
+            border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            scrollbar_color: Color::from_rgba(0.5, 0.5, 0.5, 0.5),
+        }
+    }
+
+    fn focused(&self) -> Style {
+        Style {
+            border_color: Color::from_rgb(0.5, 0.5, 0.5),
+            ..self.active()
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
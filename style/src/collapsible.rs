@@ -0,0 +1,67 @@
+//! Show sections of content that can be expanded and collapsed.
+use iced_core::{Background, Color};
+
+/// The appearance of a collapsible section.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub header_background: Option<Background>,
+    pub header_text_color: Color,
+    pub icon_color: Color,
+    pub body_background: Option<Background>,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            header_background: Some(Background::Color(
+                [0.95, 0.95, 0.95].into(),
+            )),
+            header_text_color: Color::BLACK,
+            icon_color: [0.3, 0.3, 0.3].into(),
+            body_background: None,
+            border_radius: 2.0,
+            border_width: 1.0,
+            border_color: [0.8, 0.8, 0.8].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a collapsible section.
+pub trait StyleSheet {
+    fn active(&self, is_open: bool) -> Style;
+
+    fn hovered(&self, is_open: bool) -> Style {
+        let active = self.active(is_open);
+
+        Style {
+            header_background: Some(Background::Color([0.9, 0.9, 0.9].into())),
+            ..active
+        }
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self, _is_open: bool) -> Style {
+        Style::default()
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: 'a + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
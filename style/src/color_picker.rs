@@ -0,0 +1,76 @@
+//! Let your users pick a color.
+use iced_core::{Background, Color};
+
+/// The appearance of a color picker.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Option<Background>,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub handle_color: Color,
+    pub button_background: Option<Background>,
+    pub button_border_radius: f32,
+    pub button_text_color: Color,
+    pub swatch_border_radius: f32,
+    pub swatch_border_color: Color,
+}
+
+/// A set of rules that dictate the style of a color picker.
+pub trait StyleSheet {
+    /// Produces the style of a color picker.
+    fn active(&self) -> Style;
+
+    /// Produces the style of the notation button or a swatch of a color
+    /// picker when hovered.
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            background: None,
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+            handle_color: Color::WHITE,
+            button_background: Some(Background::Color(Color::from_rgb(
+                0.87, 0.87, 0.87,
+            ))),
+            button_border_radius: 2.0,
+            button_text_color: Color::from_rgb(0.3, 0.3, 0.3),
+            swatch_border_radius: 2.0,
+            swatch_border_color: Color::from_rgb(0.7, 0.7, 0.7),
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            button_background: Some(Background::Color(Color::from_rgb(
+                0.8, 0.8, 0.8,
+            ))),
+            button_text_color: Color::BLACK,
+            swatch_border_color: Color::BLACK,
+            ..self.active()
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
@@ -0,0 +1,110 @@
+//! Let your users pick dates from a calendar.
+use iced_core::{Background, Color};
+
+/// The appearance of the field of a date picker.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Color,
+    pub placeholder_color: Color,
+    pub background: Background,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            text_color: Color::BLACK,
+            placeholder_color: [0.4, 0.4, 0.4].into(),
+            background: Background::Color([0.87, 0.87, 0.87].into()),
+            border_radius: 0.0,
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+        }
+    }
+}
+
+/// The appearance of the calendar of a date picker.
+#[derive(Debug, Clone, Copy)]
+pub struct Calendar {
+    pub background: Background,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub text_color: Color,
+    pub weekday_color: Color,
+    pub outside_color: Color,
+    pub disabled_color: Color,
+    pub day_border_radius: f32,
+    pub hovered_background: Background,
+    pub selected_background: Background,
+    pub selected_text_color: Color,
+    pub range_background: Background,
+    pub today_border_color: Color,
+}
+
+impl std::default::Default for Calendar {
+    fn default() -> Self {
+        Self {
+            background: Background::Color(Color::WHITE),
+            border_radius: 0.0,
+            border_width: 1.0,
+            border_color: [0.7, 0.7, 0.7].into(),
+            text_color: Color::BLACK,
+            weekday_color: [0.4, 0.4, 0.4].into(),
+            outside_color: [0.6, 0.6, 0.6].into(),
+            disabled_color: [0.8, 0.8, 0.8].into(),
+            day_border_radius: 2.0,
+            hovered_background: Background::Color([0.9, 0.9, 0.9].into()),
+            selected_background: Background::Color([0.2, 0.5, 0.9].into()),
+            selected_text_color: Color::WHITE,
+            range_background: Background::Color([0.82, 0.89, 0.98].into()),
+            today_border_color: [0.2, 0.5, 0.9].into(),
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a date picker.
+pub trait StyleSheet {
+    /// Produces the style of the field of a date picker.
+    fn active(&self) -> Style;
+
+    /// Produces the style of the field of a date picker when hovered.
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of the calendar of a date picker.
+    fn calendar(&self) -> Calendar {
+        Calendar::default()
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            border_color: Color::BLACK,
+            ..self.active()
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
@@ -0,0 +1,66 @@
+//! Highlight the targets of drag and drop interactions.
+use iced_core::{Background, Color};
+
+/// The appearance of a drop target.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Option<Background>,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+}
+
+impl std::default::Default for Style {
+    fn default() -> Self {
+        Self {
+            background: None,
+            border_radius: 0.0,
+            border_width: 0.0,
+            border_color: Color::TRANSPARENT,
+        }
+    }
+}
+
+/// A set of rules that dictate the style of a drop target.
+pub trait StyleSheet {
+    /// Produces the style of a drop target.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a drop target when an item that it accepts is
+    /// dragged over it.
+    fn hovered(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style::default()
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            background: Some(Background::Color(Color::from_rgba(
+                0.2, 0.5, 0.9, 0.2,
+            ))),
+            border_radius: 2.0,
+            border_width: 2.0,
+            border_color: Color::from_rgb(0.2, 0.5, 0.9),
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
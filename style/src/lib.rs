@@ -10,15 +10,26 @@
 pub use iced_core::{Background, Color};
 
 pub mod button;
+pub mod carousel;
+pub mod chart;
 pub mod checkbox;
+pub mod code_editor;
+pub mod collapsible;
+pub mod color_picker;
 pub mod container;
+pub mod date_picker;
+pub mod drag_and_drop;
+pub mod markdown;
 pub mod menu;
+pub mod number_input;
 pub mod pane_grid;
 pub mod pick_list;
 pub mod progress_bar;
 pub mod radio;
+pub mod reorderable_list;
 pub mod rule;
 pub mod scrollable;
 pub mod slider;
 pub mod text_input;
+pub mod time_picker;
 pub mod toggler;
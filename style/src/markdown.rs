@@ -0,0 +1,57 @@
+//! Display rich text written in Markdown.
+use iced_core::Color;
+
+/// The appearance of some markdown.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub text_color: Option<Color>,
+    pub link_color: Color,
+    pub hovered_link_color: Color,
+    pub code_color: Option<Color>,
+    pub code_background: Color,
+    pub code_border_radius: f32,
+    pub quote_color: Option<Color>,
+    pub quote_border_color: Color,
+    pub quote_border_width: f32,
+    pub rule_color: Color,
+}
+
+/// A set of rules that dictate the style of some markdown.
+pub trait StyleSheet {
+    /// Produces the style of some markdown.
+    fn style(&self) -> Style;
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn style(&self) -> Style {
+        Style {
+            text_color: None,
+            link_color: Color::from_rgb(0.0, 0.4, 0.8),
+            hovered_link_color: Color::from_rgb(0.0, 0.25, 0.6),
+            code_color: None,
+            code_background: Color::from_rgb(0.94, 0.94, 0.94),
+            code_border_radius: 4.0,
+            quote_color: Some(Color::from_rgb(0.4, 0.4, 0.4)),
+            quote_border_color: Color::from_rgb(0.8, 0.8, 0.8),
+            quote_border_width: 4.0,
+            rule_color: Color::from_rgb(0.8, 0.8, 0.8),
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
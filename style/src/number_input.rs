@@ -0,0 +1,72 @@
+//! Display fields that can be filled with numbers.
+use iced_core::{Background, Color};
+
+/// The appearance of the buttons of a number input.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub button_background: Option<Background>,
+    pub button_border_radius: f32,
+    pub icon_color: Color,
+}
+
+/// A set of rules that dictate the style of the buttons of a number input.
+pub trait StyleSheet {
+    /// Produces the style of an active button.
+    fn active(&self) -> Style;
+
+    /// Produces the style of an hovered button.
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+
+    /// Produces the style of a button that cannot change the value anymore.
+    fn disabled(&self) -> Style {
+        let active = self.active();
+
+        Style {
+            icon_color: Color {
+                a: active.icon_color.a * 0.5,
+                ..active.icon_color
+            },
+            ..active
+        }
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            button_background: Some(Background::Color(Color::from_rgb(
+                0.87, 0.87, 0.87,
+            ))),
+            button_border_radius: 5.0,
+            icon_color: Color::from_rgb(0.3, 0.3, 0.3),
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            button_background: Some(Background::Color(Color::from_rgb(
+                0.8, 0.8, 0.8,
+            ))),
+            ..self.active()
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: 'a + StyleSheet,
+{
+    fn from(style: T) -> Self {
+        Box::new(style)
+    }
+}
@@ -16,6 +16,16 @@ pub trait StyleSheet {
     fn active(&self) -> Style;
 
     fn hovered(&self) -> Style;
+
+    /// Produces the style of the selected radio button of a focused group.
+    fn focused(&self) -> Style {
+        let hovered = self.hovered();
+
+        Style {
+            border_width: hovered.border_width + 1.0,
+            ..hovered
+        }
+    }
 }
 
 struct Default;
@@ -0,0 +1,77 @@
+//! Let your users reorder a list of items.
+use iced_core::{Background, Color};
+
+/// The appearance of a reorderable list.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub handle_background: Option<Background>,
+    pub handle_color: Color,
+    pub handle_border_radius: f32,
+    pub placeholder: Option<Background>,
+}
+
+/// A set of rules that dictate the style of a reorderable list.
+pub trait StyleSheet {
+    /// Produces the style of an item of a reorderable list.
+    fn active(&self) -> Style;
+
+    /// Produces the style of an item of a reorderable list when its drag
+    /// handle is hovered.
+    fn hovered(&self) -> Style;
+
+    /// Produces the style of the item of a reorderable list being dragged.
+    fn dragging(&self) -> Style {
+        self.hovered()
+    }
+
+    /// Produces the style of the focused item of a reorderable list, which
+    /// can be moved with the keyboard.
+    fn focused(&self) -> Style {
+        let hovered = self.hovered();
+
+        Style {
+            handle_color: Color::from_rgb(0.2, 0.5, 0.9),
+            ..hovered
+        }
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            handle_background: None,
+            handle_color: Color::from_rgb(0.6, 0.6, 0.6),
+            handle_border_radius: 2.0,
+            placeholder: Some(Background::Color(Color::from_rgba(
+                0.0, 0.0, 0.0, 0.05,
+            ))),
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            handle_background: Some(Background::Color(Color::from_rgb(
+                0.9, 0.9, 0.9,
+            ))),
+            handle_color: Color::from_rgb(0.3, 0.3, 0.3),
+            ..self.active()
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
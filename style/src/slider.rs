@@ -24,6 +24,15 @@ pub enum HandleShape {
     Rectangle { width: u16, border_radius: f32 },
 }
 
+/// The appearance of the tick marks of a slider.
+#[derive(Debug, Clone, Copy)]
+pub struct TickMarks {
+    pub color: Color,
+    pub width: f32,
+    pub length: f32,
+    pub label_color: Color,
+}
+
 /// A set of rules that dictate the style of a slider.
 pub trait StyleSheet {
     /// Produces the style of an active slider.
@@ -34,6 +43,22 @@ pub trait StyleSheet {
 
     /// Produces the style of a slider that is being dragged.
     fn dragging(&self) -> Style;
+
+    /// Produces the style of the tick marks of a slider.
+    fn tick_marks(&self) -> TickMarks {
+        TickMarks {
+            color: Color::from_rgb(0.6, 0.6, 0.6),
+            width: 1.0,
+            length: 8.0,
+            label_color: Color::from_rgb(0.4, 0.4, 0.4),
+        }
+    }
+
+    /// Produces the color of the selected part of the rail of a range slider,
+    /// between its two handles.
+    fn range_color(&self) -> Color {
+        Color::from_rgb(0.5, 0.5, 0.5)
+    }
 }
 
 struct Default;
@@ -39,6 +39,15 @@ pub trait StyleSheet {
     fn hovered(&self) -> Style {
         self.focused()
     }
+
+    /// Produces the style of an unfocused text input whose value does not
+    /// satisfy its filters.
+    fn invalid(&self) -> Style {
+        Style {
+            border_color: Color::from_rgb(0.8, 0.2, 0.2),
+            ..self.active()
+        }
+    }
 }
 
 struct Default;
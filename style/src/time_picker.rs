@@ -0,0 +1,81 @@
+//! Let your users pick a time of the day.
+use iced_core::{Background, Color};
+
+/// The appearance of a time picker.
+#[derive(Debug, Clone, Copy)]
+pub struct Style {
+    pub background: Background,
+    pub border_radius: f32,
+    pub border_width: f32,
+    pub border_color: Color,
+    pub text_color: Color,
+    pub segment_background: Option<Background>,
+    pub segment_border_radius: f32,
+    pub button_background: Option<Background>,
+    pub icon_color: Color,
+}
+
+/// A set of rules that dictate the style of a time picker.
+pub trait StyleSheet {
+    /// Produces the style of a time picker and its segments.
+    fn active(&self) -> Style;
+
+    /// Produces the style of a hovered spinner button of a time picker.
+    fn hovered(&self) -> Style {
+        self.active()
+    }
+
+    /// Produces the style of the focused segment of a time picker, which
+    /// can be changed with the keyboard.
+    fn focused(&self) -> Style {
+        Style {
+            segment_background: Some(Background::Color(Color::from_rgb(
+                0.82, 0.89, 0.98,
+            ))),
+            ..self.active()
+        }
+    }
+}
+
+struct Default;
+
+impl StyleSheet for Default {
+    fn active(&self) -> Style {
+        Style {
+            background: Background::Color(Color::WHITE),
+            border_radius: 0.0,
+            border_width: 1.0,
+            border_color: Color::from_rgb(0.7, 0.7, 0.7),
+            text_color: Color::BLACK,
+            segment_background: None,
+            segment_border_radius: 2.0,
+            button_background: None,
+            icon_color: Color::from_rgb(0.4, 0.4, 0.4),
+        }
+    }
+
+    fn hovered(&self) -> Style {
+        Style {
+            button_background: Some(Background::Color(Color::from_rgb(
+                0.9, 0.9, 0.9,
+            ))),
+            icon_color: Color::BLACK,
+            ..self.active()
+        }
+    }
+}
+
+impl<'a> std::default::Default for Box<dyn StyleSheet + 'a> {
+    fn default() -> Self {
+        Box::new(Default)
+    }
+}
+
+impl<'a, T> From<T> for Box<dyn StyleSheet + 'a>
+where
+    T: StyleSheet + 'a,
+{
+    fn from(style_sheet: T) -> Self {
+        Box::new(style_sheet)
+    }
+}
@@ -79,14 +79,15 @@ pub use hasher::Hasher;
 pub use subscription::Subscription;
 
 pub use iced_core::alignment;
+pub use iced_core::font;
 pub use iced_core::keyboard;
 pub use iced_core::mouse;
 pub use iced_futures::executor;
 pub use iced_futures::futures;
 
 pub use iced_core::{
-    Alignment, Background, Color, Font, Length, Padding, Point, Rectangle,
-    Size, Vector,
+    Alignment, Background, Color, Direction, Font, Length, Padding, Point,
+    Rectangle, Size, Vector,
 };
 
 #[doc(no_inline)]
@@ -127,9 +127,10 @@ impl<'a, Message> Widget<Message> for Text {
             self.size.unwrap_or(20),
             color,
             text_align,
-            match self.font {
+            match &self.font {
                 Font::Default => "inherit",
-                Font::External { name, .. } => name,
+                Font::External { name, .. } => *name,
+                Font::Family { name, .. } => name.as_ref(),
             }
         );
 
@@ -10,10 +10,14 @@ use crate::Transformation;
 use atlas::Atlas;
 
 use iced_graphics::layer;
+use iced_native::image::FilterMethod;
 use iced_native::Rectangle;
 use std::cell::RefCell;
 use std::mem;
 
+#[cfg(feature = "image_rs")]
+use std::time::Duration;
+
 use bytemuck::{Pod, Zeroable};
 
 #[cfg(feature = "image_rs")]
@@ -34,7 +38,8 @@ pub struct Pipeline {
     vertices: wgpu::Buffer,
     indices: wgpu::Buffer,
     instances: wgpu::Buffer,
-    constants: wgpu::BindGroup,
+    linear_constants: wgpu::BindGroup,
+    nearest_constants: wgpu::BindGroup,
     texture: wgpu::BindGroup,
     texture_version: usize,
     texture_layout: wgpu::BindGroupLayout,
@@ -45,15 +50,20 @@ impl Pipeline {
     pub fn new(device: &wgpu::Device, format: wgpu::TextureFormat) -> Self {
         use wgpu::util::DeviceExt;
 
-        let sampler = device.create_sampler(&wgpu::SamplerDescriptor {
-            address_mode_u: wgpu::AddressMode::ClampToEdge,
-            address_mode_v: wgpu::AddressMode::ClampToEdge,
-            address_mode_w: wgpu::AddressMode::ClampToEdge,
-            mag_filter: wgpu::FilterMode::Linear,
-            min_filter: wgpu::FilterMode::Linear,
-            mipmap_filter: wgpu::FilterMode::Linear,
-            ..Default::default()
-        });
+        let create_sampler = |filter_mode| {
+            device.create_sampler(&wgpu::SamplerDescriptor {
+                address_mode_u: wgpu::AddressMode::ClampToEdge,
+                address_mode_v: wgpu::AddressMode::ClampToEdge,
+                address_mode_w: wgpu::AddressMode::ClampToEdge,
+                mag_filter: filter_mode,
+                min_filter: filter_mode,
+                mipmap_filter: filter_mode,
+                ..Default::default()
+            })
+        };
+
+        let linear_sampler = create_sampler(wgpu::FilterMode::Linear);
+        let nearest_sampler = create_sampler(wgpu::FilterMode::Nearest);
 
         let constant_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -89,7 +99,7 @@ impl Pipeline {
             mapped_at_creation: false,
         });
 
-        let constant_bind_group =
+        let create_constant_bind_group = |sampler| {
             device.create_bind_group(&wgpu::BindGroupDescriptor {
                 label: Some("iced_wgpu::image constants bind group"),
                 layout: &constant_layout,
@@ -106,10 +116,14 @@ impl Pipeline {
                     },
                     wgpu::BindGroupEntry {
                         binding: 1,
-                        resource: wgpu::BindingResource::Sampler(&sampler),
+                        resource: wgpu::BindingResource::Sampler(sampler),
                     },
                 ],
-            });
+            })
+        };
+
+        let linear_constants = create_constant_bind_group(&linear_sampler);
+        let nearest_constants = create_constant_bind_group(&nearest_sampler);
 
         let texture_layout =
             device.create_bind_group_layout(&wgpu::BindGroupLayoutDescriptor {
@@ -253,7 +267,8 @@ impl Pipeline {
             vertices,
             indices,
             instances,
-            constants: constant_bind_group,
+            linear_constants,
+            nearest_constants,
             texture,
             texture_version: texture_atlas.layer_count(),
             texture_layout,
@@ -269,6 +284,14 @@ impl Pipeline {
         memory.dimensions()
     }
 
+    #[cfg(feature = "image_rs")]
+    pub fn frame_delays(&self, handle: &image::Handle) -> Vec<Duration> {
+        let mut cache = self.raster_cache.borrow_mut();
+        let memory = cache.load(&handle);
+
+        memory.frame_delays()
+    }
+
     #[cfg(feature = "svg")]
     pub fn viewport_dimensions(&self, handle: &svg::Handle) -> (u32, u32) {
         let mut cache = self.vector_cache.borrow_mut();
@@ -288,7 +311,9 @@ impl Pipeline {
         target: &wgpu::TextureView,
         _scale: f32,
     ) {
-        let instances: &mut Vec<Instance> = &mut Vec::new();
+        // Images are grouped in consecutive batches sharing the same filter
+        // method, so their drawing order is preserved.
+        let mut batches: Vec<(FilterMethod, Vec<Instance>)> = Vec::new();
 
         #[cfg(feature = "image_rs")]
         let mut raster_cache = self.raster_cache.borrow_mut();
@@ -299,9 +324,15 @@ impl Pipeline {
         for image in images {
             match &image {
                 #[cfg(feature = "image_rs")]
-                layer::Image::Raster { handle, bounds } => {
+                layer::Image::Raster {
+                    handle,
+                    frame,
+                    filter_method,
+                    bounds,
+                } => {
                     if let Some(atlas_entry) = raster_cache.upload(
                         handle,
+                        *frame,
                         device,
                         encoder,
                         &mut self.texture_atlas,
@@ -310,7 +341,7 @@ impl Pipeline {
                             [bounds.x, bounds.y],
                             [bounds.width, bounds.height],
                             atlas_entry,
-                            instances,
+                            batch(&mut batches, *filter_method),
                         );
                     }
                 }
@@ -333,7 +364,7 @@ impl Pipeline {
                             [bounds.x, bounds.y],
                             size,
                             atlas_entry,
-                            instances,
+                            batch(&mut batches, FilterMethod::Linear),
                         );
                     }
                 }
@@ -342,7 +373,7 @@ impl Pipeline {
             }
         }
 
-        if instances.is_empty() {
+        if batches.is_empty() {
             return;
         }
 
@@ -381,6 +412,34 @@ impl Pipeline {
             }));
         }
 
+        for (filter_method, instances) in &batches {
+            let constants = match filter_method {
+                FilterMethod::Linear => &self.linear_constants,
+                FilterMethod::Nearest => &self.nearest_constants,
+            };
+
+            self.render(
+                device,
+                staging_belt,
+                encoder,
+                constants,
+                instances,
+                bounds,
+                target,
+            );
+        }
+    }
+
+    fn render(
+        &self,
+        device: &wgpu::Device,
+        staging_belt: &mut wgpu::util::StagingBelt,
+        encoder: &mut wgpu::CommandEncoder,
+        constants: &wgpu::BindGroup,
+        instances: &[Instance],
+        bounds: Rectangle<u32>,
+        target: &wgpu::TextureView,
+    ) {
         let mut i = 0;
         let total = instances.len();
 
@@ -418,7 +477,7 @@ impl Pipeline {
                 });
 
             render_pass.set_pipeline(&self.pipeline);
-            render_pass.set_bind_group(0, &self.constants, &[]);
+            render_pass.set_bind_group(0, constants, &[]);
             render_pass.set_bind_group(1, &self.texture, &[]);
             render_pass.set_index_buffer(
                 self.indices.slice(..),
@@ -496,6 +555,18 @@ struct Uniforms {
     transform: [f32; 16],
 }
 
+fn batch(
+    batches: &mut Vec<(FilterMethod, Vec<Instance>)>,
+    filter_method: FilterMethod,
+) -> &mut Vec<Instance> {
+    match batches.last() {
+        Some((last, _)) if *last == filter_method => {}
+        _ => batches.push((filter_method, Vec::new())),
+    }
+
+    &mut batches.last_mut().unwrap().1
+}
+
 fn add_instances(
     image_position: [f32; 2],
     image_size: [f32; 2],
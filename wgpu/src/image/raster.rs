@@ -1,13 +1,16 @@
 use crate::image::atlas::{self, Atlas};
 use iced_native::image;
 use std::collections::{HashMap, HashSet};
+use std::time::Duration;
 
 use bitflags::bitflags;
 
 #[derive(Debug)]
 pub enum Memory {
-    Host(::image_rs::ImageBuffer<::image_rs::Bgra<u8>, Vec<u8>>),
+    /// An image in host memory, with its pixels in BGRA order.
+    Host(::image_rs::RgbaImage),
     Device(atlas::Entry),
+    Animation(Vec<Frame>),
     NotFound,
     Invalid,
 }
@@ -17,10 +20,33 @@ impl Memory {
         match self {
             Memory::Host(image) => image.dimensions(),
             Memory::Device(entry) => entry.size(),
+            Memory::Animation(frames) => frames
+                .first()
+                .map(|frame| frame.memory.dimensions())
+                .unwrap_or((1, 1)),
             Memory::NotFound => (1, 1),
             Memory::Invalid => (1, 1),
         }
     }
+
+    pub fn frame_delays(&self) -> Vec<Duration> {
+        match self {
+            Memory::Animation(frames) => {
+                frames.iter().map(|frame| frame.delay).collect()
+            }
+            _ => Vec::new(),
+        }
+    }
+}
+
+/// A frame of an animated image.
+///
+/// Frames are decoded once and uploaded to the atlas the first time they
+/// are drawn.
+#[derive(Debug)]
+pub struct Frame {
+    delay: Duration,
+    memory: Memory,
 }
 
 #[derive(Debug)]
@@ -44,31 +70,16 @@ impl Cache {
 
         let memory = match handle.data() {
             image::Data::Path(path) => {
-                if let Ok(image) = image_rs::open(path) {
-                    let operation = std::fs::File::open(path)
-                        .ok()
-                        .map(std::io::BufReader::new)
-                        .and_then(|mut reader| {
-                            Operation::from_exif(&mut reader).ok()
-                        })
-                        .unwrap_or_else(Operation::empty);
-
-                    Memory::Host(operation.perform(image.to_bgra8()))
+                if let Ok(bytes) = std::fs::read(path) {
+                    let format = image_rs::ImageFormat::from_path(path).ok();
+
+                    decode(&bytes, format).unwrap_or(Memory::Invalid)
                 } else {
                     Memory::NotFound
                 }
             }
             image::Data::Bytes(bytes) => {
-                if let Ok(image) = image_rs::load_from_memory(&bytes) {
-                    let operation =
-                        Operation::from_exif(&mut std::io::Cursor::new(bytes))
-                            .ok()
-                            .unwrap_or_else(Operation::empty);
-
-                    Memory::Host(operation.perform(image.to_bgra8()))
-                } else {
-                    Memory::Invalid
-                }
+                decode(bytes, None).unwrap_or(Memory::Invalid)
             }
             image::Data::Pixels {
                 width,
@@ -94,11 +105,19 @@ impl Cache {
     pub fn upload(
         &mut self,
         handle: &image::Handle,
+        frame: usize,
         device: &wgpu::Device,
         encoder: &mut wgpu::CommandEncoder,
         atlas: &mut Atlas,
     ) -> Option<&atlas::Entry> {
-        let memory = self.load(handle);
+        let memory = match self.load(handle) {
+            Memory::Animation(frames) => {
+                let index = frame % frames.len();
+
+                &mut frames[index].memory
+            }
+            memory => memory,
+        };
 
         if let Memory::Host(image) = memory {
             let (width, height) = image.dimensions();
@@ -122,8 +141,16 @@ impl Cache {
             let retain = hits.contains(k);
 
             if !retain {
-                if let Memory::Device(entry) = memory {
-                    atlas.remove(entry);
+                match memory {
+                    Memory::Device(entry) => atlas.remove(entry),
+                    Memory::Animation(frames) => {
+                        for frame in frames {
+                            if let Memory::Device(entry) = &frame.memory {
+                                atlas.remove(entry);
+                            }
+                        }
+                    }
+                    _ => {}
                 }
             }
 
@@ -148,6 +175,96 @@ impl Cache {
     }
 }
 
+/// Decodes the given bytes into an image, or into all of its frames if it
+/// is animated.
+///
+/// The format is guessed from the bytes if it is not provided.
+fn decode(
+    bytes: &[u8],
+    format: Option<image_rs::ImageFormat>,
+) -> Option<Memory> {
+    let format = match format {
+        Some(format) => format,
+        None => image_rs::guess_format(bytes).ok()?,
+    };
+
+    let mut frames = decode_frames(bytes, format).unwrap_or_default();
+
+    if frames.len() > 1 {
+        return Some(Memory::Animation(
+            frames
+                .into_iter()
+                .map(|frame| Frame {
+                    delay: Duration::from(frame.delay()),
+                    memory: Memory::Host(to_bgra8(frame.into_buffer())),
+                })
+                .collect(),
+        ));
+    }
+
+    // A single frame is already the decoded image
+    let image = match frames.pop() {
+        Some(frame) => frame.into_buffer(),
+        None => image_rs::load_from_memory_with_format(bytes, format)
+            .ok()?
+            .into_rgba8(),
+    };
+
+    let operation = Operation::from_exif(&mut std::io::Cursor::new(bytes))
+        .ok()
+        .unwrap_or_else(Operation::empty);
+
+    Some(Memory::Host(operation.perform(to_bgra8(image))))
+}
+
+/// Decodes the frames of the given bytes, if their format supports
+/// animation.
+fn decode_frames(
+    bytes: &[u8],
+    format: image_rs::ImageFormat,
+) -> Option<Vec<image_rs::Frame>> {
+    match format {
+        #[cfg(feature = "gif")]
+        image_rs::ImageFormat::Gif => {
+            use image_rs::AnimationDecoder;
+
+            let decoder = image_rs::codecs::gif::GifDecoder::new(bytes).ok()?;
+
+            decoder.into_frames().collect_frames().ok()
+        }
+        #[cfg(feature = "png")]
+        image_rs::ImageFormat::Png => {
+            use image_rs::AnimationDecoder;
+
+            let decoder = image_rs::codecs::png::PngDecoder::new(bytes).ok()?;
+
+            if !decoder.is_apng() {
+                return None;
+            }
+
+            decoder.apng().into_frames().collect_frames().ok()
+        }
+        #[cfg(feature = "webp")]
+        image_rs::ImageFormat::WebP => {
+            use image_rs::AnimationDecoder;
+
+            let decoder =
+                image_rs::codecs::webp::WebPDecoder::new(bytes).ok()?;
+
+            decoder.into_frames().collect_frames().ok()
+        }
+        _ => None,
+    }
+}
+
+/// Swaps the red and blue channels of the given image, as the atlas expects
+/// BGRA pixels.
+fn to_bgra8(mut image: image_rs::RgbaImage) -> image_rs::RgbaImage {
+    image.chunks_exact_mut(4).for_each(|pixel| pixel.swap(0, 2));
+
+    image
+}
+
 bitflags! {
     struct Operation: u8 {
         const FLIP_HORIZONTALLY = 0b001;
@@ -222,3 +339,68 @@ where
 
     out
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "gif")]
+    #[test]
+    fn decodes_animated_gif() {
+        use image_rs::codecs::gif::GifEncoder;
+        use image_rs::{Delay, Rgba};
+
+        let frames = [Rgba([255, 0, 0, 255]), Rgba([0, 0, 255, 255])]
+            .iter()
+            .map(|&pixel| {
+                image_rs::Frame::from_parts(
+                    image_rs::RgbaImage::from_pixel(2, 2, pixel),
+                    0,
+                    0,
+                    Delay::from_numer_denom_ms(50, 1),
+                )
+            });
+
+        let mut bytes = Vec::new();
+        GifEncoder::new(&mut bytes).encode_frames(frames).unwrap();
+
+        let frames = match decode(&bytes, None) {
+            Some(Memory::Animation(frames)) => frames,
+            memory => panic!("expected an animation, got {:?}", memory),
+        };
+
+        assert_eq!(frames.len(), 2);
+        assert_eq!(frames[0].delay, Duration::from_millis(50));
+
+        match &frames[0].memory {
+            Memory::Host(image) => {
+                assert_eq!(image.dimensions(), (2, 2));
+                assert_eq!(image.get_pixel(0, 0).0, [0, 0, 255, 255]);
+            }
+            memory => panic!("expected a host image, got {:?}", memory),
+        }
+    }
+
+    #[cfg(feature = "png")]
+    #[test]
+    fn decodes_still_png() {
+        let image =
+            image_rs::RgbaImage::from_pixel(3, 1, image_rs::Rgba([1, 2, 3, 4]));
+
+        let mut bytes = Vec::new();
+        image_rs::DynamicImage::ImageRgba8(image)
+            .write_to(
+                &mut std::io::Cursor::new(&mut bytes),
+                image_rs::ImageOutputFormat::Png,
+            )
+            .unwrap();
+
+        match decode(&bytes, None) {
+            Some(Memory::Host(image)) => {
+                assert_eq!(image.dimensions(), (3, 1));
+                assert_eq!(image.get_pixel(0, 0).0, [3, 2, 1, 4]);
+            }
+            memory => panic!("expected a host image, got {:?}", memory),
+        }
+    }
+}
@@ -1,6 +1,8 @@
 //! Configure a renderer.
 pub use crate::Antialiasing;
 
+use iced_native::Direction;
+
 /// The settings of a [`Backend`].
 ///
 /// [`Backend`]: crate::Backend
@@ -30,6 +32,11 @@ pub struct Settings {
     /// By default, it is disabled.
     pub text_multithreading: bool,
 
+    /// The [`Direction`] used to lay out the user interface.
+    ///
+    /// By default, it is [`Direction::LeftToRight`].
+    pub direction: Direction,
+
     /// The antialiasing strategy that will be used for triangle primitives.
     ///
     /// By default, it is `None`.
@@ -68,6 +75,7 @@ impl Default for Settings {
             default_font: None,
             default_text_size: 20,
             text_multithreading: false,
+            direction: Direction::LeftToRight,
             antialiasing: None,
         }
     }
@@ -0,0 +1,15 @@
+//! Page through content horizontally.
+//!
+//! A [`Carousel`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::carousel::{Style, StyleSheet};
+pub use iced_native::widget::carousel::State;
+
+/// A horizontally paged container that can be swiped or dragged with the
+/// mouse, snapping to the nearest page.
+///
+/// This is an alias of an `iced_native` carousel with an
+/// `iced_wgpu::Renderer`.
+pub type Carousel<'a, Message> =
+    iced_native::widget::Carousel<'a, Message, Renderer>;
@@ -0,0 +1,2 @@
+//! Plot series of data points with axes and a legend.
+pub use iced_graphics::chart::*;
@@ -0,0 +1,15 @@
+//! Let your users pick dates from a calendar.
+//!
+//! A [`DatePicker`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::date_picker::{Calendar, Style, StyleSheet};
+pub use iced_native::widget::date_picker::{Date, State, Weekday};
+
+/// A compact field that opens a calendar to pick a date, or a range of
+/// dates.
+///
+/// This is an alias of an `iced_native` date picker with an
+/// `iced_wgpu::Renderer`.
+pub type DatePicker<'a, Message> =
+    iced_native::widget::DatePicker<'a, Message, Renderer>;
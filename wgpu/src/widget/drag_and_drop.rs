@@ -0,0 +1,19 @@
+//! Let your users drag content around and drop it somewhere else.
+use crate::Renderer;
+
+pub use iced_graphics::drag_and_drop::{Style, StyleSheet};
+pub use iced_native::widget::drag_and_drop::{Event, Grab, State};
+
+/// Some content that can be dragged around, carrying a payload.
+///
+/// This is an alias of an `iced_native` drag source with an
+/// `iced_wgpu::Renderer`.
+pub type DragSource<'a, T, Message> =
+    iced_native::widget::DragSource<'a, T, Message, Renderer>;
+
+/// Some content where the payload of a [`DragSource`] can be dropped.
+///
+/// This is an alias of an `iced_native` drop target with an
+/// `iced_wgpu::Renderer`.
+pub type DropTarget<'a, T, Message> =
+    iced_native::widget::DropTarget<'a, T, Message, Renderer>;
@@ -0,0 +1,13 @@
+//! Display fields that can be filled with numbers.
+//!
+//! A [`NumberInput`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::number_input::{Style, StyleSheet};
+pub use iced_native::widget::number_input::State;
+
+/// A field that can be filled with a number from a range of values.
+///
+/// This is an alias of an `iced_native` number input with an `iced_wgpu::Renderer`.
+pub type NumberInput<'a, T, Message> =
+    iced_native::widget::NumberInput<'a, T, Message, Renderer>;
@@ -0,0 +1,6 @@
+//! Allow your users to visually track the progress of a computation with a
+//! ring of dots.
+//!
+//! A [`ProgressRing`] has a range of possible values and a current value,
+//! or some [`State`] when it is indeterminate.
+pub use iced_graphics::progress_ring::*;
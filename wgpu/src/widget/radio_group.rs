@@ -0,0 +1,11 @@
+//! Choose a single option from a group of radio buttons.
+use crate::Renderer;
+
+pub use iced_graphics::radio_group::{Orientation, State, Style, StyleSheet};
+
+/// A list of radio buttons where only one option can be selected.
+///
+/// This is an alias of an `iced_native` radio group with an
+/// `iced_wgpu::Renderer`.
+pub type RadioGroup<'a, T, Message> =
+    iced_native::widget::RadioGroup<'a, T, Message, Renderer>;
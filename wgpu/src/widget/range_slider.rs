@@ -0,0 +1,9 @@
+//! Display an interactive selector of a range of values within another range.
+//!
+//! A [`RangeSlider`] has some local [`State`].
+pub use iced_graphics::range_slider::{
+    Handle, HandleShape, Style, StyleSheet, TickMarks,
+};
+pub use iced_native::widget::range_slider::{
+    Orientation, RangeSlider, State, Tick,
+};
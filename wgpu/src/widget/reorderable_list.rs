@@ -0,0 +1,14 @@
+//! Let your users reorder a list of items by dragging them.
+//!
+//! A [`ReorderableList`] has some local [`State`].
+use crate::Renderer;
+
+pub use iced_graphics::reorderable_list::{Style, StyleSheet};
+pub use iced_native::widget::reorderable_list::State;
+
+/// A vertical list of items that can be reordered by dragging their handles.
+///
+/// This is an alias of an `iced_native` reorderable list with an
+/// `iced_wgpu::Renderer`.
+pub type ReorderableList<'a, Message> =
+    iced_native::widget::ReorderableList<'a, Message, Renderer>;
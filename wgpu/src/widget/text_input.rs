@@ -4,7 +4,7 @@
 use crate::Renderer;
 
 pub use iced_graphics::text_input::{Style, StyleSheet};
-pub use iced_native::widget::text_input::State;
+pub use iced_native::widget::text_input::{filter, Filter, State};
 
 /// A field that can be filled with text.
 ///
@@ -16,8 +16,10 @@ use iced_futures::futures::channel::mpsc;
 use iced_graphics::window;
 use iced_native::program::Program;
 use iced_native::user_interface::{self, UserInterface};
+use iced_native::window::RedrawRequest;
 
 use std::mem::ManuallyDrop;
+use std::time::{Duration, Instant};
 
 /// An interactive, native cross-platform application.
 ///
@@ -151,18 +153,21 @@ where
 
     let mut clipboard = Clipboard::connect(&window);
 
+    let (compositor, mut renderer) =
+        C::new(compositor_settings, Some(&window))?;
+
     run_command(
         init_command,
         &mut runtime,
         &mut clipboard,
+        &mut renderer,
         &mut proxy,
         &window,
     );
     runtime.track(subscription);
 
-    let (compositor, renderer) = C::new(compositor_settings, Some(&window))?;
-
     let (mut sender, receiver) = mpsc::unbounded();
+    let (control_sender, mut control_receiver) = mpsc::unbounded();
 
     let mut instance = Box::pin(run_instance::<A, E, C>(
         application,
@@ -173,11 +178,13 @@ where
         proxy,
         debug,
         receiver,
+        control_sender,
         window,
         settings.exit_on_close_request,
     ));
 
     let mut context = task::Context::from_waker(task::noop_waker_ref());
+    let mut next_control_flow = winit::event_loop::ControlFlow::Wait;
 
     event_loop.run_return(move |event, _, control_flow| {
         use winit::event_loop::ControlFlow;
@@ -207,7 +214,13 @@ where
             let poll = instance.as_mut().poll(&mut context);
 
             *control_flow = match poll {
-                task::Poll::Pending => ControlFlow::Wait,
+                task::Poll::Pending => {
+                    while let Ok(Some(flow)) = control_receiver.try_next() {
+                        next_control_flow = flow;
+                    }
+
+                    next_control_flow
+                }
                 task::Poll::Ready(_) => ControlFlow::Exit,
             };
         }
@@ -225,6 +238,7 @@ async fn run_instance<A, E, C>(
     mut proxy: winit::event_loop::EventLoopProxy<A::Message>,
     mut debug: Debug,
     mut receiver: mpsc::UnboundedReceiver<winit::event::Event<'_, A::Message>>,
+    mut control_sender: mpsc::UnboundedSender<winit::event_loop::ControlFlow>,
     window: winit::window::Window,
     exit_on_close_request: bool,
 ) where
@@ -259,12 +273,31 @@ async fn run_instance<A, E, C>(
     let mut mouse_interaction = mouse::Interaction::default();
     let mut events = Vec::new();
     let mut messages = Vec::new();
+    // Widgets of the initial view may need to be redrawn continuously (e.g.
+    // progress indicators and animations), so a redraw is always requested
+    let mut redraw_request = Some(RedrawRequest::NextFrame);
 
     debug.startup_finished();
 
     while let Some(event) = receiver.next().await {
         match event {
             event::Event::MainEventsCleared => {
+                let now = Instant::now();
+
+                let is_redraw_due = match redraw_request {
+                    Some(RedrawRequest::NextFrame) => true,
+                    Some(RedrawRequest::At(at)) => at <= now,
+                    None => false,
+                };
+
+                if is_redraw_due {
+                    events.push(iced_native::Event::Window(
+                        iced_native::window::Event::RedrawRequested(now),
+                    ));
+
+                    redraw_request = None;
+                }
+
                 if events.is_empty() && messages.is_empty() {
                     continue;
                 }
@@ -281,6 +314,18 @@ async fn run_instance<A, E, C>(
 
                 debug.event_processing_finished();
 
+                redraw_request = redraw_request
+                    .into_iter()
+                    .chain(user_interface.redraw_request())
+                    .min();
+
+                if let Some(position) = user_interface.ime_position() {
+                    window.set_ime_position(conversion::ime_position(
+                        position,
+                        state.scale_factor(),
+                    ));
+                }
+
                 for event in events.drain(..).zip(statuses.into_iter()) {
                     runtime.broadcast(event);
                 }
@@ -299,6 +344,7 @@ async fn run_instance<A, E, C>(
                         &mut application,
                         &mut runtime,
                         &mut clipboard,
+                        &mut renderer,
                         &mut proxy,
                         &mut debug,
                         &mut messages,
@@ -337,6 +383,10 @@ async fn run_instance<A, E, C>(
                 }
 
                 window.request_redraw();
+
+                control_sender
+                    .start_send(control_flow(redraw_request, now))
+                    .expect("Send control flow");
             }
             event::Event::PlatformSpecific(event::PlatformSpecific::MacOS(
                 event::MacOS::ReceivedUrl(url),
@@ -402,9 +452,6 @@ async fn run_instance<A, E, C>(
                 ) {
                     Ok(()) => {
                         debug.render_finished();
-
-                        // TODO: Handle animations!
-                        // Maybe we can use `ControlFlow::WaitUntil` for this.
                     }
                     Err(error) => match error {
                         // This is an unrecoverable error.
@@ -439,6 +486,12 @@ async fn run_instance<A, E, C>(
                 ) {
                     events.push(event);
                 }
+
+                if let Some(ime) = conversion::ime_event(&window_event) {
+                    events.push(iced_native::Event::Keyboard(
+                        iced_native::keyboard::Event::Ime(ime),
+                    ));
+                }
             }
             _ => {}
         }
@@ -472,6 +525,31 @@ pub fn requests_exit(
     }
 }
 
+/// The time between the frames requested with [`RedrawRequest::NextFrame`].
+///
+/// The present mode of the compositor may not wait for vertical sync, so
+/// frames are paced to the refresh rate of most displays instead.
+const FRAME_INTERVAL: Duration = Duration::from_micros(16_667);
+
+/// Returns the [`ControlFlow`] of the event loop that satisfies the given
+/// [`RedrawRequest`], as of the given instant.
+///
+/// [`ControlFlow`]: winit::event_loop::ControlFlow
+pub fn control_flow(
+    redraw_request: Option<RedrawRequest>,
+    now: Instant,
+) -> winit::event_loop::ControlFlow {
+    use winit::event_loop::ControlFlow;
+
+    match redraw_request {
+        Some(RedrawRequest::NextFrame) => {
+            ControlFlow::WaitUntil(now + FRAME_INTERVAL)
+        }
+        Some(RedrawRequest::At(at)) => ControlFlow::WaitUntil(at),
+        None => ControlFlow::Wait,
+    }
+}
+
 /// Builds a [`UserInterface`] for the provided [`Application`], logging
 /// [`struct@Debug`] information accordingly.
 pub fn build_user_interface<'a, A: Application>(
@@ -498,6 +576,7 @@ pub fn update<A: Application, E: Executor>(
     application: &mut A,
     runtime: &mut Runtime<E, Proxy<A::Message>, A::Message>,
     clipboard: &mut Clipboard,
+    renderer: &mut A::Renderer,
     proxy: &mut winit::event_loop::EventLoopProxy<A::Message>,
     debug: &mut Debug,
     messages: &mut Vec<A::Message>,
@@ -510,7 +589,7 @@ pub fn update<A: Application, E: Executor>(
         let command = runtime.enter(|| application.update(message));
         debug.update_finished();
 
-        run_command(command, runtime, clipboard, proxy, window);
+        run_command(command, runtime, clipboard, renderer, proxy, window);
     }
 
     let subscription = application.subscription();
@@ -518,14 +597,20 @@ pub fn update<A: Application, E: Executor>(
 }
 
 /// Runs the actions of a [`Command`].
-pub fn run_command<Message: 'static + std::fmt::Debug + Send, E: Executor>(
+pub fn run_command<Message, E, R>(
     command: Command<Message>,
     runtime: &mut Runtime<E, Proxy<Message>, Message>,
     clipboard: &mut Clipboard,
+    renderer: &mut R,
     proxy: &mut winit::event_loop::EventLoopProxy<Message>,
     window: &winit::window::Window,
-) {
+) where
+    Message: 'static + std::fmt::Debug + Send,
+    E: Executor,
+    R: iced_native::Renderer,
+{
     use iced_native::command;
+    use iced_native::font;
     use iced_native::window;
 
     for action in command.actions() {
@@ -559,6 +644,15 @@ pub fn run_command<Message: 'static + std::fmt::Debug + Send, E: Executor>(
                     });
                 }
             },
+            command::Action::Font(action) => match action {
+                font::Action::Load { bytes, tagger } => {
+                    let message = tagger(renderer.load_font(bytes));
+
+                    proxy
+                        .send_event(message)
+                        .expect("Send message to event loop");
+                }
+            },
         }
     }
 }
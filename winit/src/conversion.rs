@@ -229,6 +229,26 @@ pub fn mouse_interaction(
     }
 }
 
+/// Converts a winit window event into an [`iced_native`] IME event, if the
+/// event affects the input method editor (IME) of the window.
+///
+/// `winit` does not report the composition of the IME yet, so its committed
+/// text is received as characters instead. Losing focus cancels any
+/// composition in progress.
+///
+/// [`iced_native`]: https://github.com/hecrj/iced/tree/master/native
+pub fn ime_event(
+    event: &winit::event::WindowEvent<'_>,
+) -> Option<keyboard::Ime> {
+    use winit::event::WindowEvent;
+
+    match event {
+        WindowEvent::Focused(true) => Some(keyboard::Ime::Enabled),
+        WindowEvent::Focused(false) => Some(keyboard::Ime::Disabled),
+        _ => None,
+    }
+}
+
 /// Converts a `MouseButton` from [`winit`] to an [`iced_native`] mouse button.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -272,6 +292,21 @@ pub fn cursor_position(
     Point::new(logical_position.x, logical_position.y)
 }
 
+/// Converts a logical position of the input method editor (IME) to a
+/// physical [`winit`] position.
+///
+/// [`winit`]: https://github.com/rust-windowing/winit
+pub fn ime_position(
+    position: Point,
+    scale_factor: f64,
+) -> winit::dpi::PhysicalPosition<f64> {
+    winit::dpi::LogicalPosition::new(
+        f64::from(position.x),
+        f64::from(position.y),
+    )
+    .to_physical(scale_factor)
+}
+
 /// Converts a `Touch` from [`winit`] to an [`iced_native`] touch event.
 ///
 /// [`winit`]: https://github.com/rust-windowing/winit
@@ -0,0 +1,22 @@
+//! Load fonts at runtime.
+use crate::command::{self, Command};
+use iced_native::font;
+
+pub use font::{Descriptor, Error, Font, Stretch, Style, Weight};
+
+/// Loads the font contained in the given bytes and produces a message with
+/// the result.
+///
+/// Once loaded, the font can be used by any [`Font::Family`] matching its
+/// family name and properties. The result contains the [`Descriptor`] of the
+/// font, which can be turned into a [`Font`] to use it; for instance, when
+/// the font was picked by the user.
+pub fn load<Message>(
+    bytes: Vec<u8>,
+    f: impl Fn(Result<Descriptor, Error>) -> Message + 'static,
+) -> Command<Message> {
+    Command::single(command::Action::Font(font::Action::Load {
+        bytes,
+        tagger: Box::new(f),
+    }))
+}
@@ -30,6 +30,7 @@ pub use winit;
 pub mod application;
 pub mod clipboard;
 pub mod conversion;
+pub mod font;
 pub mod settings;
 pub mod window;
 